@@ -11,9 +11,7 @@ use std::{
     task::{Context, Poll},
 };
 
-#[cfg(test)]
-use bson::RawDocumentBuf;
-use bson::{Document, Timestamp};
+use bson::{Document, RawDocumentBuf, Timestamp};
 use derivative::Derivative;
 use futures_core::{future::BoxFuture, Stream};
 use serde::de::DeserializeOwned;
@@ -102,6 +100,9 @@ where
     /// A pending future for a resume.
     #[derivative(Debug = "ignore")]
     pending_resume: Option<BoxFuture<'static, Result<ChangeStream<T>>>>,
+
+    /// The fragments received so far of an event split by `$changeStreamSplitLargeEvent`.
+    split_event: SplitEventBuffer,
 }
 
 impl<T> ChangeStream<T>
@@ -115,6 +116,7 @@ where
             args,
             data,
             pending_resume,
+            split_event: SplitEventBuffer::default(),
         }
     }
 
@@ -135,6 +137,7 @@ where
             args: self.args,
             data: self.data,
             pending_resume: None,
+            split_event: self.split_event,
         }
     }
 
@@ -236,6 +239,80 @@ impl ChangeStreamData {
     }
 }
 
+/// Reassembles events that were split into fragments by a `$changeStreamSplitLargeEvent` stage.
+///
+/// Each fragment contains a subset of the top-level fields of the event along with a `splitEvent`
+/// field describing its position, and its own resume token in `_id`. The fragments of an event are
+/// always returned consecutively.
+#[derive(Debug, Default)]
+pub(crate) struct SplitEventBuffer {
+    fragments: Vec<RawDocumentBuf>,
+}
+
+impl SplitEventBuffer {
+    /// Returns `doc` if it is a whole event, the reassembled event if `doc` is the final fragment
+    /// of a split event, or `None` if more fragments are needed.
+    ///
+    /// The reassembled event has the resume token of its final fragment, as resuming from an
+    /// earlier fragment's token would return the remaining fragments.
+    pub(crate) fn push(&mut self, doc: RawDocumentBuf) -> Result<Option<RawDocumentBuf>> {
+        let split_event = match doc.get("splitEvent")? {
+            Some(split_event) => split_event.as_document().ok_or_else(|| {
+                invalid_split_event(format!("invalid splitEvent field: {:?}", split_event))
+            })?,
+            None if self.fragments.is_empty() => return Ok(Some(doc)),
+            None => {
+                return Err(invalid_split_event(format!(
+                    "expected fragment {} of a split event",
+                    self.fragments.len() + 1
+                )))
+            }
+        };
+        let fragment = split_event
+            .get_i32("fragment")
+            .map_err(invalid_split_event)?;
+        let of = split_event.get_i32("of").map_err(invalid_split_event)?;
+        if fragment as usize != self.fragments.len() + 1 || fragment > of {
+            return Err(invalid_split_event(format!(
+                "expected fragment {} of a split event, got fragment {} of {}",
+                self.fragments.len() + 1,
+                fragment,
+                of
+            )));
+        }
+
+        self.fragments.push(doc);
+        if fragment < of {
+            return Ok(None);
+        }
+
+        let mut event = RawDocumentBuf::new();
+        if let Some(id) = self
+            .fragments
+            .last()
+            .and_then(|f| f.get("_id").ok().flatten())
+        {
+            event.append("_id", id.to_raw_bson());
+        }
+        for fragment in self.fragments.drain(..) {
+            for element in fragment.iter() {
+                let (key, value) = element?;
+                if key != "_id" && key != "splitEvent" {
+                    event.append(key, value.to_raw_bson());
+                }
+            }
+        }
+        Ok(Some(event))
+    }
+}
+
+fn invalid_split_event(message: impl std::fmt::Display) -> crate::error::Error {
+    ErrorKind::InvalidResponse {
+        message: format!("invalid change stream event fragment: {}", message),
+    }
+    .into()
+}
+
 fn get_resume_token(
     batch_value: &BatchValue,
     batch_token: Option<&ResumeToken>,
@@ -312,7 +389,17 @@ where
                 }
                 _ => {}
             }
-            return out;
+            return match out {
+                Poll::Ready(Ok(BatchValue::Some { doc, is_last })) => {
+                    match self.split_event.push(doc)? {
+                        Some(doc) => Poll::Ready(Ok(BatchValue::Some { doc, is_last })),
+                        // The remaining fragments of the event will be in the next batch.
+                        None if is_last => Poll::Ready(Ok(BatchValue::Empty)),
+                        None => continue,
+                    }
+                }
+                other => other,
+            };
         }
     }
 }
@@ -6,7 +6,7 @@ use crate::{cursor::CursorSpecification, options::ChangeStreamOptions};
 
 #[cfg(test)]
 use bson::Bson;
-use bson::{DateTime, Document, RawBson, RawDocumentBuf, Timestamp};
+use bson::{Binary, DateTime, Document, RawBson, RawDocumentBuf, Timestamp};
 use serde::{Deserialize, Serialize};
 
 /// An opaque token used for resuming an interrupted
@@ -74,6 +74,19 @@ pub struct ChangeStreamEvent<T> {
     /// The new name for the `ns` collection.  Only included for `OperationType::Rename`.
     pub to: Option<ChangeNamespace>,
 
+    /// The UUID of the collection on which the event occurred. Only included if
+    /// [`show_expanded_events`](crate::options::ChangeStreamOptions::show_expanded_events) is
+    /// enabled on the change stream.
+    #[serde(rename = "collectionUUID")]
+    pub collection_uuid: Option<Binary>,
+
+    /// Additional information about the operation that caused the event, such as the
+    /// specification of a created index or the options of a modified collection. Only included
+    /// for the expanded event types when
+    /// [`show_expanded_events`](crate::options::ChangeStreamOptions::show_expanded_events) is
+    /// enabled on the change stream.
+    pub operation_description: Option<Document>,
+
     /// A `Document` that contains the `_id` of the document created or modified by the `insert`,
     /// `replace`, `delete`, `update` operations (i.e. CRUD operations). For sharded collections,
     /// also displays the full shard key for the document. The `_id` field is not repeated if it is
@@ -168,6 +181,55 @@ pub enum OperationType {
     /// See [invalidate-event](https://www.mongodb.com/docs/manual/reference/change-events/#invalidate-event)
     Invalidate,
 
+    /// See [create-event](https://www.mongodb.com/docs/manual/reference/change-events/create/)
+    ///
+    /// Only reported if
+    /// [`show_expanded_events`](crate::options::ChangeStreamOptions::show_expanded_events) is
+    /// enabled.
+    Create,
+
+    /// See [createIndexes-event](https://www.mongodb.com/docs/manual/reference/change-events/createIndexes/)
+    ///
+    /// Only reported if
+    /// [`show_expanded_events`](crate::options::ChangeStreamOptions::show_expanded_events) is
+    /// enabled.
+    CreateIndexes,
+
+    /// See [dropIndexes-event](https://www.mongodb.com/docs/manual/reference/change-events/dropIndexes/)
+    ///
+    /// Only reported if
+    /// [`show_expanded_events`](crate::options::ChangeStreamOptions::show_expanded_events) is
+    /// enabled.
+    DropIndexes,
+
+    /// See [modify-event](https://www.mongodb.com/docs/manual/reference/change-events/modify/)
+    ///
+    /// Only reported if
+    /// [`show_expanded_events`](crate::options::ChangeStreamOptions::show_expanded_events) is
+    /// enabled.
+    Modify,
+
+    /// See [shardCollection-event](https://www.mongodb.com/docs/manual/reference/change-events/shardCollection/)
+    ///
+    /// Only reported if
+    /// [`show_expanded_events`](crate::options::ChangeStreamOptions::show_expanded_events) is
+    /// enabled.
+    ShardCollection,
+
+    /// See [reshardCollection-event](https://www.mongodb.com/docs/manual/reference/change-events/reshardCollection/)
+    ///
+    /// Only reported if
+    /// [`show_expanded_events`](crate::options::ChangeStreamOptions::show_expanded_events) is
+    /// enabled.
+    ReshardCollection,
+
+    /// See [refineCollectionShardKey-event](https://www.mongodb.com/docs/manual/reference/change-events/refineCollectionShardKey/)
+    ///
+    /// Only reported if
+    /// [`show_expanded_events`](crate::options::ChangeStreamOptions::show_expanded_events) is
+    /// enabled.
+    RefineCollectionShardKey,
+
     /// A catch-all for future event types.
     Other(String),
 }
@@ -183,6 +245,13 @@ enum OperationTypeHelper {
     Rename,
     DropDatabase,
     Invalidate,
+    Create,
+    CreateIndexes,
+    DropIndexes,
+    Modify,
+    ShardCollection,
+    ReshardCollection,
+    RefineCollectionShardKey,
 }
 
 #[derive(Serialize, Deserialize)]
@@ -203,6 +272,15 @@ impl<'a> From<&'a OperationType> for OperationTypeWrapper<'a> {
             OperationType::Rename => Self::Known(OperationTypeHelper::Rename),
             OperationType::DropDatabase => Self::Known(OperationTypeHelper::DropDatabase),
             OperationType::Invalidate => Self::Known(OperationTypeHelper::Invalidate),
+            OperationType::Create => Self::Known(OperationTypeHelper::Create),
+            OperationType::CreateIndexes => Self::Known(OperationTypeHelper::CreateIndexes),
+            OperationType::DropIndexes => Self::Known(OperationTypeHelper::DropIndexes),
+            OperationType::Modify => Self::Known(OperationTypeHelper::Modify),
+            OperationType::ShardCollection => Self::Known(OperationTypeHelper::ShardCollection),
+            OperationType::ReshardCollection => Self::Known(OperationTypeHelper::ReshardCollection),
+            OperationType::RefineCollectionShardKey => {
+                Self::Known(OperationTypeHelper::RefineCollectionShardKey)
+            }
             OperationType::Other(s) => Self::Unknown(s),
         }
     }
@@ -220,6 +298,13 @@ impl<'a> From<OperationTypeWrapper<'a>> for OperationType {
                 OperationTypeHelper::Rename => Self::Rename,
                 OperationTypeHelper::DropDatabase => Self::DropDatabase,
                 OperationTypeHelper::Invalidate => Self::Invalidate,
+                OperationTypeHelper::Create => Self::Create,
+                OperationTypeHelper::CreateIndexes => Self::CreateIndexes,
+                OperationTypeHelper::DropIndexes => Self::DropIndexes,
+                OperationTypeHelper::Modify => Self::Modify,
+                OperationTypeHelper::ShardCollection => Self::ShardCollection,
+                OperationTypeHelper::ReshardCollection => Self::ReshardCollection,
+                OperationTypeHelper::RefineCollectionShardKey => Self::RefineCollectionShardKey,
             },
             OperationTypeWrapper::Unknown(s) => Self::Other(s.to_string()),
         }
@@ -59,6 +59,16 @@ pub struct ChangeStreamOptions {
     #[builder(default)]
     pub start_after: Option<ResumeToken>,
 
+    /// If `true`, the change stream will also report DDL events such as `create`, `createIndexes`,
+    /// `modify` and `shardCollection`, and events will include the
+    /// [`collection_uuid`](crate::change_stream::event::ChangeStreamEvent::collection_uuid) and
+    /// [`operation_description`](
+    /// crate::change_stream::event::ChangeStreamEvent::operation_description) fields.
+    ///
+    /// This option is only supported on MongoDB 6.0+.
+    #[builder(default)]
+    pub show_expanded_events: Option<bool>,
+
     /// If `true`, the change stream will monitor all changes for the given cluster.
     #[builder(default, setter(skip))]
     pub(crate) all_changes_for_cluster: Option<bool>,
@@ -12,6 +12,7 @@ use super::{
     event::{ChangeStreamEvent, ResumeToken},
     get_resume_token,
     ChangeStreamData,
+    SplitEventBuffer,
     WatchArgs,
 };
 
@@ -45,6 +46,7 @@ where
     cursor: SessionCursor<T>,
     args: WatchArgs,
     data: ChangeStreamData,
+    split_event: SplitEventBuffer,
 }
 
 impl<T> SessionChangeStream<T>
@@ -52,7 +54,12 @@ where
     T: DeserializeOwned + Unpin + Send + Sync,
 {
     pub(crate) fn new(cursor: SessionCursor<T>, args: WatchArgs, data: ChangeStreamData) -> Self {
-        Self { cursor, args, data }
+        Self {
+            cursor,
+            args,
+            data,
+            split_event: SplitEventBuffer::default(),
+        }
     }
 
     /// Returns the cached resume token that can be used to resume after the most recently returned
@@ -67,7 +74,12 @@ where
 
     /// Update the type streamed values will be parsed as.
     pub fn with_type<D: DeserializeOwned + Unpin + Send + Sync>(self) -> SessionChangeStream<D> {
-        SessionChangeStream::new(self.cursor.with_type(), self.args, self.data)
+        SessionChangeStream {
+            cursor: self.cursor.with_type(),
+            args: self.args,
+            data: self.data,
+            split_event: self.split_event,
+        }
     }
 
     /// Retrieve the next result from the change stream.
@@ -147,9 +159,14 @@ where
                         self.data.resume_token = Some(token);
                     }
                     match bv {
-                        BatchValue::Some { doc, .. } => {
+                        BatchValue::Some { doc, is_last } => {
                             self.data.document_returned = true;
-                            return Ok(Some(bson::from_slice(doc.as_bytes())?));
+                            match self.split_event.push(doc)? {
+                                Some(doc) => return Ok(Some(bson::from_slice(doc.as_bytes())?)),
+                                // The remaining fragments of the event will be in the next batch.
+                                None if is_last => return Ok(None),
+                                None => continue,
+                            }
                         }
                         BatchValue::Empty | BatchValue::Exhausted => return Ok(None),
                     }
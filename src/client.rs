@@ -17,7 +17,7 @@ use std::{
 pub use self::csfle::client_builder::*;
 use derivative::Derivative;
 use futures_core::{future::BoxFuture, Future};
-use futures_util::{future::join_all, FutureExt};
+use futures_util::{future::join_all, FutureExt, TryStreamExt};
 
 #[cfg(feature = "tracing-unstable")]
 use crate::trace::{
@@ -28,7 +28,7 @@ use crate::trace::{
     COMMAND_TRACING_EVENT_TARGET,
 };
 use crate::{
-    bson::Document,
+    bson::{doc, oid::ObjectId, Document},
     change_stream::{
         event::ChangeStreamEvent,
         options::ChangeStreamOptions,
@@ -36,22 +36,36 @@ use crate::{
         ChangeStream,
     },
     concern::{ReadConcern, WriteConcern},
+    cursor::{session::SessionCursor, Cursor},
     db::Database,
-    error::{Error, ErrorKind, Result},
-    event::command::{handle_command_event, CommandEvent},
+    error::{ClientBulkWriteFailure, Error, ErrorKind, Result},
+    event::{
+        command::{handle_command_event, CommandEvent},
+        sdam::TopologyDescription,
+    },
     id_set::IdSet,
-    operation::{AggregateTarget, ListDatabases},
+    operation::{
+        self,
+        AggregateTarget,
+        BulkWrite,
+        ListDatabases,
+        OverrideCriteriaFn,
+        SingleWriteResult,
+    },
     options::{
+        BulkWriteOptions,
         ClientOptions,
         DatabaseOptions,
         ListDatabasesOptions,
         ReadPreference,
         SelectionCriteria,
         ServerAddress,
+        ServerStatusOptions,
         SessionOptions,
+        WriteModel,
     },
-    results::DatabaseSpecification,
-    sdam::{server_selection, SelectedServer, Topology},
+    results::{BulkWriteResult, DatabaseSpecification, ServerStatus},
+    sdam::{server_selection, SelectedServer, Topology, TopologyUpdates},
     tracking_arc::TrackingArc,
     ClientSession,
 };
@@ -337,6 +351,11 @@ impl Client {
         self.inner.options.write_concern.as_ref()
     }
 
+    /// Gets the default timeout the `Client` uses for operations.
+    pub fn timeout(&self) -> Option<Duration> {
+        self.inner.options.timeout
+    }
+
     /// Gets a handle to a database specified by `name` in the cluster the `Client` is connected to.
     /// The `Database` options (e.g. read preference and write concern) will default to those of the
     /// `Client`.
@@ -431,6 +450,197 @@ impl Client {
         }
     }
 
+    /// Gets the status of a server in the deployment, such as its version, uptime and operation
+    /// counters. The server is chosen according to the selection criteria in `options`, or the
+    /// `Client`'s selection criteria if none is provided.
+    pub async fn server_status(
+        &self,
+        options: impl Into<Option<ServerStatusOptions>>,
+    ) -> Result<ServerStatus> {
+        let mut options = options.into();
+        resolve_options!(self, options, [selection_criteria]);
+
+        let server_status = operation::ServerStatus::new(options);
+        self.execute_operation(server_status, None).await
+    }
+
+    /// Executes the writes described by `models` as one or more `bulkWrite` commands. Unlike the
+    /// write methods on [`Collection`](crate::Collection), the models may target any number of
+    /// namespaces and mix inserts, updates, replaces and deletes.
+    ///
+    /// The models are split into batches according to the `maxWriteBatchSize` and
+    /// `maxMessageSizeBytes` limits reported by the server. If any of the writes fail, an error
+    /// with a kind of [`ErrorKind::ClientBulkWrite`] is returned that describes the failures along
+    /// with the results of the writes that succeeded.
+    ///
+    /// This method is only available on MongoDB 8.0+.
+    pub async fn bulk_write(
+        &self,
+        models: impl IntoIterator<Item = impl Into<WriteModel>>,
+        options: impl Into<Option<BulkWriteOptions>>,
+    ) -> Result<BulkWriteResult> {
+        self.bulk_write_common(models, options, None).await
+    }
+
+    /// Executes the writes described by `models` as one or more `bulkWrite` commands using the
+    /// provided `ClientSession`. See [`Client::bulk_write`] for more details.
+    ///
+    /// This method is only available on MongoDB 8.0+.
+    pub async fn bulk_write_with_session(
+        &self,
+        models: impl IntoIterator<Item = impl Into<WriteModel>>,
+        options: impl Into<Option<BulkWriteOptions>>,
+        session: &mut ClientSession,
+    ) -> Result<BulkWriteResult> {
+        self.bulk_write_common(models, options, Some(session)).await
+    }
+
+    async fn bulk_write_common(
+        &self,
+        models: impl IntoIterator<Item = impl Into<WriteModel>>,
+        options: impl Into<Option<BulkWriteOptions>>,
+        mut session: Option<&mut ClientSession>,
+    ) -> Result<BulkWriteResult> {
+        let mut models: Vec<WriteModel> = models.into_iter().map(Into::into).collect();
+        if models.is_empty() {
+            return Err(ErrorKind::InvalidArgument {
+                message: "at least one write model must be provided to bulk_write".to_string(),
+            }
+            .into());
+        }
+
+        #[cfg(feature = "in-use-encryption-unstable")]
+        if self.auto_encryption_opts().await.is_some() {
+            return Err(ErrorKind::InvalidArgument {
+                message: "bulk_write does not currently support automatic encryption".to_string(),
+            }
+            .into());
+        }
+
+        for model in models.iter_mut() {
+            model.validate()?;
+            // Generate ids up front so that they are reported consistently and remain the same
+            // if a batch is retried.
+            if let WriteModel::InsertOne(ref mut model) = model {
+                if !model.document.contains_key("_id") {
+                    let mut document = doc! { "_id": ObjectId::new() };
+                    document.extend(std::mem::take(&mut model.document));
+                    model.document = document;
+                }
+            }
+        }
+
+        let mut options = options.into();
+        resolve_write_concern_with_session!(self, options, session.as_ref())?;
+        resolve_timeout_with_session!(self, options, session.as_ref());
+
+        let ordered = options.as_ref().and_then(|o| o.ordered).unwrap_or(true);
+        let verbose = options
+            .as_ref()
+            .and_then(|o| o.verbose_results)
+            .unwrap_or(false);
+
+        let mut result = BulkWriteResult::new(verbose);
+        let mut failure = ClientBulkWriteFailure::new();
+        let mut any_successful = false;
+        let mut offset = 0;
+
+        while offset < models.len() {
+            let mut op = BulkWrite::new(&models, offset, options.as_ref());
+            let batch_result = self
+                .execute_bulk_write_batch(
+                    &mut op,
+                    &models,
+                    offset,
+                    &mut result,
+                    &mut failure,
+                    &mut any_successful,
+                    session.as_deref_mut(),
+                )
+                .await;
+
+            if let Err(error) = batch_result {
+                if failure.write_errors.is_empty()
+                    && failure.write_concern_errors.is_empty()
+                    && !any_successful
+                {
+                    return Err(error);
+                }
+                failure.partial_result = any_successful.then(|| result);
+                let labels = error.labels().clone();
+                return Err(
+                    Error::new(ErrorKind::ClientBulkWrite(failure), Some(labels))
+                        .with_source(error),
+                );
+            }
+
+            if ordered && !failure.write_errors.is_empty() {
+                break;
+            }
+            offset += op.n_attempted();
+        }
+
+        if failure.write_errors.is_empty() && failure.write_concern_errors.is_empty() {
+            Ok(result)
+        } else {
+            failure.partial_result = any_successful.then(|| result);
+            Err(ErrorKind::ClientBulkWrite(failure).into())
+        }
+    }
+
+    /// Executes a single `bulkWrite` command and records the results it reports.
+    #[allow(clippy::too_many_arguments)]
+    async fn execute_bulk_write_batch(
+        &self,
+        op: &mut BulkWrite<'_>,
+        models: &[WriteModel],
+        offset: usize,
+        result: &mut BulkWriteResult,
+        failure: &mut ClientBulkWriteFailure,
+        any_successful: &mut bool,
+        mut session: Option<&mut ClientSession>,
+    ) -> Result<()> {
+        let mut details = self
+            .execute_operation_with_details::<BulkWrite>(&mut *op, session.as_deref_mut())
+            .await?;
+
+        let summary = &details.output.summary;
+        result.inserted_count += summary.n_inserted;
+        result.upserted_count += summary.n_upserted;
+        result.matched_count += summary.n_matched;
+        result.modified_count += summary.n_modified;
+        result.deleted_count += summary.n_deleted;
+        *any_successful |= summary.any_successful();
+        if let Some(wc_error) = details.output.write_concern_error.take() {
+            failure.write_concern_errors.push(wc_error);
+        }
+
+        let mut spec = details.output.cursor;
+        spec.info.deadline = details.deadline;
+        match session {
+            Some(session) => {
+                let pinned =
+                    self.pin_connection_for_session(&spec, &mut details.connection, session)?;
+                let mut cursor: SessionCursor<SingleWriteResult> =
+                    SessionCursor::new(self.clone(), spec, pinned);
+                let mut stream = cursor.stream(session);
+                while let Some(write_result) = stream.try_next().await? {
+                    write_result.record(models, offset, result, failure)?;
+                }
+            }
+            None => {
+                let pinned = self.pin_connection_for_cursor(&spec, &mut details.connection)?;
+                let mut cursor: Cursor<SingleWriteResult> =
+                    Cursor::new(self.clone(), spec, details.implicit_session, pinned);
+                while let Some(write_result) = cursor.try_next().await? {
+                    write_result.record(models, offset, result, failure)?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+
     /// Starts a new `ClientSession`.
     pub async fn start_session(
         &self,
@@ -626,6 +836,41 @@ impl Client {
         self.inner.topology.warm_pool().await;
     }
 
+    /// Returns a snapshot of the client's current view of the topology, including the type of the
+    /// topology and the state of each of its servers (e.g. their round trip times or the errors
+    /// that caused them to be marked as unknown).
+    pub fn topology_description(&self) -> TopologyDescription {
+        self.inner
+            .topology
+            .watch()
+            .peek_latest()
+            .description
+            .clone()
+            .into()
+    }
+
+    /// Waits for the client's view of the topology to change, returning the new description. If
+    /// `timeout` is specified and elapses before a change occurs, `None` is returned. `None` is
+    /// also returned if the client is shut down while waiting.
+    pub async fn wait_for_topology_change(
+        &self,
+        timeout: impl Into<Option<Duration>>,
+    ) -> Option<TopologyDescription> {
+        let mut watcher = self.inner.topology.watch();
+        let previous = watcher.peek_latest().description.clone();
+        watcher
+            .wait_for_description_change(&previous, timeout)
+            .await
+            .map(Into::into)
+    }
+
+    /// Returns a [`TopologyUpdates`] stream that yields a new description of the client's view of
+    /// the topology each time it changes. The stream does not yield the current description; use
+    /// [`Client::topology_description`] to obtain it.
+    pub fn topology_updates(&self) -> TopologyUpdates {
+        TopologyUpdates::new(self.inner.topology.watch())
+    }
+
     /// Check in a server session to the server session pool. The session will be discarded if it is
     /// expired or dirty.
     pub(crate) async fn check_in_server_session(&self, session: ServerSession) {
@@ -651,29 +896,58 @@ impl Client {
         criteria: Option<&SelectionCriteria>,
     ) -> Result<ServerAddress> {
         let server = self
-            .select_server(criteria, "Test select server", None)
+            .select_server(criteria, "Test select server", None, None)
             .await?;
         Ok(server.address.clone())
     }
 
     /// Select a server using the provided criteria. If none is provided, a primary read preference
     /// will be used instead.
+    ///
+    /// If an operation timeout is provided and is shorter than the server selection timeout,
+    /// selection gives up once it elapses and returns a timeout error.
     async fn select_server(
         &self,
         criteria: Option<&SelectionCriteria>,
-        #[allow(unused_variables)] // we only use the operation_name for tracing.
         operation_name: &str,
         deprioritized: Option<&ServerAddress>,
+        operation_timeout: Option<Duration>,
     ) -> Result<SelectedServer> {
+        self.select_server_with_override(
+            criteria,
+            operation_name,
+            deprioritized,
+            operation_timeout,
+            |_, _| None,
+        )
+        .await
+        .map(|(server, _)| server)
+    }
+
+    /// Select a server as in [`Client::select_server`], allowing `override_criteria` to replace
+    /// the criteria based on the observed topology. The replacement criteria, if any, are returned
+    /// alongside the selected server.
+    async fn select_server_with_override(
+        &self,
+        criteria: Option<&SelectionCriteria>,
+        #[allow(unused_variables)] // we only use the operation_name for tracing.
+        operation_name: &str,
+        deprioritized: Option<&ServerAddress>,
+        operation_timeout: Option<Duration>,
+        override_criteria: OverrideCriteriaFn,
+    ) -> Result<(SelectedServer, Option<SelectionCriteria>)> {
         let criteria =
             criteria.unwrap_or(&SelectionCriteria::ReadPreference(ReadPreference::Primary));
 
         let start_time = Instant::now();
-        let timeout = self
+        let server_selection_timeout = self
             .inner
             .options
             .server_selection_timeout
             .unwrap_or(DEFAULT_SERVER_SELECTION_TIMEOUT);
+        let timeout = operation_timeout.map_or(server_selection_timeout, |t| {
+            t.min(server_selection_timeout)
+        });
 
         #[cfg(feature = "tracing-unstable")]
         let event_emitter = ServerSelectionTracingEventEmitter::new(
@@ -692,9 +966,11 @@ impl Client {
         let mut watcher = self.inner.topology.watch();
         loop {
             let state = watcher.observe_latest();
+            let overridden = override_criteria(criteria, &state.description);
+            let effective_criteria = overridden.as_ref().unwrap_or(criteria);
 
             let result = server_selection::attempt_to_select_server(
-                criteria,
+                effective_criteria,
                 &state.description,
                 &state.servers(),
                 deprioritized,
@@ -711,7 +987,7 @@ impl Client {
                         #[cfg(feature = "tracing-unstable")]
                         event_emitter.emit_succeeded_event(&state.description, &server);
 
-                        return Ok(server);
+                        return Ok((server, overridden));
                     } else {
                         #[cfg(feature = "tracing-unstable")]
                         if !emitted_waiting_message {
@@ -726,12 +1002,19 @@ impl Client {
                                 .wait_for_update(timeout - start_time.elapsed())
                                 .await;
                         if !change_occurred {
-                            let error: Error = ErrorKind::ServerSelection {
+                            let mut error: Error = ErrorKind::ServerSelection {
                                 message: state
                                     .description
-                                    .server_selection_timeout_error_message(criteria),
+                                    .server_selection_timeout_error_message(effective_criteria),
                             }
                             .into();
+                            if timeout < server_selection_timeout {
+                                error = Error::operation_timeout(format!(
+                                    "timed out after {:?} during server selection",
+                                    timeout
+                                ))
+                                .with_source(error);
+                            }
 
                             #[cfg(feature = "tracing-unstable")]
                             event_emitter.emit_failed_event(&state.description, &error);
@@ -761,16 +1044,6 @@ impl Client {
         self.inner.topology.sync_workers().await;
     }
 
-    #[cfg(test)]
-    pub(crate) fn topology_description(&self) -> crate::sdam::TopologyDescription {
-        self.inner
-            .topology
-            .watch()
-            .peek_latest()
-            .description
-            .clone()
-    }
-
     #[cfg(test)]
     pub(crate) fn topology(&self) -> &Topology {
         &self.inner.topology
@@ -3,6 +3,8 @@
 
 #[cfg(feature = "aws-auth")]
 pub(crate) mod aws;
+#[cfg(feature = "gssapi-auth")]
+mod gssapi;
 pub(crate) mod oidc;
 mod plain;
 mod sasl;
@@ -66,7 +68,19 @@ pub enum AuthMechanism {
     ///
     /// See the [MongoDB documentation](https://www.mongodb.com/docs/manual/core/kerberos/) for more information.
     ///
-    /// Note: This mechanism is not currently supported by this driver but will be in the future.
+    /// The following mechanism properties are supported:
+    ///   * `SERVICE_NAME`: the service name of the server's principal. Defaults to "mongodb".
+    ///   * `CANONICALIZE_HOST_NAME`: how to canonicalize the server's hostname before constructing
+    ///     its principal name. One of "none" (or false), "forward", or "forwardAndReverse" (or
+    ///     true). Defaults to "none".
+    ///   * `SERVICE_REALM`: the realm of the server's principal, if it differs from the user's.
+    ///   * `SERVICE_HOST`: the hostname to use in the server's principal instead of the one used
+    ///     to connect to it.
+    ///
+    /// Credentials are acquired from the environment (e.g. a ticket cache populated by `kinit`),
+    /// so a password does not need to be provided.
+    ///
+    /// Note: Authenticating with this mechanism requires the `gssapi-auth` feature flag.
     Gssapi,
 
     /// The SASL PLAIN mechanism, as defined in [RFC 4616](), is used in MongoDB to perform LDAP
@@ -183,11 +197,30 @@ impl AuthMechanism {
 
                 Ok(())
             }
+            AuthMechanism::Gssapi => {
+                if credential.username.is_none() {
+                    return Err(Error::invalid_argument(
+                        "No username provided for GSSAPI authentication",
+                    ));
+                }
+                if credential
+                    .source
+                    .as_ref()
+                    .map_or(false, |s| s != "$external")
+                {
+                    return Err(Error::invalid_argument(
+                        "only $external may be specified as an auth source for GSSAPI",
+                    ));
+                }
+                #[cfg(feature = "gssapi-auth")]
+                gssapi::GssapiProperties::from_credential(credential)?;
+                Ok(())
+            }
             AuthMechanism::MongoDbOidc => {
                 let is_automatic = credential
                     .mechanism_properties
                     .as_ref()
-                    .map_or(false, |p| p.contains_key("PROVIDER_NAME"));
+                    .map_or(false, |p| p.contains_key(oidc::PROVIDER_NAME));
                 if credential.username.is_some() && is_automatic {
                     return Err(Error::invalid_argument(
                         "username and PROVIDER_NAME cannot both be specified for MONGODB-OIDC \
@@ -208,6 +241,13 @@ impl AuthMechanism {
                         "password must not be set for MONGODB-OIDC authentication",
                     ));
                 }
+                if is_automatic && credential.oidc_callbacks.is_some() {
+                    return Err(Error::invalid_argument(
+                        "callbacks and PROVIDER_NAME cannot both be specified for MONGODB-OIDC \
+                         authentication",
+                    ));
+                }
+                oidc::Provider::from_credential(credential)?;
                 Ok(())
             }
             _ => Ok(()),
@@ -241,7 +281,7 @@ impl AuthMechanism {
             AuthMechanism::MongoDbOidc => "$external",
             #[cfg(feature = "aws-auth")]
             AuthMechanism::MongoDbAws => "$external",
-            AuthMechanism::Gssapi => "",
+            AuthMechanism::Gssapi => "$external",
         }
     }
 
@@ -268,6 +308,7 @@ impl AuthMechanism {
             )))),
             Self::Plain => Ok(None),
             Self::MongoDbOidc => Ok(None),
+            Self::Gssapi => Ok(None),
             #[cfg(feature = "aws-auth")]
             AuthMechanism::MongoDbAws => Ok(None),
             AuthMechanism::MongoDbCr => Err(ErrorKind::Authentication {
@@ -276,10 +317,6 @@ impl AuthMechanism {
                     .into(),
             }
             .into()),
-            _ => Err(ErrorKind::Authentication {
-                message: format!("Authentication mechanism {:?} not yet implemented.", self),
-            }
-            .into()),
         }
     }
 
@@ -322,8 +359,15 @@ impl AuthMechanism {
             AuthMechanism::MongoDbOidc => {
                 oidc::authenticate_stream(stream, credential, server_api).await
             }
-            _ => Err(ErrorKind::Authentication {
-                message: format!("Authentication mechanism {:?} not yet implemented.", self),
+            #[cfg(feature = "gssapi-auth")]
+            AuthMechanism::Gssapi => {
+                gssapi::authenticate_stream(stream, credential, server_api).await
+            }
+            #[cfg(not(feature = "gssapi-auth"))]
+            AuthMechanism::Gssapi => Err(ErrorKind::Authentication {
+                message: "GSSAPI authentication is only supported with the gssapi-auth feature \
+                          flag"
+                    .into(),
             }
             .into()),
         }
@@ -394,6 +438,12 @@ pub struct Credential {
     #[serde(skip)]
     #[derivative(Debug = "ignore", PartialEq = "ignore")]
     pub(crate) oidc_callbacks: Option<oidc::Callbacks>,
+
+    /// The cache of OIDC tokens obtained for this credential, shared by all of its clones.
+    #[serde(skip)]
+    #[derivative(Debug = "ignore", PartialEq = "ignore")]
+    #[builder(setter(skip))]
+    pub(crate) oidc_cache: oidc::Cache,
 }
 
 impl Credential {
@@ -464,6 +514,24 @@ impl Credential {
             .await
     }
 
+    /// Reauthenticates a connection after the server has returned a `ReauthenticationRequired`
+    /// error. This is only supported by mechanisms whose credentials can expire.
+    pub(crate) async fn reauthenticate_stream(
+        &self,
+        conn: &mut Connection,
+        server_api: Option<&ServerApi>,
+    ) -> Result<()> {
+        match self.mechanism {
+            Some(AuthMechanism::MongoDbOidc) => {
+                oidc::reauthenticate_stream(conn, self, server_api).await
+            }
+            ref mechanism => Err(Error::authentication_error(
+                mechanism.as_ref().map_or("unknown", AuthMechanism::as_str),
+                "reauthentication is not supported by this mechanism",
+            )),
+        }
+    }
+
     #[cfg(test)]
     pub(crate) fn serialize_for_client_options<S>(
         credential: &Option<Credential>,
@@ -0,0 +1,216 @@
+use cross_krb5::{ClientCtx, InitiateFlags, K5Ctx, PendingClientCtx, Step};
+
+use crate::{
+    bson::{Bson, Document},
+    client::{
+        auth::{
+            sasl::{SaslContinue, SaslResponse, SaslStart},
+            AuthMechanism,
+            Credential,
+            GSSAPI_STR,
+        },
+        options::ServerApi,
+    },
+    cmap::Connection,
+    error::{Error, Result},
+    runtime::AsyncResolver,
+};
+
+pub(crate) const SERVICE_NAME: &str = "SERVICE_NAME";
+pub(crate) const CANONICALIZE_HOST_NAME: &str = "CANONICALIZE_HOST_NAME";
+pub(crate) const SERVICE_REALM: &str = "SERVICE_REALM";
+pub(crate) const SERVICE_HOST: &str = "SERVICE_HOST";
+
+pub(crate) const DEFAULT_SERVICE_NAME: &str = "mongodb";
+
+/// How the hostname of the server should be canonicalized before being used to construct the
+/// service principal name.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) enum CanonicalizeHostName {
+    /// Use the hostname as-is.
+    None,
+
+    /// Resolve the hostname to its canonical name by following CNAME records.
+    Forward,
+
+    /// Resolve the hostname to its canonical name, then perform a reverse lookup on its address.
+    ForwardAndReverse,
+}
+
+impl CanonicalizeHostName {
+    fn from_bson(value: Option<&Bson>) -> Result<Self> {
+        match value {
+            None | Some(Bson::Boolean(false)) => Ok(Self::None),
+            Some(Bson::Boolean(true)) => Ok(Self::ForwardAndReverse),
+            Some(Bson::String(s)) => match s.as_str() {
+                "none" | "false" => Ok(Self::None),
+                "forward" => Ok(Self::Forward),
+                "forwardAndReverse" | "true" => Ok(Self::ForwardAndReverse),
+                other => Err(Error::invalid_argument(format!(
+                    "invalid {} value for {}: {}",
+                    CANONICALIZE_HOST_NAME, GSSAPI_STR, other
+                ))),
+            },
+            Some(other) => Err(Error::invalid_argument(format!(
+                "invalid {} value for {}: {}",
+                CANONICALIZE_HOST_NAME, GSSAPI_STR, other
+            ))),
+        }
+    }
+}
+
+/// The GSSAPI mechanism properties specified in a `Credential`.
+#[derive(Debug)]
+pub(crate) struct GssapiProperties {
+    pub(crate) service_name: String,
+    pub(crate) canonicalize_host_name: CanonicalizeHostName,
+    pub(crate) service_realm: Option<String>,
+    pub(crate) service_host: Option<String>,
+}
+
+impl GssapiProperties {
+    pub(crate) fn from_credential(credential: &Credential) -> Result<Self> {
+        let empty = Document::new();
+        let properties = credential.mechanism_properties.as_ref().unwrap_or(&empty);
+        let get_str = |key: &str| -> Result<Option<String>> {
+            match properties.get(key) {
+                None => Ok(None),
+                Some(Bson::String(s)) => Ok(Some(s.clone())),
+                Some(other) => Err(Error::invalid_argument(format!(
+                    "invalid {} value for {}: {}",
+                    key, GSSAPI_STR, other
+                ))),
+            }
+        };
+
+        Ok(Self {
+            service_name: get_str(SERVICE_NAME)?.unwrap_or_else(|| DEFAULT_SERVICE_NAME.into()),
+            canonicalize_host_name: CanonicalizeHostName::from_bson(
+                properties.get(CANONICALIZE_HOST_NAME),
+            )?,
+            service_realm: get_str(SERVICE_REALM)?,
+            service_host: get_str(SERVICE_HOST)?,
+        })
+    }
+
+    /// Constructs the service principal name for the given server hostname, e.g.
+    /// "mongodb/db.example.com@EXAMPLE.COM".
+    pub(crate) fn service_principal(&self, host: &str) -> String {
+        let host = self.service_host.as_deref().unwrap_or(host);
+        match self.service_realm {
+            Some(ref realm) => format!("{}/{}@{}", self.service_name, host, realm),
+            None => format!("{}/{}", self.service_name, host),
+        }
+    }
+}
+
+/// The state of the client's GSSAPI security context during the SASL conversation.
+enum ContextState {
+    Pending(PendingClientCtx),
+    Established(ClientCtx),
+}
+
+/// Performs GSSAPI (Kerberos) authentication for a given stream.
+///
+/// The credentials used are those available to the current process (e.g. a ticket cache populated
+/// by `kinit` or a keytab); a password provided in the `Credential` is not used to acquire them.
+pub(crate) async fn authenticate_stream(
+    conn: &mut Connection,
+    credential: &Credential,
+    server_api: Option<&ServerApi>,
+) -> Result<()> {
+    let properties = GssapiProperties::from_credential(credential)?;
+    let user_principal = credential
+        .username
+        .as_deref()
+        .ok_or_else(|| Error::authentication_error(GSSAPI_STR, "no username supplied"))?;
+    let source = credential.source.as_deref().unwrap_or("$external");
+
+    let host = canonicalize_host_name(
+        conn.address().host().as_ref(),
+        properties.canonicalize_host_name,
+    )
+    .await?;
+    let service_principal = properties.service_principal(&host);
+
+    let (pending, token) = ClientCtx::new(
+        InitiateFlags::empty(),
+        Some(user_principal),
+        &service_principal,
+        None,
+    )
+    .map_err(gssapi_error)?;
+    let mut state = ContextState::Pending(pending);
+
+    let sasl_start = SaslStart::new(
+        source.into(),
+        AuthMechanism::Gssapi,
+        token.to_vec(),
+        server_api.cloned(),
+    )
+    .into_command();
+    let response = conn.send_command(sasl_start, None).await?;
+    let mut sasl_response =
+        SaslResponse::parse(GSSAPI_STR, response.auth_response_body(GSSAPI_STR)?)?;
+
+    while !sasl_response.done {
+        let payload = match state {
+            ContextState::Pending(pending) => {
+                match pending.step(&sasl_response.payload).map_err(gssapi_error)? {
+                    Step::Continue((pending, token)) => {
+                        let payload = token.to_vec();
+                        state = ContextState::Pending(pending);
+                        payload
+                    }
+                    Step::Finished((ctx, token)) => {
+                        let payload = token.map(|t| t.to_vec()).unwrap_or_default();
+                        state = ContextState::Established(ctx);
+                        payload
+                    }
+                }
+            }
+            ContextState::Established(ref mut ctx) => {
+                // Once the security context is established, the server sends a wrapped message
+                // describing the security layers it supports. As MongoDB connections are
+                // protected by TLS if at all, no security layer is requested in the response, per
+                // RFC 4752 section 3.1.
+                ctx.unwrap(&sasl_response.payload).map_err(gssapi_error)?;
+                let mut message = vec![1, 0, 0, 0];
+                message.extend(user_principal.as_bytes());
+                let wrapped = ctx.wrap(false, &message).map_err(gssapi_error)?;
+                wrapped.to_vec()
+            }
+        };
+
+        let sasl_continue = SaslContinue::new(
+            source.into(),
+            sasl_response.conversation_id.clone(),
+            payload,
+            server_api.cloned(),
+        )
+        .into_command();
+        let response = conn.send_command(sasl_continue, None).await?;
+        sasl_response = SaslResponse::parse(GSSAPI_STR, response.auth_response_body(GSSAPI_STR)?)?;
+    }
+
+    Ok(())
+}
+
+async fn canonicalize_host_name(host: &str, mode: CanonicalizeHostName) -> Result<String> {
+    if mode == CanonicalizeHostName::None {
+        return Ok(host.to_string());
+    }
+
+    let resolver = AsyncResolver::new(None).await?;
+    let (canonical, address) = resolver.canonical_name(host).await?;
+    if mode == CanonicalizeHostName::ForwardAndReverse {
+        if let Some(name) = resolver.reverse_lookup(address).await? {
+            return Ok(name);
+        }
+    }
+    Ok(canonical)
+}
+
+fn gssapi_error(error: impl std::fmt::Display) -> Error {
+    Error::authentication_error(GSSAPI_STR, &error.to_string())
+}
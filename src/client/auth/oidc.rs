@@ -1,10 +1,12 @@
 use std::{
+    path::PathBuf,
     sync::Arc,
     time::{Duration, Instant},
 };
 
 use bson::rawdoc;
 use serde::Deserialize;
+use tokio::sync::Mutex;
 use typed_builder::TypedBuilder;
 
 use crate::{
@@ -21,6 +23,22 @@ use crate::{
 
 use super::{sasl::SaslContinue, Credential, MONGODB_OIDC_STR};
 
+/// The mechanism property used to select a built-in token provider.
+pub(crate) const PROVIDER_NAME: &str = "PROVIDER_NAME";
+
+/// The mechanism property (and environment variable) specifying the path of the file read by the
+/// "file" provider.
+pub(crate) const OIDC_TOKEN_FILE: &str = "OIDC_TOKEN_FILE";
+
+/// The environment variable read by the "env" provider.
+pub(crate) const OIDC_TOKEN_ENV: &str = "OIDC_TOKEN";
+
+const FILE_PROVIDER: &str = "file";
+const ENV_PROVIDER: &str = "env";
+
+/// The amount of time given to a callback to produce a token.
+const CALLBACK_TIMEOUT: Duration = Duration::from_secs(5 * 60);
+
 /// The user-supplied callbacks for OIDC authentication.
 #[derive(Clone)]
 pub struct Callbacks {
@@ -29,6 +47,12 @@ pub struct Callbacks {
 
 pub type BoxFuture<'a, T> = std::pin::Pin<Box<dyn std::future::Future<Output = T> + Send + 'a>>;
 
+type Callback = Box<
+    dyn Fn(IdpServerInfo, RequestParameters) -> BoxFuture<'static, Result<IdpServerResponse>>
+        + Send
+        + Sync,
+>;
+
 impl Callbacks {
     /// Create a new instance with a token request callback.
     pub fn new<F>(on_request: F) -> Self
@@ -41,6 +65,32 @@ impl Callbacks {
         Self {
             inner: Arc::new(CallbacksInner {
                 on_request: Box::new(on_request),
+                on_refresh: None,
+            }),
+        }
+    }
+
+    /// Create a new instance with a token request callback and a token refresh callback.
+    ///
+    /// When the identity provider returned a refresh token alongside a previous access token, the
+    /// refresh callback will be called with it (via [`RequestParameters::refresh_token`]) in
+    /// place of the request callback to obtain a new access token. If the refresh callback fails,
+    /// the request callback will be called instead.
+    pub fn with_refresh<F, G>(on_request: F, on_refresh: G) -> Self
+    where
+        F: Fn(IdpServerInfo, RequestParameters) -> BoxFuture<'static, Result<IdpServerResponse>>
+            + Send
+            + Sync
+            + 'static,
+        G: Fn(IdpServerInfo, RequestParameters) -> BoxFuture<'static, Result<IdpServerResponse>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        Self {
+            inner: Arc::new(CallbacksInner {
+                on_request: Box::new(on_request),
+                on_refresh: Some(Box::new(on_refresh)),
             }),
         }
     }
@@ -53,15 +103,11 @@ impl std::fmt::Debug for Callbacks {
 }
 
 struct CallbacksInner {
-    on_request: Box<
-        dyn Fn(IdpServerInfo, RequestParameters) -> BoxFuture<'static, Result<IdpServerResponse>>
-            + Send
-            + Sync,
-    >,
-    // on_refresh: Option<Box<dyn Fn(&IdpServerInfo) -> IdpServerResponse + Send + Sync>>,
+    on_request: Callback,
+    on_refresh: Option<Callback>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
 #[non_exhaustive]
 pub struct IdpServerInfo {
@@ -83,6 +129,176 @@ pub struct IdpServerResponse {
 #[non_exhaustive]
 pub struct RequestParameters {
     pub deadline: Instant,
+
+    /// The refresh token previously returned by the identity provider. This is only set when
+    /// invoking a refresh callback.
+    pub refresh_token: Option<String>,
+}
+
+/// A cache of the tokens obtained for a `Credential`. Clones of a credential share the same cache,
+/// so all connections authenticated with it reuse the same access token until it expires or is
+/// rejected by the server.
+#[derive(Clone, Default)]
+pub(crate) struct Cache {
+    inner: Arc<Mutex<CacheInner>>,
+}
+
+#[derive(Default)]
+struct CacheInner {
+    access_token: Option<String>,
+    expires: Option<Instant>,
+    refresh_token: Option<String>,
+}
+
+impl CacheInner {
+    fn valid_access_token(&self) -> Option<&String> {
+        match self.expires {
+            Some(expires) if expires <= Instant::now() => None,
+            _ => self.access_token.as_ref(),
+        }
+    }
+
+    fn store(&mut self, response: IdpServerResponse) -> String {
+        self.access_token = Some(response.access_token.clone());
+        self.expires = response.expires;
+        if response.refresh_token.is_some() {
+            self.refresh_token = response.refresh_token;
+        }
+        response.access_token
+    }
+}
+
+impl Cache {
+    /// Returns the cached access token, if there is one that has not expired.
+    pub(crate) async fn access_token(&self) -> Option<String> {
+        self.inner.lock().await.valid_access_token().cloned()
+    }
+
+    /// Removes `token` from the cache if it is still the current access token. Any refresh token
+    /// is kept so that a new access token can be obtained without user interaction.
+    pub(crate) async fn invalidate(&self, token: &str) {
+        let mut inner = self.inner.lock().await;
+        if inner.access_token.as_deref() == Some(token) {
+            inner.access_token = None;
+            inner.expires = None;
+        }
+    }
+
+    /// Returns a valid access token, obtaining one from `provider` if none is cached. The cache is
+    /// locked for the duration of the call so that concurrent connections do not each fetch a new
+    /// token.
+    async fn get_or_fetch(&self, provider: &Provider) -> Result<String> {
+        let mut inner = self.inner.lock().await;
+        if let Some(token) = inner.valid_access_token() {
+            return Ok(token.clone());
+        }
+        let token = provider.read_token()?;
+        inner.access_token = Some(token.clone());
+        inner.expires = None;
+        Ok(token)
+    }
+
+    /// Returns a valid access token, calling the user-supplied callbacks to obtain one if none is
+    /// cached.
+    pub(crate) async fn get_or_request(
+        &self,
+        callbacks: &Callbacks,
+        server_info: IdpServerInfo,
+    ) -> Result<String> {
+        let mut inner = self.inner.lock().await;
+        if let Some(token) = inner.valid_access_token() {
+            return Ok(token.clone());
+        }
+
+        if let (Some(on_refresh), Some(refresh_token)) = (
+            callbacks.inner.on_refresh.as_ref(),
+            inner.refresh_token.clone(),
+        ) {
+            let params = RequestParameters {
+                deadline: Instant::now() + CALLBACK_TIMEOUT,
+                refresh_token: Some(refresh_token),
+            };
+            match on_refresh(server_info.clone(), params).await {
+                Ok(response) => return Ok(inner.store(response)),
+                // The refresh token may have expired or been revoked, so fall back to requesting
+                // a new token from scratch.
+                Err(_) => inner.refresh_token = None,
+            }
+        }
+
+        let params = RequestParameters {
+            deadline: Instant::now() + CALLBACK_TIMEOUT,
+            refresh_token: None,
+        };
+        let response = (callbacks.inner.on_request)(server_info, params).await?;
+        Ok(inner.store(response))
+    }
+}
+
+/// A built-in source of access tokens for machine workflows, selected by the `PROVIDER_NAME`
+/// mechanism property.
+#[derive(Debug, PartialEq)]
+pub(crate) enum Provider {
+    /// Reads the token from the file specified by the `OIDC_TOKEN_FILE` mechanism property or
+    /// environment variable.
+    File(PathBuf),
+
+    /// Reads the token from the `OIDC_TOKEN` environment variable.
+    Env,
+}
+
+impl Provider {
+    pub(crate) fn from_credential(credential: &Credential) -> Result<Option<Self>> {
+        let properties = match credential.mechanism_properties.as_ref() {
+            Some(properties) => properties,
+            None => return Ok(None),
+        };
+        let name = match properties.get(PROVIDER_NAME) {
+            Some(name) => name.as_str().ok_or_else(|| {
+                Error::invalid_argument(format!(
+                    "{} must be a string for {} authentication",
+                    PROVIDER_NAME, MONGODB_OIDC_STR
+                ))
+            })?,
+            None => return Ok(None),
+        };
+
+        match name {
+            FILE_PROVIDER => {
+                let path = match properties.get_str(OIDC_TOKEN_FILE) {
+                    Ok(path) => path.to_string(),
+                    Err(_) => std::env::var(OIDC_TOKEN_FILE).map_err(|_| {
+                        Error::invalid_argument(format!(
+                            "the {} provider requires {} to be specified as a mechanism property \
+                             or environment variable",
+                            FILE_PROVIDER, OIDC_TOKEN_FILE
+                        ))
+                    })?,
+                };
+                Ok(Some(Self::File(path.into())))
+            }
+            ENV_PROVIDER => Ok(Some(Self::Env)),
+            other => Err(Error::invalid_argument(format!(
+                "unsupported {} for {} authentication: {}",
+                PROVIDER_NAME, MONGODB_OIDC_STR, other
+            ))),
+        }
+    }
+
+    fn read_token(&self) -> Result<String> {
+        let token = match self {
+            Self::File(path) => std::fs::read_to_string(path).map_err(|e| {
+                auth_error(format!(
+                    "failed to read token from {}: {}",
+                    path.display(),
+                    e
+                ))
+            })?,
+            Self::Env => std::env::var(OIDC_TOKEN_ENV)
+                .map_err(|_| auth_error(format!("{} is not set", OIDC_TOKEN_ENV)))?,
+        };
+        Ok(token.trim().to_string())
+    }
 }
 
 pub(crate) async fn authenticate_stream(
@@ -91,11 +307,32 @@ pub(crate) async fn authenticate_stream(
     server_api: Option<&ServerApi>,
 ) -> Result<()> {
     let source = credential.source.as_deref().unwrap_or("$external");
+    let cache = &credential.oidc_cache;
+
+    // A cached token can be sent directly without first asking the server for the identity
+    // provider's information. If the server rejects it, it is discarded and a new one obtained.
+    if let Some(token) = cache.access_token().await {
+        match authenticate_with_token(conn, source, &token, server_api).await {
+            Ok(()) => {
+                conn.oidc_access_token = Some(token);
+                return Ok(());
+            }
+            Err(e) if e.is_auth_error() => cache.invalidate(&token).await,
+            Err(e) => return Err(e),
+        }
+    }
+
+    if let Some(provider) = Provider::from_credential(credential)? {
+        let token = cache.get_or_fetch(&provider).await?;
+        authenticate_with_token(conn, source, &token, server_api).await?;
+        conn.oidc_access_token = Some(token);
+        return Ok(());
+    }
+
     let callbacks = credential
         .oidc_callbacks
         .as_ref()
-        .ok_or_else(|| auth_error("no callbacks supplied"))?
-        .clone();
+        .ok_or_else(|| auth_error("no callbacks supplied"))?;
 
     let mut start_doc = rawdoc! {};
     if let Some(username) = credential.username.as_deref() {
@@ -112,20 +349,14 @@ pub(crate) async fn authenticate_stream(
     if response.done {
         return Err(invalid_auth_response());
     }
-    let idp_response = {
-        let server_info: IdpServerInfo =
-            bson::from_slice(&response.payload).map_err(|_| invalid_auth_response())?;
-        const CALLBACK_TIMEOUT: Duration = Duration::from_secs(5 * 60);
-        let cb_params = RequestParameters {
-            deadline: Instant::now() + CALLBACK_TIMEOUT,
-        };
-        (callbacks.inner.on_request)(server_info, cb_params).await?
-    };
+    let server_info: IdpServerInfo =
+        bson::from_slice(&response.payload).map_err(|_| invalid_auth_response())?;
+    let token = cache.get_or_request(callbacks, server_info).await?;
 
     let sasl_continue = SaslContinue::new(
         source.to_string(),
         response.conversation_id,
-        rawdoc! { "jwt": idp_response.access_token }.into_bytes(),
+        rawdoc! { "jwt": token.as_str() }.into_bytes(),
         server_api.cloned(),
     )
     .into_command();
@@ -133,7 +364,43 @@ pub(crate) async fn authenticate_stream(
     if !response.done {
         return Err(invalid_auth_response());
     }
+    conn.oidc_access_token = Some(token);
+
+    Ok(())
+}
+
+/// Reauthenticates a connection after the server has indicated that its access token has expired.
+/// The token the connection was authenticated with is discarded from the cache before a new one is
+/// obtained.
+pub(crate) async fn reauthenticate_stream(
+    conn: &mut Connection,
+    credential: &Credential,
+    server_api: Option<&ServerApi>,
+) -> Result<()> {
+    if let Some(token) = conn.oidc_access_token.take() {
+        credential.oidc_cache.invalidate(&token).await;
+    }
+    authenticate_stream(conn, credential, server_api).await
+}
 
+/// Performs one-step authentication by sending `token` in the `saslStart` command.
+async fn authenticate_with_token(
+    conn: &mut Connection,
+    source: &str,
+    token: &str,
+    server_api: Option<&ServerApi>,
+) -> Result<()> {
+    let sasl_start = SaslStart::new(
+        source.to_string(),
+        AuthMechanism::MongoDbOidc,
+        rawdoc! { "jwt": token }.into_bytes(),
+        server_api.cloned(),
+    )
+    .into_command();
+    let response = send_sasl_command(conn, sasl_start).await?;
+    if !response.done {
+        return Err(invalid_auth_response());
+    }
     Ok(())
 }
 
@@ -99,3 +99,178 @@ fn sasl_first_options_not_specified() {
         "SaslStart should not contain options document for X.509 authentication"
     );
 }
+
+#[cfg(feature = "gssapi-auth")]
+#[test]
+fn gssapi_service_principal() {
+    use super::gssapi::{CanonicalizeHostName, GssapiProperties};
+    use crate::{
+        bson::{doc, Bson},
+        options::Credential,
+    };
+
+    let credential = Credential::builder()
+        .username("user@EXAMPLE.COM".to_string())
+        .mechanism(AuthMechanism::Gssapi)
+        .build();
+    let properties = GssapiProperties::from_credential(&credential).unwrap();
+    assert_eq!(
+        properties.canonicalize_host_name,
+        CanonicalizeHostName::None
+    );
+    assert_eq!(
+        properties.service_principal("db.example.com"),
+        "mongodb/db.example.com"
+    );
+
+    let credential = Credential::builder()
+        .username("user@EXAMPLE.COM".to_string())
+        .mechanism(AuthMechanism::Gssapi)
+        .mechanism_properties(doc! {
+            "SERVICE_NAME": "other",
+            "SERVICE_REALM": "OTHER.COM",
+            "CANONICALIZE_HOST_NAME": "forward",
+        })
+        .build();
+    let properties = GssapiProperties::from_credential(&credential).unwrap();
+    assert_eq!(
+        properties.canonicalize_host_name,
+        CanonicalizeHostName::Forward
+    );
+    assert_eq!(
+        properties.service_principal("db.example.com"),
+        "other/db.example.com@OTHER.COM"
+    );
+
+    for (value, expected) in [
+        (Bson::Boolean(true), CanonicalizeHostName::ForwardAndReverse),
+        (
+            Bson::String("true".to_string()),
+            CanonicalizeHostName::ForwardAndReverse,
+        ),
+        (
+            Bson::String("false".to_string()),
+            CanonicalizeHostName::None,
+        ),
+    ] {
+        let credential = Credential::builder()
+            .username("user@EXAMPLE.COM".to_string())
+            .mechanism(AuthMechanism::Gssapi)
+            .mechanism_properties(doc! { "CANONICALIZE_HOST_NAME": value })
+            .build();
+        let properties = GssapiProperties::from_credential(&credential).unwrap();
+        assert_eq!(properties.canonicalize_host_name, expected);
+    }
+
+    let credential = Credential::builder()
+        .username("user@EXAMPLE.COM".to_string())
+        .mechanism(AuthMechanism::Gssapi)
+        .mechanism_properties(doc! { "CANONICALIZE_HOST_NAME": "sideways" })
+        .build();
+    assert!(GssapiProperties::from_credential(&credential).is_err());
+}
+
+#[test]
+fn oidc_provider_from_credential() {
+    use super::oidc::Provider;
+    use crate::{bson::doc, options::Credential};
+
+    let credential = |properties| {
+        Credential::builder()
+            .mechanism(AuthMechanism::MongoDbOidc)
+            .mechanism_properties(properties)
+            .build()
+    };
+
+    assert_eq!(
+        Provider::from_credential(&credential(doc! {
+            "PROVIDER_NAME": "file",
+            "OIDC_TOKEN_FILE": "/tmp/tokens/test_user1",
+        }))
+        .unwrap(),
+        Some(Provider::File("/tmp/tokens/test_user1".into()))
+    );
+    assert_eq!(
+        Provider::from_credential(&credential(doc! { "PROVIDER_NAME": "env" })).unwrap(),
+        Some(Provider::Env)
+    );
+    assert_eq!(
+        Provider::from_credential(&credential(doc! {})).unwrap(),
+        None
+    );
+    assert!(Provider::from_credential(&credential(doc! { "PROVIDER_NAME": "aws" })).is_err());
+    assert!(Provider::from_credential(&credential(doc! { "PROVIDER_NAME": 1 })).is_err());
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+async fn oidc_cache_prefers_refresh_callback() {
+    use std::sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    };
+
+    use futures_util::FutureExt;
+
+    use super::oidc::{Cache, Callbacks, IdpServerInfo, IdpServerResponse};
+    use crate::bson::doc;
+
+    let requests = Arc::new(AtomicUsize::new(0));
+    let refreshes = Arc::new(AtomicUsize::new(0));
+    let callbacks = {
+        let requests = requests.clone();
+        let refreshes = refreshes.clone();
+        Callbacks::with_refresh(
+            move |_info, params| {
+                assert!(params.refresh_token.is_none());
+                let n = requests.fetch_add(1, Ordering::SeqCst);
+                async move {
+                    Ok(IdpServerResponse::builder()
+                        .access_token(format!("request-{}", n))
+                        .expires(None)
+                        .refresh_token(Some("refresh".to_string()))
+                        .build())
+                }
+                .boxed()
+            },
+            move |_info, params| {
+                assert_eq!(params.refresh_token.as_deref(), Some("refresh"));
+                let n = refreshes.fetch_add(1, Ordering::SeqCst);
+                async move {
+                    Ok(IdpServerResponse::builder()
+                        .access_token(format!("refresh-{}", n))
+                        .expires(None)
+                        .refresh_token(None)
+                        .build())
+                }
+                .boxed()
+            },
+        )
+    };
+    let server_info: IdpServerInfo =
+        crate::bson::from_document(doc! { "issuer": "issuer", "clientId": "client" }).unwrap();
+
+    let cache = Cache::default();
+    let token = cache
+        .get_or_request(&callbacks, server_info.clone())
+        .await
+        .unwrap();
+    assert_eq!(token, "request-0");
+
+    // The cached token is reused until it is invalidated.
+    let token = cache
+        .get_or_request(&callbacks, server_info.clone())
+        .await
+        .unwrap();
+    assert_eq!(token, "request-0");
+
+    // Invalidating a stale token has no effect.
+    cache.invalidate("other").await;
+    assert_eq!(cache.access_token().await.as_deref(), Some("request-0"));
+
+    cache.invalidate("request-0").await;
+    let token = cache.get_or_request(&callbacks, server_info).await.unwrap();
+    assert_eq!(token, "refresh-0");
+    assert_eq!(requests.load(Ordering::SeqCst), 1);
+    assert_eq!(refreshes.load(Ordering::SeqCst), 1);
+}
@@ -20,11 +20,11 @@ use crate::{
         RawDocumentBuf,
     },
     client::options::TlsOptions,
-    coll::options::CollectionOptions,
+    coll::options::{CollectionOptions, CollectionWriteModel, UpdateOne},
     db::options::CreateCollectionOptions,
     error::{Error, Result},
     options::{ReadConcern, WriteConcern},
-    results::DeleteResult,
+    results::{BulkWriteResult, DeleteResult},
     Client,
     Collection,
     Cursor,
@@ -165,9 +165,76 @@ impl ClientEncryption {
         Ok(builder.build_datakey()?)
     }
 
-    // pub async fn rewrap_many_data_key(&self, _filter: Document, _opts: impl
-    // Into<Option<RewrapManyDataKeyOptions>>) -> Result<RewrapManyDataKeyResult> {
-    // todo!("RUST-1441") }
+    /// Decrypts multiple data keys and (re-)encrypts them with a new `master_key`, or with their
+    /// current `master_key` if a new one is not given. The updated fields of the re-encrypted data
+    /// keys are written back to the key vault collection with a bulk write; the result of that
+    /// write is returned, or `None` if no data keys matched `filter`.
+    pub async fn rewrap_many_data_key(
+        &self,
+        filter: Document,
+        opts: impl Into<Option<RewrapManyDataKeyOptions>>,
+    ) -> Result<RewrapManyDataKeyResult> {
+        let ctx = self.create_rewrap_many_data_key_ctx(&filter, opts.into().as_ref())?;
+        let result = self.exec.run_ctx(ctx, None).await?;
+
+        let mut models: Vec<CollectionWriteModel<RawDocumentBuf>> = Vec::new();
+        let keys = result
+            .get_array("v")
+            .map_err(|e| Error::internal(format!("invalid rewrap result: {}", e)))?;
+        for key in keys {
+            let key = key?
+                .as_document()
+                .ok_or_else(|| Error::internal("invalid rewrapped data key"))?;
+            let id = key
+                .get_binary("_id")
+                .map_err(|e| Error::internal(format!("invalid data key id: {}", e)))?;
+            let master_key = key
+                .get_document("masterKey")
+                .map_err(|e| Error::internal(format!("invalid data key master key: {}", e)))?;
+            let key_material = key
+                .get_binary("keyMaterial")
+                .map_err(|e| Error::internal(format!("invalid data key material: {}", e)))?;
+            models.push(
+                UpdateOne::builder()
+                    .filter(doc! { "_id": id.to_binary() })
+                    .update(doc! {
+                        "$set": {
+                            "masterKey": master_key.to_document()?,
+                            "keyMaterial": key_material.to_binary(),
+                        },
+                        "$currentDate": { "updateDate": true },
+                    })
+                    .build()
+                    .into(),
+            );
+        }
+
+        if models.is_empty() {
+            return Ok(RewrapManyDataKeyResult {
+                bulk_write_result: None,
+            });
+        }
+        let bulk_write_result = self.key_vault.bulk_write(models, None).await?;
+        Ok(RewrapManyDataKeyResult {
+            bulk_write_result: Some(bulk_write_result),
+        })
+    }
+
+    fn create_rewrap_many_data_key_ctx(
+        &self,
+        filter: &Document,
+        opts: Option<&RewrapManyDataKeyOptions>,
+    ) -> Result<Ctx> {
+        let mut builder = self.crypt.ctx_builder();
+        if let Some(opts) = opts {
+            let mut key_doc = doc! { "provider": opts.provider.name() };
+            if let Some(master_key) = &opts.master_key {
+                key_doc.extend(master_key.clone());
+            }
+            builder = builder.key_encryption_key(&key_doc)?;
+        }
+        Ok(builder.build_rewrap_many_datakey(&RawDocumentBuf::from_document(filter)?)?)
+    }
 
     /// Removes the key document with the given UUID (BSON binary subtype 0x04) from the key vault
     /// collection. Returns the result of the internal deleteOne() operation on the key vault
@@ -563,17 +630,30 @@ impl MasterKey {
     }
 }
 
-// #[non_exhaustive]
-// pub struct RewrapManyDataKeyOptions {
-// pub provider: KmsProvider,
-// pub master_key: Option<Document>,
-// }
-//
-//
-// #[non_exhaustive]
-// pub struct RewrapManyDataKeyResult {
-// pub bulk_write_result: Option<BulkWriteResult>,
-// }
+/// The options for rewrapping data keys with
+/// [`ClientEncryption::rewrap_many_data_key`].
+#[derive(Debug, Clone, Deserialize, TypedBuilder)]
+#[serde(rename_all = "camelCase")]
+#[builder(field_defaults(setter(into)))]
+#[non_exhaustive]
+pub struct RewrapManyDataKeyOptions {
+    /// The KMS provider to re-encrypt the data keys with.
+    pub provider: KmsProvider,
+
+    /// The KMS-specific master key fields to re-encrypt the data keys with, in the same form as
+    /// the fields of a [`MasterKey`]. Must be unset for [`KmsProvider::Local`].
+    #[builder(default)]
+    pub master_key: Option<Document>,
+}
+
+/// The result of [`ClientEncryption::rewrap_many_data_key`].
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub struct RewrapManyDataKeyResult {
+    /// The result of the bulk write used to update the rewrapped data keys in the key vault
+    /// collection, or `None` if no data keys matched the filter.
+    pub bulk_write_result: Option<BulkWriteResult>,
+}
 
 /// The options for explicit encryption.
 #[derive(Debug, Clone)]
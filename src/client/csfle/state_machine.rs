@@ -126,7 +126,13 @@ impl CryptExecutor {
                     let db = db.as_ref().ok_or_else(|| {
                         Error::internal("db required for NeedMongoMarkings state")
                     })?;
-                    let op = RawOutput(RunCommand::new_raw(db.to_string(), command, None, None)?);
+                    let op = RawOutput(RunCommand::new_raw(
+                        db.to_string(),
+                        command,
+                        None,
+                        None,
+                        None,
+                    )?);
                     let mongocryptd_client = self.mongocryptd_client.as_ref().ok_or_else(|| {
                         Error::invalid_argument("this operation requires mongocryptd")
                     })?;
@@ -183,6 +189,12 @@ impl CryptExecutor {
                     while let Some(kms_ctx) = scope.next_kms_ctx() {
                         kms_ctxen.push(Ok(kms_ctx));
                     }
+                    // KMS requests are tunneled through the same proxy as the key vault client.
+                    let proxy = self
+                        .key_vault_client
+                        .upgrade()
+                        .and_then(|client| client.options().socks5_proxy());
+                    let proxy = proxy.as_ref();
                     stream::iter(kms_ctxen)
                         .try_for_each_concurrent(None, |mut kms_ctx| async move {
                             let endpoint = kms_ctx.endpoint()?;
@@ -194,9 +206,12 @@ impl CryptExecutor {
                                 .and_then(|tls| tls.get(&provider))
                                 .cloned()
                                 .unwrap_or_default();
-                            let mut stream =
-                                AsyncStream::connect(addr, Some(&TlsConfig::new(tls_options)?))
-                                    .await?;
+                            let mut stream = AsyncStream::connect(
+                                addr,
+                                Some(&TlsConfig::new(tls_options)?),
+                                proxy,
+                            )
+                            .await?;
                             stream.write_all(kms_ctx.message()?).await?;
                             let mut buf = vec![0];
                             while kms_ctx.bytes_needed() > 0 {
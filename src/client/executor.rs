@@ -1,15 +1,15 @@
-#[cfg(feature = "in-use-encryption-unstable")]
-use bson::RawDocumentBuf;
-use bson::{doc, RawBsonRef, RawDocument, Timestamp};
+use bson::{doc, Bson, RawBsonRef, RawDocument, RawDocumentBuf, Timestamp};
 #[cfg(feature = "in-use-encryption-unstable")]
 use futures_core::future::BoxFuture;
 use lazy_static::lazy_static;
 use serde::de::DeserializeOwned;
 
 use std::{
+    borrow::BorrowMut,
     collections::HashSet,
+    convert::TryFrom,
     sync::{atomic::Ordering, Arc},
-    time::Instant,
+    time::{Duration, Instant},
 };
 
 use super::{options::ServerAddress, session::TransactionState, Client, ClientSession};
@@ -51,10 +51,12 @@ use crate::{
         ChangeStreamAggregate,
         CommandErrorBody,
         CommitTransaction,
+        GetMore,
         Operation,
         Retryability,
     },
     options::{ChangeStreamOptions, SelectionCriteria},
+    runtime,
     sdam::{HandshakePhase, SelectedServer, ServerType, TopologyType, TransactionSupportStatus},
     selection_criteria::ReadPreference,
     tracking_arc::TrackingArc,
@@ -91,7 +93,7 @@ impl Client {
     /// sessions and an explicit session is not provided.
     pub(crate) async fn execute_operation<T: Operation>(
         &self,
-        op: T,
+        op: impl BorrowMut<T>,
         session: impl Into<Option<&mut ClientSession>>,
     ) -> Result<T::O> {
         self.execute_operation_with_details(op, session)
@@ -99,11 +101,12 @@ impl Client {
             .map(|details| details.output)
     }
 
-    async fn execute_operation_with_details<T: Operation>(
+    pub(super) async fn execute_operation_with_details<T: Operation>(
         &self,
-        op: T,
+        mut op: impl BorrowMut<T>,
         session: impl Into<Option<&mut ClientSession>>,
     ) -> Result<ExecutionDetails<T>> {
+        let op = op.borrow_mut();
         if self.inner.shutdown.executed.load(Ordering::SeqCst) {
             return Err(ErrorKind::Shutdown.into());
         }
@@ -151,6 +154,7 @@ impl Client {
     {
         Box::pin(async {
             let mut details = self.execute_operation_with_details(op, None).await?;
+            details.output.info.deadline = details.deadline;
             let pinned =
                 self.pin_connection_for_cursor(&details.output, &mut details.connection)?;
             Ok(Cursor::new(
@@ -174,6 +178,7 @@ impl Client {
         let mut details = self
             .execute_operation_with_details(op, &mut *session)
             .await?;
+        details.output.info.deadline = details.deadline;
 
         let pinned =
             self.pin_connection_for_session(&details.output, &mut details.connection, session)?;
@@ -184,7 +189,7 @@ impl Client {
         self.inner.options.load_balanced.unwrap_or(false)
     }
 
-    fn pin_connection_for_cursor(
+    pub(super) fn pin_connection_for_cursor(
         &self,
         spec: &CursorSpecification,
         conn: &mut Connection,
@@ -196,7 +201,7 @@ impl Client {
         }
     }
 
-    fn pin_connection_for_session(
+    pub(super) fn pin_connection_for_session(
         &self,
         spec: &CursorSpecification,
         conn: &mut Connection,
@@ -284,7 +289,7 @@ impl Client {
     /// session. Retries the operation upon failure if retryability is supported.
     async fn execute_operation_with_retry<T: Operation>(
         &self,
-        mut op: T,
+        op: &mut T,
         mut session: Option<&mut ClientSession>,
     ) -> Result<ExecutionDetails<T>> {
         // If the current transaction has been committed/aborted and it is not being
@@ -301,9 +306,15 @@ impl Client {
             }
         }
 
+        let deadline = self.operation_deadline(op, &session);
         let mut retry: Option<ExecutionRetry> = None;
         let mut implicit_session: Option<ClientSession> = None;
         loop {
+            let remaining = match remaining_time(op.name(), deadline) {
+                Ok(remaining) => remaining,
+                Err(err) => return Err(err.with_source(retry.map(|r| r.first_error))),
+            };
+
             if retry.is_some() {
                 op.update_for_retry();
             }
@@ -313,17 +324,21 @@ impl Client {
                 .and_then(|s| s.transaction.pinned_mongos())
                 .or_else(|| op.selection_criteria());
 
-            let server = match self
-                .select_server(
+            let (server, overridden_criteria) = match self
+                .select_server_with_override(
                     selection_criteria,
                     op.name(),
                     retry.as_ref().map(|r| &r.first_server),
+                    remaining,
+                    op.override_criteria(),
                 )
                 .await
             {
-                Ok(server) => server,
+                Ok(selected) => selected,
                 Err(mut err) => {
-                    retry.first_error()?;
+                    if !err.is_timeout_error() {
+                        retry.first_error()?;
+                    }
 
                     err.add_labels_and_update_pin(None, &mut session, None)?;
                     return Err(err);
@@ -331,17 +346,20 @@ impl Client {
             };
             let server_addr = server.address.clone();
 
-            let mut conn = match get_connection(&session, &op, &server.pool).await {
+            let remaining = remaining_time(op.name(), deadline)?;
+            let mut conn = match get_connection(&session, op, &server.pool, remaining).await {
                 Ok(c) => c,
                 Err(mut err) => {
-                    retry.first_error()?;
+                    if !err.is_timeout_error() {
+                        retry.first_error()?;
+                    }
 
                     err.add_labels_and_update_pin(None, &mut session, None)?;
                     if err.is_read_retryable() && self.inner.options.retry_writes != Some(false) {
                         err.add_label(RETRYABLE_WRITE_ERROR);
                     }
 
-                    let op_retry = match self.get_op_retryability(&op, &session) {
+                    let op_retry = match self.get_op_retryability(op, &session) {
                         Retryability::Read => err.is_read_retryable(),
                         Retryability::Write => err.is_write_retryable(),
                         _ => false,
@@ -372,7 +390,7 @@ impl Client {
                 session = implicit_session.as_mut();
             }
 
-            let retryability = self.get_retryability(&conn, &op, &session)?;
+            let retryability = self.get_retryability(&conn, op, &session)?;
             if retryability == Retryability::None {
                 retry.first_error()?;
             }
@@ -382,20 +400,44 @@ impl Client {
                 .and_then(|r| r.prior_txn_number)
                 .or_else(|| get_txn_number(&mut session, retryability));
 
-            let details = match self
+            let mut result = self
                 .execute_operation_on_connection(
-                    &mut op,
+                    op,
                     &mut conn,
                     &mut session,
                     txn_number,
                     retryability,
+                    overridden_criteria.as_ref(),
+                    deadline,
                 )
-                .await
-            {
+                .await;
+
+            // If the connection's credentials have expired, reauthenticate it and retry the
+            // operation once on the same connection.
+            if matches!(result, Err(ref err) if err.is_reauthentication_required()) {
+                result = match self.reauthenticate_connection(&mut conn).await {
+                    Ok(()) => {
+                        self.execute_operation_on_connection(
+                            op,
+                            &mut conn,
+                            &mut session,
+                            txn_number,
+                            retryability,
+                            overridden_criteria.as_ref(),
+                            deadline,
+                        )
+                        .await
+                    }
+                    Err(err) => Err(err),
+                };
+            }
+
+            let details = match result {
                 Ok(output) => ExecutionDetails {
                     output,
                     connection: conn,
                     implicit_session,
+                    deadline,
                 },
                 Err(mut err) => {
                     err.wire_version = conn.stream_description()?.max_wire_version;
@@ -426,6 +468,19 @@ impl Client {
                     // release the selected server to decrement its operation count
                     drop(server);
 
+                    let retryable = retryability == Retryability::Read && err.is_read_retryable()
+                        || retryability == Retryability::Write && err.is_write_retryable();
+
+                    // When the operation has a timeout, it is retried for as long as the timeout
+                    // allows rather than only once.
+                    if let (Some(r), Some(_)) = (retry.as_mut(), deadline) {
+                        if retryable {
+                            r.prior_txn_number = txn_number;
+                            r.first_server = server_addr.clone();
+                            continue;
+                        }
+                    }
+
                     if let Some(r) = retry {
                         if (err.is_server_error()
                             || err.is_read_retryable()
@@ -436,9 +491,7 @@ impl Client {
                         } else {
                             return Err(r.first_error);
                         }
-                    } else if retryability == Retryability::Read && err.is_read_retryable()
-                        || retryability == Retryability::Write && err.is_write_retryable()
-                    {
+                    } else if retryable {
                         retry = Some(ExecutionRetry {
                             prior_txn_number: txn_number,
                             first_error: err,
@@ -454,7 +507,22 @@ impl Client {
         }
     }
 
+    /// Reauthenticates a connection whose credentials have expired.
+    async fn reauthenticate_connection(&self, connection: &mut Connection) -> Result<()> {
+        let credential = self.inner.options.credential.as_ref().ok_or_else(|| {
+            Error::internal("reauthentication required but no credential was provided")
+        })?;
+        credential
+            .reauthenticate_stream(connection, self.inner.options.server_api.as_ref())
+            .await
+    }
+
     /// Executes an operation on a given connection, optionally using a provided session.
+    ///
+    /// If the operation's selection criteria were overridden during server selection,
+    /// `overridden_criteria` should be provided so that the command's read preference matches the
+    /// criteria the server was actually selected with.
+    #[allow(clippy::too_many_arguments)]
     async fn execute_operation_on_connection<T: Operation>(
         &self,
         op: &mut T,
@@ -462,6 +530,8 @@ impl Client {
         session: &mut Option<&mut ClientSession>,
         txn_number: Option<i64>,
         retryability: Retryability,
+        overridden_criteria: Option<&SelectionCriteria>,
+        deadline: Option<Instant>,
     ) -> Result<T::O> {
         if let Some(wc) = op.write_concern() {
             wc.validate()?;
@@ -473,7 +543,7 @@ impl Client {
         self.inner.topology.update_command_with_read_pref(
             connection.address(),
             &mut cmd,
-            op.selection_criteria(),
+            overridden_criteria.or_else(|| op.selection_criteria()),
         );
 
         match session {
@@ -583,6 +653,7 @@ impl Client {
 
         let cmd_name = cmd.name.clone();
         let target_db = cmd.target_db.clone();
+        let document_sequences = std::mem::take(&mut cmd.document_sequences);
 
         let serialized = op.serialize_command(cmd)?;
         #[cfg(feature = "in-use-encryption-unstable")]
@@ -600,18 +671,36 @@ impl Client {
                 serialized
             }
         };
+        // The server is told how much of the timeout remains so that it can abort the operation
+        // itself. This is skipped for getMore, where maxTimeMS instead configures how long a
+        // tailable await cursor waits for new data, and for commands within a transaction.
+        let remaining = remaining_time(&cmd_name, deadline)?;
+        let serialized = match remaining {
+            Some(remaining)
+                if op.name() != GetMore::NAME
+                    && !session.as_ref().map_or(false, |s| {
+                        s.in_transaction()
+                            && op.name() != CommitTransaction::NAME
+                            && op.name() != AbortTransaction::NAME
+                    }) =>
+            {
+                append_max_time_ms(serialized, remaining)?
+            }
+            _ => serialized,
+        };
         let raw_cmd = RawCommand {
             name: cmd_name.clone(),
             target_db,
             exhaust_allowed: false,
             bytes: serialized,
+            document_sequences,
         };
 
         self.emit_command_event(|| {
             let command_body = if should_redact {
                 Document::new()
             } else {
-                Document::from_reader(raw_cmd.bytes.as_slice())
+                command_event_body(&raw_cmd)
                     .unwrap_or_else(|e| doc! { "serialization error": e.to_string() })
             };
             CommandEvent::Started(CommandStartedEvent {
@@ -626,7 +715,22 @@ impl Client {
         .await;
 
         let start_time = Instant::now();
-        let command_result = match connection.send_raw_command(raw_cmd, request_id).await {
+        let send_result = match remaining_time(&cmd_name, deadline)? {
+            // If the timeout elapses while the command is in flight, the connection is left in
+            // the executing state and will be closed rather than reused once it is checked in.
+            Some(remaining) => {
+                runtime::timeout(remaining, connection.send_raw_command(raw_cmd, request_id))
+                    .await
+                    .unwrap_or_else(|_| {
+                        Err(Error::operation_timeout(format!(
+                            "{} did not complete within the configured timeout",
+                            cmd_name
+                        )))
+                    })
+            }
+            None => connection.send_raw_command(raw_cmd, request_id).await,
+        };
+        let command_result = match send_result {
             Ok(response) => {
                 async fn handle_response<T: Operation>(
                     client: &Client,
@@ -817,7 +921,7 @@ impl Client {
                 || server_type.is_data_bearing()
         }));
         let _: SelectedServer = self
-            .select_server(Some(&criteria), operation_name, None)
+            .select_server(Some(&criteria), operation_name, None, None)
             .await?;
         Ok(())
     }
@@ -840,6 +944,31 @@ impl Client {
         }
     }
 
+    /// Returns the point in time by which this operation must complete, if any timeout applies.
+    /// A deadline carried by the operation itself (e.g. a `getMore` continuing a cursor) is used
+    /// as is. Otherwise, a timeout set on the operation takes precedence over the session's
+    /// default timeout, which in turn takes precedence over the Client's. A timeout of zero
+    /// disables it.
+    fn operation_deadline<T: Operation>(
+        &self,
+        op: &T,
+        session: &Option<&mut ClientSession>,
+    ) -> Option<Instant> {
+        if let Some(deadline) = op.deadline() {
+            return Some(deadline);
+        }
+        op.timeout()
+            .or_else(|| {
+                session
+                    .as_ref()
+                    .and_then(|session| session.options())
+                    .and_then(|options| options.default_timeout)
+            })
+            .or(self.inner.options.timeout)
+            .filter(|timeout| !timeout.is_zero())
+            .map(|timeout| Instant::now() + timeout)
+    }
+
     /// Returns the retryability level for the execution of this operation.
     fn get_op_retryability<T: Operation>(
         &self,
@@ -879,8 +1008,13 @@ impl Client {
     ) -> Result<Retryability> {
         match self.get_op_retryability(op, session) {
             Retryability::Read => Ok(Retryability::Read),
-            Retryability::Write if conn.stream_description()?.supports_retryable_writes() => {
-                Ok(Retryability::Write)
+            Retryability::Write => {
+                let description = conn.stream_description()?;
+                if description.supports_retryable_writes() && op.can_retry_write(description) {
+                    Ok(Retryability::Write)
+                } else {
+                    Ok(Retryability::None)
+                }
             }
             _ => Ok(Retryability::None),
         }
@@ -904,7 +1038,8 @@ impl Client {
 
         if let Some(timestamp) = at_cluster_time {
             if let Some(ref mut session) = session {
-                session.snapshot_time = Some(timestamp);
+                // Snapshot sessions read from a single point in time, so keep the first one.
+                session.snapshot_time.get_or_insert(timestamp);
             }
         }
     }
@@ -914,6 +1049,7 @@ async fn get_connection<T: Operation>(
     session: &Option<&mut ClientSession>,
     op: &T,
     pool: &ConnectionPool,
+    timeout: Option<Duration>,
 ) -> Result<Connection> {
     let session_pinned = session
         .as_ref()
@@ -925,8 +1061,49 @@ async fn get_connection<T: Operation>(
             debug_assert_eq!(session_handle.id(), op_handle.id());
             session_handle.take_connection().await
         }
-        (None, None) => pool.check_out().await,
+        (None, None) => pool.check_out(timeout).await,
+    }
+}
+
+/// Reconstructs the full command document for command monitoring, including any document
+/// sequences as array fields.
+fn command_event_body(raw_cmd: &RawCommand) -> Result<Document> {
+    let mut body = Document::from_reader(raw_cmd.bytes.as_slice())?;
+    for sequence in &raw_cmd.document_sequences {
+        let documents = sequence
+            .documents
+            .iter()
+            .map(|doc| doc.to_document().map(Bson::Document))
+            .collect::<std::result::Result<Vec<_>, _>>()?;
+        body.insert(sequence.identifier.clone(), documents);
+    }
+    Ok(body)
+}
+
+/// Returns how much time remains before the given deadline, or a timeout error if it has already
+/// passed.
+fn remaining_time(operation_name: &str, deadline: Option<Instant>) -> Result<Option<Duration>> {
+    match deadline {
+        Some(deadline) => match deadline.checked_duration_since(Instant::now()) {
+            Some(remaining) if !remaining.is_zero() => Ok(Some(remaining)),
+            _ => Err(Error::operation_timeout(format!(
+                "{} did not complete within the configured timeout",
+                operation_name
+            ))),
+        },
+        None => Ok(None),
+    }
+}
+
+/// Appends a `maxTimeMS` field reflecting the remaining timeout to a serialized command, unless
+/// the command already specifies one.
+fn append_max_time_ms(command: Vec<u8>, remaining: Duration) -> Result<Vec<u8>> {
+    let mut command = RawDocumentBuf::from_bytes(command)?;
+    if command.get("maxTimeMS")?.is_none() {
+        let millis = i64::try_from(remaining.as_millis()).unwrap_or(i64::MAX);
+        command.append("maxTimeMS", millis.max(1));
     }
+    Ok(command.into_bytes())
 }
 
 fn get_txn_number(
@@ -1022,10 +1199,12 @@ impl Error {
     }
 }
 
-struct ExecutionDetails<T: Operation> {
-    output: T::O,
-    connection: Connection,
-    implicit_session: Option<ClientSession>,
+pub(super) struct ExecutionDetails<T: Operation> {
+    pub(super) output: T::O,
+    pub(super) connection: Connection,
+    pub(super) implicit_session: Option<ClientSession>,
+    /// The deadline the operation ran under, which also bounds any cursor it created.
+    pub(super) deadline: Option<Instant>,
 }
 
 struct ExecutionRetry {
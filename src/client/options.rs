@@ -1,6 +1,7 @@
 #[cfg(all(test, not(feature = "sync"), not(feature = "tokio-sync")))]
 mod test;
 
+mod bulk_write;
 mod resolver_config;
 
 use std::{
@@ -27,23 +28,34 @@ use typed_builder::TypedBuilder;
 #[cfg(test)]
 use crate::srv::LookupHosts;
 use crate::{
-    bson::{doc, Bson, Document},
+    bson::{doc, Bson, Document, Timestamp},
     client::auth::{AuthMechanism, Credential},
     compression::Compressor,
     concern::{Acknowledgment, ReadConcern, WriteConcern},
     error::{Error, ErrorKind, Result},
     event::{cmap::CmapEventHandler, command::CommandEventHandler, sdam::SdamEventHandler},
     options::ReadConcernLevel,
+    runtime::socks5::{Socks5Proxy, DEFAULT_PROXY_PORT},
     sdam::{verify_max_staleness, DEFAULT_HEARTBEAT_FREQUENCY, MIN_HEARTBEAT_FREQUENCY},
     selection_criteria::{ReadPreference, SelectionCriteria, TagSet},
     serde_util,
-    srv::{OriginalSrvInfo, SrvResolver},
+    srv::{self, OriginalSrvInfo, SrvResolver},
 };
 
 #[cfg(any(feature = "sync", feature = "tokio-sync"))]
 use crate::runtime;
 
-pub use resolver_config::ResolverConfig;
+pub use bulk_write::{
+    BulkWriteOptions,
+    DeleteManyModel,
+    DeleteOneModel,
+    InsertOneModel,
+    ReplaceOneModel,
+    UpdateManyModel,
+    UpdateOneModel,
+    WriteModel,
+};
+pub use resolver_config::{DnsResolver, ResolverConfig, SrvRecord};
 
 pub(crate) const DEFAULT_PORT: u16 = 27017;
 
@@ -63,20 +75,29 @@ const URI_OPTIONS: &[&str] = &[
     "maxpoolsize",
     "minpoolsize",
     "maxconnecting",
+    "proxyhost",
+    "proxyport",
+    "proxyusername",
+    "proxypassword",
     "readconcernlevel",
     "readpreference",
     "readpreferencetags",
     "replicaset",
     "retrywrites",
     "retryreads",
+    "servermonitoringmode",
     "serverselectiontimeoutms",
     "sockettimeoutms",
+    "srvmaxhosts",
+    "srvservicename",
+    "timeoutms",
     "tls",
     "ssl",
     "tlsinsecure",
     "tlsallowinvalidcertificates",
     "tlscafile",
     "tlscertificatekeyfile",
+    "tlscertificatekeyfilepassword",
     "uuidRepresentation",
     "w",
     "waitqueuetimeoutms",
@@ -302,6 +323,45 @@ impl fmt::Display for ServerAddress {
     }
 }
 
+/// Specifies which protocol the monitors of a [`Client`](crate::Client) should use to check the
+/// servers they are monitoring.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub enum ServerMonitoringMode {
+    /// Use the streaming protocol whenever the server supports it, in which the server pushes a
+    /// new `hello` response to the client as soon as its state changes.
+    Stream,
+
+    /// Always use the polling protocol, in which the client sends a `hello` command to the server
+    /// every `heartbeat_freq`.
+    Poll,
+
+    /// Use the polling protocol when running in a function-as-a-service (FaaS) environment such
+    /// as AWS Lambda, and the streaming protocol otherwise.
+    Auto,
+}
+
+impl FromStr for ServerMonitoringMode {
+    type Err = Error;
+
+    fn from_str(str: &str) -> Result<Self> {
+        match str {
+            "stream" => Ok(Self::Stream),
+            "poll" => Ok(Self::Poll),
+            "auto" => Ok(Self::Auto),
+            _ => Err(ErrorKind::InvalidArgument {
+                message: format!(
+                    "connection string `serverMonitoringMode` option can be one of `stream`, \
+                     `poll`, or `auto`. Received invalid `{}`",
+                    str
+                ),
+            }
+            .into()),
+        }
+    }
+}
+
 /// Specifies the server API version to declare
 #[derive(Clone, Debug, PartialEq, Serialize)]
 #[non_exhaustive]
@@ -438,6 +498,16 @@ pub struct ClientOptions {
     #[builder(default)]
     pub direct_connection: Option<bool>,
 
+    /// The resolver to use for the SRV and TXT lookups performed when polling the SRV records of
+    /// a `mongodb+srv` connection string for changes. To also use it for the initial lookups,
+    /// parse the connection string with [`ClientOptions::parse_with_dns_resolver`].
+    ///
+    /// By default, a resolver using the host system's configuration is used.
+    #[derivative(Debug = "ignore", PartialEq = "ignore")]
+    #[builder(default)]
+    #[serde(skip)]
+    pub dns_resolver: Option<Arc<dyn DnsResolver>>,
+
     /// Extra information to append to the driver version in the metadata of the handshake with the
     /// server. This should be used by libraries wrapping the driver, e.g. ODMs.
     #[builder(default)]
@@ -498,6 +568,31 @@ pub struct ClientOptions {
     #[builder(default)]
     pub max_connecting: Option<u32>,
 
+    /// The host of a SOCKS5 proxy that all connections to the servers, including monitoring
+    /// connections, should be tunneled through. Hostnames of the servers are resolved by the
+    /// proxy.
+    ///
+    /// By default, no proxy is used.
+    #[builder(default)]
+    pub proxy_host: Option<String>,
+
+    /// The port of the SOCKS5 proxy. This can only be specified if `proxy_host` is set.
+    ///
+    /// The default value is 1080.
+    #[builder(default)]
+    pub proxy_port: Option<u16>,
+
+    /// The username to authenticate to the SOCKS5 proxy with. This must be specified together with
+    /// `proxy_password`.
+    #[builder(default)]
+    pub proxy_username: Option<String>,
+
+    /// The password to authenticate to the SOCKS5 proxy with. This must be specified together with
+    /// `proxy_username`.
+    #[builder(default)]
+    #[derivative(Debug = "ignore")]
+    pub proxy_password: Option<String>,
+
     /// Specifies the default read concern for operations performed on the Client. See the
     /// ReadConcern type documentation for more details.
     #[builder(default)]
@@ -545,6 +640,12 @@ pub struct ClientOptions {
     #[builder(default)]
     pub server_api: Option<ServerApi>,
 
+    /// The protocol that the client's monitors should use to check the servers in the topology.
+    ///
+    /// The default value is [`ServerMonitoringMode::Auto`].
+    #[builder(default)]
+    pub server_monitoring_mode: Option<ServerMonitoringMode>,
+
     /// The amount of time the Client should attempt to select a server for an operation before
     /// timing outs
     ///
@@ -562,6 +663,32 @@ pub struct ClientOptions {
     #[derivative(Debug = "ignore")]
     pub(crate) socket_timeout: Option<Duration>,
 
+    /// The maximum number of hosts to connect to when using a `mongodb+srv` connection string. If
+    /// the SRV records contain more hosts than this, a random subset is used, and hosts removed
+    /// from the records during SRV polling are replaced with randomly chosen new ones.
+    ///
+    /// This cannot be combined with `repl_set_name` or `load_balanced`. The default value is 0,
+    /// which means that all of the hosts are used.
+    #[builder(default)]
+    pub srv_max_hosts: Option<u32>,
+
+    /// The service name to use for SRV lookups when using a `mongodb+srv` connection string.
+    ///
+    /// The default value is "mongodb".
+    #[builder(default)]
+    pub srv_service_name: Option<String>,
+
+    /// The default timeout for operations performed on the Client. The timeout bounds the whole
+    /// operation, including server selection, connection checkout, any retries and the time
+    /// spent waiting for the server to reply; the remaining budget is sent to the server as
+    /// `maxTimeMS`. Operations that exceed it fail with an
+    /// [`ErrorKind::Timeout`](crate::error::ErrorKind::Timeout) error.
+    ///
+    /// This can be overridden per database, collection, session or operation, and a timeout of
+    /// zero disables it. By default, no timeout is applied.
+    #[builder(default)]
+    pub timeout: Option<Duration>,
+
     /// The TLS configuration for the Client to use in its connections with the server.
     ///
     /// By default, TLS is disabled.
@@ -693,12 +820,17 @@ impl Serialize for ClientOptions {
             )]
             selectioncriteria: &'a Option<SelectionCriteria>,
 
+            servermonitoringmode: &'a Option<ServerMonitoringMode>,
+
             #[serde(serialize_with = "serde_util::serialize_duration_option_as_int_millis")]
             serverselectiontimeoutms: &'a Option<Duration>,
 
             #[serde(serialize_with = "serde_util::serialize_duration_option_as_int_millis")]
             sockettimeoutms: &'a Option<Duration>,
 
+            #[serde(serialize_with = "serde_util::serialize_duration_option_as_int_millis")]
+            timeoutms: &'a Option<Duration>,
+
             #[serde(flatten, serialize_with = "Tls::serialize_for_client_options")]
             tls: &'a Option<Tls>,
 
@@ -726,8 +858,10 @@ impl Serialize for ClientOptions {
             retryreads: &self.retry_reads,
             retrywrites: &self.retry_writes,
             selectioncriteria: &self.selection_criteria,
+            servermonitoringmode: &self.server_monitoring_mode,
             serverselectiontimeoutms: &self.server_selection_timeout,
             sockettimeoutms: &self.socket_timeout,
+            timeoutms: &self.timeout,
             tls: &self.tls,
             writeconcern: &self.write_concern,
             loadbalanced: &self.load_balanced,
@@ -741,7 +875,8 @@ impl Serialize for ClientOptions {
 /// Contains the options that can be set via a MongoDB connection string.
 ///
 /// The format of a MongoDB connection string is described [here](https://www.mongodb.com/docs/manual/reference/connection-string/#connection-string-formats).
-#[derive(Debug, Default, PartialEq)]
+#[derive(Default, Derivative)]
+#[derivative(Debug, PartialEq)]
 #[non_exhaustive]
 pub struct ConnectionString {
     /// The initial list of seeds that the Client should connect to, or a DNS name used for SRV
@@ -797,6 +932,17 @@ pub struct ConnectionString {
     /// The default value is 30 seconds.
     pub server_selection_timeout: Option<Duration>,
 
+    /// The protocol that the client's monitors should use to check the servers in the topology.
+    ///
+    /// The default value is [`ServerMonitoringMode::Auto`].
+    pub server_monitoring_mode: Option<ServerMonitoringMode>,
+
+    /// The default timeout for operations performed on the Client, including server selection,
+    /// connection checkout and any retries.
+    ///
+    /// By default, no timeout is applied.
+    pub timeout: Option<Duration>,
+
     /// The maximum amount of connections that the Client should allow to be created in a
     /// connection pool for a given server. If an operation is attempted on a server while
     /// `max_pool_size` connections are checked out, the operation will block until an in-progress
@@ -835,6 +981,21 @@ pub struct ConnectionString {
     /// The default value is 10 seconds.
     pub connect_timeout: Option<Duration>,
 
+    /// The host of a SOCKS5 proxy that connections should be tunneled through.
+    pub proxy_host: Option<String>,
+
+    /// The port of the SOCKS5 proxy.
+    ///
+    /// The default value is 1080.
+    pub proxy_port: Option<u16>,
+
+    /// The username to authenticate to the SOCKS5 proxy with.
+    pub proxy_username: Option<String>,
+
+    /// The password to authenticate to the SOCKS5 proxy with.
+    #[derivative(Debug = "ignore")]
+    pub proxy_password: Option<String>,
+
     /// Whether or not the client should retry a read operation if the operation fails.
     ///
     /// The default value is true.
@@ -866,6 +1027,14 @@ pub struct ConnectionString {
     /// this only applies to application operations, not server discovery and monitoring.
     pub socket_timeout: Option<Duration>,
 
+    /// The maximum number of hosts from the SRV records to connect to. This can only be specified
+    /// with a `mongodb+srv` connection string.
+    pub srv_max_hosts: Option<u32>,
+
+    /// The service name to use for SRV lookups. This can only be specified with a `mongodb+srv`
+    /// connection string.
+    pub srv_service_name: Option<String>,
+
     /// Default read preference for the client.
     pub read_preference: Option<ReadPreference>,
 
@@ -910,12 +1079,16 @@ impl Default for HostInfo {
 }
 
 impl HostInfo {
-    async fn resolve(self, resolver_config: Option<ResolverConfig>) -> Result<ResolvedHostInfo> {
+    async fn resolve(self, options: &ClientOptions) -> Result<ResolvedHostInfo> {
         Ok(match self {
             Self::HostIdentifiers(hosts) => ResolvedHostInfo::HostIdentifiers(hosts),
             Self::DnsRecord(hostname) => {
-                let mut resolver =
-                    SrvResolver::new(resolver_config.clone().map(|config| config.inner)).await?;
+                let mut resolver = SrvResolver::new(
+                    options.dns_resolver.clone(),
+                    options.resolver_config.clone().map(|config| config.inner),
+                    options.srv_service_name.clone(),
+                )
+                .await?;
                 let config = resolver.resolve_client_options(&hostname).await?;
                 ResolvedHostInfo::DnsRecord { hostname, config }
             }
@@ -973,7 +1146,8 @@ impl Tls {
 }
 
 /// Specifies the TLS configuration that the [`Client`](../struct.Client.html) should use.
-#[derive(Clone, Debug, Default, Deserialize, PartialEq, TypedBuilder)]
+#[derive(Clone, Default, Deserialize, Derivative, TypedBuilder)]
+#[derivative(Debug, PartialEq)]
 #[builder(field_defaults(default, setter(into)))]
 #[non_exhaustive]
 pub struct TlsOptions {
@@ -985,8 +1159,8 @@ pub struct TlsOptions {
     pub allow_invalid_certificates: Option<bool>,
 
     /// The path to the CA file that the [`Client`](../struct.Client.html) should use for TLS. If
-    /// none is specified, then the driver will use the Mozilla root certificates from the
-    /// `webpki-roots` crate.
+    /// neither this nor [`TlsOptions::ca_pem`] is specified, then the driver will use the Mozilla
+    /// root certificates from the `webpki-roots` crate.
     pub ca_file_path: Option<PathBuf>,
 
     /// The path to the certificate file that the [`Client`](../struct.Client.html) should present
@@ -995,6 +1169,66 @@ pub struct TlsOptions {
     /// server.
     pub cert_key_file_path: Option<PathBuf>,
 
+    /// PEM-encoded CA certificates that the [`Client`](../struct.Client.html) should use for TLS.
+    /// This can be used instead of [`TlsOptions::ca_file_path`] when the certificates are not
+    /// stored on disk. If both are specified, the certificates from both sources will be trusted.
+    pub ca_pem: Option<Vec<u8>>,
+
+    /// A PEM-encoded client certificate chain and private key that the
+    /// [`Client`](../struct.Client.html) should present to the server to verify its identity.
+    /// This can be used instead of [`TlsOptions::cert_key_file_path`] when the certificate is not
+    /// stored on disk, and takes precedence over it if both are specified.
+    pub cert_key_pem: Option<Vec<u8>>,
+
+    /// The password used to decrypt the private key in [`TlsOptions::cert_key_file_path`] or
+    /// [`TlsOptions::cert_key_pem`]. This is only needed if the private key is encrypted. Unless
+    /// the `openssl-tls` feature flag is enabled, decrypting the key requires the `rustls-tls`
+    /// feature flag, which is enabled by default.
+    #[derivative(Debug = "ignore")]
+    pub cert_key_password: Option<String>,
+
+    /// A prebuilt rustls configuration to use for TLS connections. If this is specified, all of
+    /// the other fields in `TlsOptions` are ignored.
+    ///
+    /// This is an escape hatch for configurations that the other options do not cover, e.g. custom
+    /// certificate verifiers. The `rustls` version used must match the one the driver depends on.
+    #[cfg(not(feature = "openssl-tls"))]
+    #[serde(skip)]
+    #[derivative(Debug = "ignore", PartialEq = "ignore")]
+    pub rustls_config: Option<Arc<rustls::ClientConfig>>,
+
+    /// A prebuilt openssl connector to use for TLS connections. If this is specified, all of the
+    /// other fields in `TlsOptions` other than [`TlsOptions::allow_invalid_hostnames`] are
+    /// ignored.
+    ///
+    /// This is an escape hatch for configurations that the other options do not cover. The
+    /// `openssl` version used must match the one the driver depends on.
+    #[cfg(feature = "openssl-tls")]
+    #[serde(skip)]
+    #[derivative(Debug = "ignore", PartialEq = "ignore")]
+    pub openssl_connector: Option<openssl::ssl::SslConnector>,
+
+    /// A provider that is consulted for the client certificate chain and private key each time a
+    /// new connection is established. This takes precedence over
+    /// [`TlsOptions::cert_key_pem`] and [`TlsOptions::cert_key_file_path`].
+    ///
+    /// When the provider returns a different certificate than before, new connections will
+    /// present it while existing connections continue to use the old one until they are closed
+    /// (e.g. due to [`ClientOptions::max_idle_time`]).
+    #[serde(skip)]
+    #[derivative(Debug = "ignore", PartialEq = "ignore")]
+    pub cert_key_provider: Option<Arc<dyn ClientCertificateProvider>>,
+
+    /// If true, the file at [`TlsOptions::cert_key_file_path`] will be checked for changes when
+    /// new connections are established, and reloaded if it has been modified. This allows
+    /// rotated certificates to be picked up without recreating the
+    /// [`Client`](../struct.Client.html). To limit the blocking file I/O done while connecting,
+    /// the file is checked at most once per second, so a rotated certificate may take up to a
+    /// second to be used.
+    ///
+    /// The default value is false.
+    pub watch_cert_key_file: Option<bool>,
+
     /// Whether or not the [`Client`](../struct.Client.html) should return an error if the hostname
     /// is invalid.
     ///
@@ -1017,6 +1251,7 @@ impl TlsOptions {
             tls: bool,
             tlscafile: Option<&'a str>,
             tlscertificatekeyfile: Option<&'a str>,
+            tlscertificatekeyfilepassword: Option<&'a str>,
             tlsallowinvalidcertificates: Option<bool>,
         }
 
@@ -1030,12 +1265,28 @@ impl TlsOptions {
                 .cert_key_file_path
                 .as_ref()
                 .map(|s| s.to_str().unwrap()),
+            tlscertificatekeyfilepassword: tls_options.cert_key_password.as_deref(),
             tlsallowinvalidcertificates: tls_options.allow_invalid_certificates,
         };
         state.serialize(serializer)
     }
 }
 
+/// Applications can implement this trait to supply client certificates that may change over the
+/// lifetime of a [`Client`](../struct.Client.html), such as ones that are rotated periodically.
+///
+/// See [`TlsOptions::cert_key_provider`] for details.
+pub trait ClientCertificateProvider: Send + Sync {
+    /// Returns the PEM-encoded client certificate chain and private key to use for a new
+    /// connection. If the private key is encrypted, [`TlsOptions::cert_key_password`] is used to
+    /// decrypt it.
+    ///
+    /// This is called synchronously from the async task establishing each new connection, so it
+    /// must not block (e.g. on file or network I/O). Implementations should instead return a
+    /// cached value that is refreshed in the background.
+    fn cert_key_pem(&self) -> Result<Vec<u8>>;
+}
+
 /// Extra information to append to the driver version in the metadata of the handshake with the
 /// server. This should be used by libraries wrapping the driver, e.g. ODMs.
 #[derive(Clone, Debug, Deserialize, TypedBuilder, PartialEq)]
@@ -1097,6 +1348,10 @@ impl ClientOptions {
     ///     field
     ///   * `maxPoolSize`: maps to the `max_pool_size` field
     ///   * `minPoolSize`: maps to the `min_pool_size` field
+    ///   * `proxyHost`: maps to the `proxy_host` field
+    ///   * `proxyPort`: maps to the `proxy_port` field
+    ///   * `proxyUsername`: maps to the `proxy_username` field
+    ///   * `proxyPassword`: maps to the `proxy_password` field
     ///   * `readConcernLevel`: maps to the `read_concern` field
     ///   * `readPreferenceField`: maps to the ReadPreference enum variant of the
     ///     `selection_criteria` field
@@ -1106,8 +1361,12 @@ impl ClientOptions {
     ///   * `replicaSet`: maps to the `repl_set_name` field
     ///   * `retryWrites`: not yet implemented
     ///   * `retryReads`: maps to the `retry_reads` field
+    ///   * `serverMonitoringMode`: maps to the `server_monitoring_mode` field
     ///   * `serverSelectionTimeoutMS`: maps to the `server_selection_timeout` field
     ///   * `socketTimeoutMS`: unsupported, does not map to any field
+    ///   * `srvMaxHosts`: maps to the `srv_max_hosts` field
+    ///   * `srvServiceName`: maps to the `srv_service_name` field
+    ///   * `timeoutMS`: maps to the `timeout` field
     ///   * `ssl`: an alias of the `tls` option
     ///   * `tls`: maps to the TLS variant of the `tls` field`.
     ///   * `tlsInsecure`: relaxes the TLS constraints on connections being made; currently is just
@@ -1172,13 +1431,52 @@ impl ClientOptions {
         runtime::block_on(Self::parse_uri(uri, Some(resolver_config)))
     }
 
+    /// Parses a MongoDB connection string into a `ClientOptions` struct.
+    /// If the string is malformed or one of the options has an invalid value, an error will be
+    /// returned.
+    ///
+    /// In the case that "mongodb+srv" is used, SRV and TXT record lookups will be done using the
+    /// provided [`DnsResolver`] as part of this method. The resolver is also stored in the
+    /// returned options' [`dns_resolver`](ClientOptions::dns_resolver) field so that it is used
+    /// for SRV polling.
+    ///
+    /// See the docstring on `ClientOptions::parse` for information on how the various URI options
+    /// map to fields on `ClientOptions`.
+    ///
+    /// Note: if the `sync` feature is enabled, then this method will be replaced with [the sync
+    /// version](#method.parse_with_dns_resolver-1).
+    #[cfg(all(not(feature = "sync"), not(feature = "tokio-sync")))]
+    pub async fn parse_with_dns_resolver(
+        uri: impl AsRef<str>,
+        dns_resolver: Arc<dyn DnsResolver>,
+    ) -> Result<Self> {
+        Self::parse_connection_string_internal(
+            ConnectionString::parse(uri)?,
+            None,
+            Some(dns_resolver),
+        )
+        .await
+    }
+
+    /// This method will be present if the `sync` feature is enabled. It's otherwise identical to
+    /// [the async version](#method.parse_with_dns_resolver)
+    #[cfg(any(feature = "sync", feature = "tokio-sync"))]
+    pub fn parse_with_dns_resolver(uri: &str, dns_resolver: Arc<dyn DnsResolver>) -> Result<Self> {
+        runtime::block_on(Self::parse_connection_string_internal(
+            ConnectionString::parse(uri)?,
+            None,
+            Some(dns_resolver),
+        ))
+    }
+
     /// Populate this `ClientOptions` from the given URI, optionally using the resolver config for
     /// DNS lookups.
     pub(crate) async fn parse_uri(
         uri: impl AsRef<str>,
         resolver_config: Option<ResolverConfig>,
     ) -> Result<Self> {
-        Self::parse_connection_string_internal(ConnectionString::parse(uri)?, resolver_config).await
+        Self::parse_connection_string_internal(ConnectionString::parse(uri)?, resolver_config, None)
+            .await
     }
 
     /// Creates a `ClientOptions` from the given `ConnectionString`.
@@ -1189,17 +1487,18 @@ impl ClientOptions {
         conn_str: ConnectionString,
         resolver_config: ResolverConfig,
     ) -> Result<Self> {
-        Self::parse_connection_string_internal(conn_str, Some(resolver_config)).await
+        Self::parse_connection_string_internal(conn_str, Some(resolver_config), None).await
     }
 
     /// Creates a `ClientOptions` from the given `ConnectionString`.
     pub async fn parse_connection_string(conn_str: ConnectionString) -> Result<Self> {
-        Self::parse_connection_string_internal(conn_str, None).await
+        Self::parse_connection_string_internal(conn_str, None, None).await
     }
 
     async fn parse_connection_string_internal(
         mut conn_str: ConnectionString,
         resolver_config: Option<ResolverConfig>,
+        dns_resolver: Option<Arc<dyn DnsResolver>>,
     ) -> Result<Self> {
         let auth_source_present = conn_str
             .credential
@@ -1208,9 +1507,10 @@ impl ClientOptions {
             .is_some();
         let host_info = std::mem::take(&mut conn_str.host_info);
         let mut options = Self::from_connection_string(conn_str);
-        options.resolver_config = resolver_config.clone();
+        options.resolver_config = resolver_config;
+        options.dns_resolver = dns_resolver;
 
-        let resolved = host_info.resolve(resolver_config).await?;
+        let resolved = host_info.resolve(&options).await?;
         options.hosts = match resolved {
             ResolvedHostInfo::HostIdentifiers(hosts) => hosts,
             ResolvedHostInfo::DnsRecord {
@@ -1252,8 +1552,9 @@ impl ClientOptions {
                     options.load_balanced = config.load_balanced;
                 }
 
-                // Set the ClientOptions hosts to those found during the SRV lookup.
-                config.hosts
+                // Set the ClientOptions hosts to those found during the SRV lookup, limited to
+                // srvMaxHosts if specified.
+                srv::choose_hosts(config.hosts, options.srv_max_hosts)
             }
         };
 
@@ -1264,7 +1565,7 @@ impl ClientOptions {
     /// Creates a `ClientOptions` from the given `ConnectionString`.
     #[cfg(any(feature = "sync", feature = "tokio-sync"))]
     pub fn parse_connection_string_sync(conn_str: ConnectionString) -> Result<Self> {
-        crate::runtime::block_on(Self::parse_connection_string_internal(conn_str, None))
+        crate::runtime::block_on(Self::parse_connection_string_internal(conn_str, None, None))
     }
 
     /// Creates a `ClientOptions` from the given `ConnectionString`.
@@ -1279,6 +1580,7 @@ impl ClientOptions {
         crate::runtime::block_on(Self::parse_connection_string_internal(
             conn_str,
             Some(resolver_config),
+            None,
         ))
     }
 
@@ -1316,14 +1618,23 @@ impl ClientOptions {
             max_idle_time: conn_str.max_idle_time,
             max_connecting: conn_str.max_connecting,
             server_selection_timeout: conn_str.server_selection_timeout,
+            server_monitoring_mode: conn_str.server_monitoring_mode,
             compressors: conn_str.compressors,
             connect_timeout: conn_str.connect_timeout,
+            proxy_host: conn_str.proxy_host,
+            proxy_port: conn_str.proxy_port,
+            proxy_username: conn_str.proxy_username,
+            proxy_password: conn_str.proxy_password,
             retry_reads: conn_str.retry_reads,
             retry_writes: conn_str.retry_writes,
             socket_timeout: conn_str.socket_timeout,
+            srv_max_hosts: conn_str.srv_max_hosts,
+            srv_service_name: conn_str.srv_service_name,
+            timeout: conn_str.timeout,
             direct_connection: conn_str.direct_connection,
             default_database: conn_str.default_database,
             driver_info: None,
+            dns_resolver: None,
             credential,
             cmap_event_handler: None,
             command_event_handler: None,
@@ -1348,6 +1659,17 @@ impl ClientOptions {
         }
     }
 
+    pub(crate) fn socks5_proxy(&self) -> Option<Socks5Proxy> {
+        let host = self.proxy_host.clone()?;
+        Some(Socks5Proxy {
+            address: ServerAddress::Tcp {
+                host,
+                port: Some(self.proxy_port.unwrap_or(DEFAULT_PROXY_PORT)),
+            },
+            credentials: self.proxy_username.clone().zip(self.proxy_password.clone()),
+        })
+    }
+
     /// Ensure the options set are valid, returning an error describing the problem if they are not.
     pub(crate) fn validate(&self) -> Result<()> {
         if let Some(true) = self.direct_connection {
@@ -1399,6 +1721,21 @@ impl ClientOptions {
             return Err(Error::invalid_argument("cannot specify maxConnecting=0"));
         }
 
+        self.validate_proxy()?;
+
+        if self.srv_max_hosts.unwrap_or(0) > 0 {
+            if self.repl_set_name.is_some() {
+                return Err(Error::invalid_argument(
+                    "cannot specify replicaSet with srvMaxHosts",
+                ));
+            }
+            if self.load_balanced == Some(true) {
+                return Err(Error::invalid_argument(
+                    "cannot specify loadBalanced=true with srvMaxHosts",
+                ));
+            }
+        }
+
         if let Some(SelectionCriteria::ReadPreference(ref rp)) = self.selection_criteria {
             if let Some(max_staleness) = rp.max_staleness() {
                 verify_max_staleness(
@@ -1447,6 +1784,41 @@ impl ClientOptions {
         Ok(())
     }
 
+    fn validate_proxy(&self) -> Result<()> {
+        if self.proxy_host.is_none() {
+            if self.proxy_port.is_some()
+                || self.proxy_username.is_some()
+                || self.proxy_password.is_some()
+            {
+                return Err(Error::invalid_argument(
+                    "cannot specify proxyPort, proxyUsername or proxyPassword without proxyHost",
+                ));
+            }
+            return Ok(());
+        }
+
+        match (&self.proxy_username, &self.proxy_password) {
+            (Some(username), Some(password)) => {
+                for (name, value) in [("proxyUsername", username), ("proxyPassword", password)] {
+                    if value.is_empty() || value.len() > 255 {
+                        return Err(ErrorKind::InvalidArgument {
+                            message: format!("{} must be between 1 and 255 bytes long", name),
+                        }
+                        .into());
+                    }
+                }
+            }
+            (None, None) => {}
+            _ => {
+                return Err(Error::invalid_argument(
+                    "proxyUsername and proxyPassword must be specified together",
+                ))
+            }
+        }
+
+        Ok(())
+    }
+
     /// Applies the options in other to these options if a value is not already present
     #[cfg(test)]
     pub(crate) fn merge(&mut self, other: ClientOptions) {
@@ -1464,6 +1836,7 @@ impl ClientOptions {
                 connect_timeout,
                 credential,
                 direct_connection,
+                dns_resolver,
                 driver_info,
                 heartbeat_freq,
                 load_balanced,
@@ -1471,15 +1844,23 @@ impl ClientOptions {
                 max_idle_time,
                 max_pool_size,
                 min_pool_size,
+                proxy_host,
+                proxy_port,
+                proxy_username,
+                proxy_password,
                 read_concern,
                 repl_set_name,
                 retry_reads,
                 retry_writes,
                 selection_criteria,
                 server_api,
+                server_monitoring_mode,
                 server_selection_timeout,
                 socket_timeout,
+                srv_max_hosts,
+                srv_service_name,
                 test_options,
+                timeout,
                 tls,
                 write_concern,
                 original_srv_info,
@@ -1767,6 +2148,14 @@ impl ConnectionString {
                     credential.mechanism_properties = Some(doc);
                 }
 
+                if *mechanism == AuthMechanism::Gssapi {
+                    credential
+                        .mechanism_properties
+                        .get_or_insert_with(Document::new)
+                        .entry("SERVICE_NAME".to_string())
+                        .or_insert_with(|| "mongodb".into());
+                }
+
                 credential.mechanism = Some(mechanism.clone());
                 mechanism.validate_credential(credential)?;
             }
@@ -1889,6 +2278,19 @@ impl ConnectionString {
             }
         }
 
+        if !self.is_srv() {
+            if self.srv_max_hosts.is_some() {
+                return Err(Error::invalid_argument(
+                    "srvMaxHosts can only be specified with 'mongodb+srv'",
+                ));
+            }
+            if self.srv_service_name.is_some() {
+                return Err(Error::invalid_argument(
+                    "srvServiceName can only be specified with 'mongodb+srv'",
+                ));
+            }
+        }
+
         // If zlib and zlib_compression_level are specified then write zlib_compression_level into
         // zlib enum
         if let (Some(compressors), Some(zlib_compression_level)) =
@@ -2005,7 +2407,16 @@ impl ConnectionString {
                                 ));
                             }
                             let value = v.ok_or_else(err_func)?;
-                            doc.insert(key, value);
+                            match (key, value) {
+                                // CANONICALIZE_HOST_NAME is a boolean unless a canonicalization
+                                // mode is named.
+                                ("CANONICALIZE_HOST_NAME", "true" | "false") => {
+                                    doc.insert(key, value == "true");
+                                }
+                                _ => {
+                                    doc.insert(key, value);
+                                }
+                            }
                         }
                         None => return Err(err_func()),
                     };
@@ -2075,6 +2486,23 @@ impl ConnectionString {
             k @ "maxconnecting" => {
                 self.max_connecting = Some(get_u32!(value, k));
             }
+            "proxyhost" => {
+                self.proxy_host = Some(value.to_string());
+            }
+            "proxyport" => {
+                self.proxy_port = Some(value.parse().map_err(|_| {
+                    Error::invalid_argument(format!(
+                        "connection string `proxyPort` option must be a valid port, instead got {}",
+                        value
+                    ))
+                })?);
+            }
+            "proxyusername" => {
+                self.proxy_username = Some(value.to_string());
+            }
+            "proxypassword" => {
+                self.proxy_password = Some(value.to_string());
+            }
             "readconcernlevel" => {
                 self.read_concern = Some(ReadConcernLevel::from_str(value).into());
             }
@@ -2141,12 +2569,24 @@ impl ConnectionString {
             k @ "retryreads" => {
                 self.retry_reads = Some(get_bool!(value, k));
             }
+            "servermonitoringmode" => {
+                self.server_monitoring_mode = Some(value.parse()?);
+            }
             k @ "serverselectiontimeoutms" => {
                 self.server_selection_timeout = Some(Duration::from_millis(get_duration!(value, k)))
             }
             k @ "sockettimeoutms" => {
                 self.socket_timeout = Some(Duration::from_millis(get_duration!(value, k)));
             }
+            k @ "srvmaxhosts" => {
+                self.srv_max_hosts = Some(get_u32!(value, k));
+            }
+            "srvservicename" => {
+                self.srv_service_name = Some(value.to_string());
+            }
+            k @ "timeoutms" => {
+                self.timeout = Some(Duration::from_millis(get_duration!(value, k)));
+            }
             k @ "tls" | k @ "ssl" => {
                 let tls = get_bool!(value, k);
 
@@ -2246,6 +2686,24 @@ impl ConnectionString {
                     ))
                 }
             },
+            "tlscertificatekeyfilepassword" => match self.tls {
+                Some(Tls::Disabled) => {
+                    return Err(ErrorKind::InvalidArgument {
+                        message: "'tlsCertificateKeyFilePassword' can't be set if tls=false".into(),
+                    }
+                    .into());
+                }
+                Some(Tls::Enabled(ref mut options)) => {
+                    options.cert_key_password = Some(value.to_string());
+                }
+                None => {
+                    self.tls = Some(Tls::Enabled(
+                        TlsOptions::builder()
+                            .cert_key_password(value.to_string())
+                            .build(),
+                    ))
+                }
+            },
             "uuidrepresentation" => match value.to_lowercase().as_str() {
                 "csharplegacy" => self.uuid_representation = Some(UuidRepresentation::CSharpLegacy),
                 "javalegacy" => self.uuid_representation = Some(UuidRepresentation::JavaLegacy),
@@ -2784,6 +3242,26 @@ pub struct SessionOptions {
     /// If true, all read operations performed using this client session will share the same
     /// snapshot.  Defaults to false.
     pub snapshot: Option<bool>,
+
+    /// The point in time that reads in a snapshot session should observe. If unset, the snapshot
+    /// time is taken from the response to the first read performed in the session. It can be
+    /// retrieved afterwards with
+    /// [`ClientSession::snapshot_time`](crate::ClientSession::snapshot_time) to start other
+    /// sessions at the same point in time.
+    ///
+    /// This can only be specified if [`SessionOptions::snapshot`] is true.
+    pub snapshot_time: Option<Timestamp>,
+
+    /// The default timeout for operations performed using this session. This takes precedence
+    /// over the timeout configured on the [`Client`](../struct.Client.html),
+    /// [`Database`](../struct.Database.html) or [`Collection`](../struct.Collection.html), but
+    /// not over a timeout specified in an individual operation's options.
+    #[serde(
+        rename = "defaultTimeoutMS",
+        deserialize_with = "serde_util::deserialize_duration_option_from_u64_millis",
+        default
+    )]
+    pub default_timeout: Option<Duration>,
 }
 
 impl SessionOptions {
@@ -2797,6 +3275,12 @@ impl SessionOptions {
                 .into());
             }
         }
+        if self.snapshot_time.is_some() && self.snapshot != Some(true) {
+            return Err(ErrorKind::InvalidArgument {
+                message: "snapshotTime can only be set when snapshot is true".to_string(),
+            }
+            .into());
+        }
         Ok(())
     }
 }
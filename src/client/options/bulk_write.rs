@@ -0,0 +1,308 @@
+use std::time::Duration;
+
+use serde::Deserialize;
+use serde_with::skip_serializing_none;
+use typed_builder::TypedBuilder;
+
+use crate::{
+    bson::{Bson, Document, RawDocumentBuf},
+    bson_util,
+    error::Result,
+    options::{Collation, Hint, UpdateModifications, WriteConcern},
+    serde_util,
+    Namespace,
+};
+
+/// Specifies the options to a [`Client::bulk_write`](crate::Client::bulk_write) operation.
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, TypedBuilder)]
+#[serde(rename_all = "camelCase")]
+#[builder(field_defaults(default, setter(into)))]
+#[non_exhaustive]
+pub struct BulkWriteOptions {
+    /// Whether the operations should be performed in the order in which they were specified. If
+    /// true, no more writes will be performed if a single write fails. If false, writes will
+    /// continue to be attempted if a single write fails.
+    ///
+    /// Defaults to true.
+    pub ordered: Option<bool>,
+
+    /// Opt out of document-level validation.
+    pub bypass_document_validation: Option<bool>,
+
+    /// Tags the query with an arbitrary [`Bson`] value to help trace the operation through the
+    /// database profiler, currentOp and logs.
+    pub comment: Option<Bson>,
+
+    /// Map of parameter names and values. Values must be constant or closed
+    /// expressions that do not reference document fields. Parameters can then be
+    /// accessed as variables in an aggregate expression context (e.g. "$$var").
+    #[serde(rename = "let")]
+    pub let_vars: Option<Document>,
+
+    /// Whether detailed results for each successful operation should be included in the returned
+    /// [`BulkWriteResult`](crate::results::BulkWriteResult).
+    ///
+    /// Defaults to false.
+    pub verbose_results: Option<bool>,
+
+    /// The write concern for the operation.
+    pub write_concern: Option<WriteConcern>,
+
+    /// The maximum amount of time to allow the operation to run, including server selection,
+    /// connection checkout and any retries.
+    ///
+    /// If none is specified, the default timeout of the session or of the client executing this
+    /// operation will be used.
+    #[serde(
+        rename = "timeoutMS",
+        deserialize_with = "serde_util::deserialize_duration_option_from_u64_millis",
+        default
+    )]
+    pub timeout: Option<Duration>,
+}
+
+/// A single write to be performed as part of a [`Client::bulk_write`](crate::Client::bulk_write)
+/// operation.
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub enum WriteModel {
+    /// Inserts a single document.
+    InsertOne(InsertOneModel),
+
+    /// Updates a single document.
+    UpdateOne(UpdateOneModel),
+
+    /// Updates multiple documents.
+    UpdateMany(UpdateManyModel),
+
+    /// Replaces a single document.
+    ReplaceOne(ReplaceOneModel),
+
+    /// Deletes a single document.
+    DeleteOne(DeleteOneModel),
+
+    /// Deletes multiple documents.
+    DeleteMany(DeleteManyModel),
+}
+
+impl WriteModel {
+    /// The namespace targeted by this write.
+    pub fn namespace(&self) -> &Namespace {
+        match self {
+            Self::InsertOne(model) => &model.namespace,
+            Self::UpdateOne(model) => &model.namespace,
+            Self::UpdateMany(model) => &model.namespace,
+            Self::ReplaceOne(model) => &model.namespace,
+            Self::DeleteOne(model) => &model.namespace,
+            Self::DeleteMany(model) => &model.namespace,
+        }
+    }
+
+    /// Whether this write can affect more than one document, which prevents it from being retried.
+    pub(crate) fn is_multi(&self) -> bool {
+        matches!(self, Self::UpdateMany(_) | Self::DeleteMany(_))
+    }
+
+    /// Checks that the update or replacement document of this write is well-formed.
+    pub(crate) fn validate(&self) -> Result<()> {
+        match self {
+            Self::UpdateOne(UpdateOneModel {
+                update: UpdateModifications::Document(update),
+                ..
+            })
+            | Self::UpdateMany(UpdateManyModel {
+                update: UpdateModifications::Document(update),
+                ..
+            }) => bson_util::update_document_check(update),
+            Self::ReplaceOne(model) => bson_util::replacement_raw_document_check(
+                &RawDocumentBuf::from_document(&model.replacement)?,
+            ),
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Inserts a single document.
+#[derive(Clone, Debug, TypedBuilder)]
+#[builder(field_defaults(setter(into)))]
+#[non_exhaustive]
+pub struct InsertOneModel {
+    /// The namespace on which the insert should be performed.
+    pub namespace: Namespace,
+
+    /// The document to insert. An `_id` will be generated for the document if it does not
+    /// already have one.
+    pub document: Document,
+}
+
+impl From<InsertOneModel> for WriteModel {
+    fn from(model: InsertOneModel) -> Self {
+        Self::InsertOne(model)
+    }
+}
+
+/// Updates a single document.
+#[derive(Clone, Debug, TypedBuilder)]
+#[builder(field_defaults(setter(into)))]
+#[non_exhaustive]
+pub struct UpdateOneModel {
+    /// The namespace on which the update should be performed.
+    pub namespace: Namespace,
+
+    /// The filter to use. The first document matching this filter will be updated.
+    pub filter: Document,
+
+    /// The update to perform.
+    pub update: UpdateModifications,
+
+    /// A set of filters specifying to which array elements an update should apply.
+    #[builder(default)]
+    pub array_filters: Option<Vec<Document>>,
+
+    /// The collation to use.
+    #[builder(default)]
+    pub collation: Option<Collation>,
+
+    /// The index to use.
+    #[builder(default)]
+    pub hint: Option<Hint>,
+
+    /// Whether a new document should be created if no document matches the filter.
+    ///
+    /// Defaults to false.
+    #[builder(default)]
+    pub upsert: Option<bool>,
+}
+
+impl From<UpdateOneModel> for WriteModel {
+    fn from(model: UpdateOneModel) -> Self {
+        Self::UpdateOne(model)
+    }
+}
+
+/// Updates multiple documents.
+#[derive(Clone, Debug, TypedBuilder)]
+#[builder(field_defaults(setter(into)))]
+#[non_exhaustive]
+pub struct UpdateManyModel {
+    /// The namespace on which the update should be performed.
+    pub namespace: Namespace,
+
+    /// The filter to use. All documents matching this filter will be updated.
+    pub filter: Document,
+
+    /// The update to perform.
+    pub update: UpdateModifications,
+
+    /// A set of filters specifying to which array elements an update should apply.
+    #[builder(default)]
+    pub array_filters: Option<Vec<Document>>,
+
+    /// The collation to use.
+    #[builder(default)]
+    pub collation: Option<Collation>,
+
+    /// The index to use.
+    #[builder(default)]
+    pub hint: Option<Hint>,
+
+    /// Whether a new document should be created if no document matches the filter.
+    ///
+    /// Defaults to false.
+    #[builder(default)]
+    pub upsert: Option<bool>,
+}
+
+impl From<UpdateManyModel> for WriteModel {
+    fn from(model: UpdateManyModel) -> Self {
+        Self::UpdateMany(model)
+    }
+}
+
+/// Replaces a single document.
+#[derive(Clone, Debug, TypedBuilder)]
+#[builder(field_defaults(setter(into)))]
+#[non_exhaustive]
+pub struct ReplaceOneModel {
+    /// The namespace on which the replace should be performed.
+    pub namespace: Namespace,
+
+    /// The filter to use. The first document matching this filter will be replaced.
+    pub filter: Document,
+
+    /// The replacement document.
+    pub replacement: Document,
+
+    /// The collation to use.
+    #[builder(default)]
+    pub collation: Option<Collation>,
+
+    /// The index to use.
+    #[builder(default)]
+    pub hint: Option<Hint>,
+
+    /// Whether a new document should be created if no document matches the filter.
+    ///
+    /// Defaults to false.
+    #[builder(default)]
+    pub upsert: Option<bool>,
+}
+
+impl From<ReplaceOneModel> for WriteModel {
+    fn from(model: ReplaceOneModel) -> Self {
+        Self::ReplaceOne(model)
+    }
+}
+
+/// Deletes a single document.
+#[derive(Clone, Debug, TypedBuilder)]
+#[builder(field_defaults(setter(into)))]
+#[non_exhaustive]
+pub struct DeleteOneModel {
+    /// The namespace on which the delete should be performed.
+    pub namespace: Namespace,
+
+    /// The filter to use. The first document matching this filter will be deleted.
+    pub filter: Document,
+
+    /// The collation to use.
+    #[builder(default)]
+    pub collation: Option<Collation>,
+
+    /// The index to use.
+    #[builder(default)]
+    pub hint: Option<Hint>,
+}
+
+impl From<DeleteOneModel> for WriteModel {
+    fn from(model: DeleteOneModel) -> Self {
+        Self::DeleteOne(model)
+    }
+}
+
+/// Deletes multiple documents.
+#[derive(Clone, Debug, TypedBuilder)]
+#[builder(field_defaults(setter(into)))]
+#[non_exhaustive]
+pub struct DeleteManyModel {
+    /// The namespace on which the delete should be performed.
+    pub namespace: Namespace,
+
+    /// The filter to use. All documents matching this filter will be deleted.
+    pub filter: Document,
+
+    /// The collation to use.
+    #[builder(default)]
+    pub collation: Option<Collation>,
+
+    /// The index to use.
+    #[builder(default)]
+    pub hint: Option<Hint>,
+}
+
+impl From<DeleteManyModel> for WriteModel {
+    fn from(model: DeleteManyModel) -> Self {
+        Self::DeleteMany(model)
+    }
+}
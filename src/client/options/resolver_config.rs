@@ -1,4 +1,9 @@
-use trust_dns_resolver::config::ResolverConfig as TrustDnsResolverConfig;
+use std::{net::SocketAddr, time::Duration};
+
+use futures_core::future::BoxFuture;
+use trust_dns_resolver::config::{NameServerConfigGroup, ResolverConfig as TrustDnsResolverConfig};
+
+use crate::error::Result;
 
 /// Configuration for the upstream nameservers to use for resolution.
 ///
@@ -40,4 +45,47 @@ impl ResolverConfig {
             inner: TrustDnsResolverConfig::quad9(),
         }
     }
+
+    /// Creates a configuration that queries the given nameservers over unencrypted UDP and TCP.
+    pub fn from_nameservers(nameservers: impl IntoIterator<Item = SocketAddr>) -> Self {
+        let mut group = NameServerConfigGroup::new();
+        for nameserver in nameservers {
+            group.merge(NameServerConfigGroup::from_ips_clear(
+                &[nameserver.ip()],
+                nameserver.port(),
+                true,
+            ));
+        }
+        ResolverConfig {
+            inner: TrustDnsResolverConfig::from_parts(None, vec![], group),
+        }
+    }
+}
+
+/// A DNS resolver used for the SRV and TXT lookups performed for `mongodb+srv` connection strings,
+/// both when parsing the connection string and when polling for changes to the SRV records.
+///
+/// Implementing this allows lookups to be served by other service-discovery backends, answered
+/// with fixed records in tests, or cached as desired. By default, a resolver configured from the
+/// host system or a [`ResolverConfig`] is used.
+pub trait DnsResolver: Send + Sync {
+    /// Looks up the SRV records for `name`. An error should be returned if no records are found.
+    fn srv_lookup<'a>(&'a self, name: &'a str) -> BoxFuture<'a, Result<Vec<SrvRecord>>>;
+
+    /// Looks up the TXT records for `name`, returning each record's character strings joined into
+    /// a single string. An empty list should be returned if no records are found.
+    fn txt_lookup<'a>(&'a self, name: &'a str) -> BoxFuture<'a, Result<Vec<String>>>;
+}
+
+/// A record returned from an SRV lookup by a [`DnsResolver`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct SrvRecord {
+    /// The hostname of the target.
+    pub target: String,
+
+    /// The port of the target.
+    pub port: u16,
+
+    /// How long the record can be cached for.
+    pub ttl: Duration,
 }
@@ -1,12 +1,24 @@
-use std::time::Duration;
+use std::{
+    net::SocketAddr,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
 
 use bson::UuidRepresentation;
+use futures_core::future::BoxFuture;
 use pretty_assertions::assert_eq;
 use serde::Deserialize;
 
 use crate::{
     bson::{Bson, Document},
-    client::options::{ClientOptions, ConnectionString, ServerAddress},
+    client::options::{
+        ClientOptions,
+        ConnectionString,
+        DnsResolver,
+        ResolverConfig,
+        ServerAddress,
+        SrvRecord,
+    },
     error::ErrorKind,
     options::Compressor,
     test::run_spec_test,
@@ -52,10 +64,6 @@ async fn run_test(test_file: TestFile) {
         // TODO: RUST-229: Implement IPv6 Support
         test_case.description.contains("ipv6")
             || test_case.description.contains("IP literal")
-            // TODO: RUST-226: Investigate whether tlsCertificateKeyFilePassword is supported in rustls
-            || test_case
-                .description
-                .contains("tlsCertificateKeyFilePassword")
             // Not Implementing
             || test_case.description.contains("tlsAllowInvalidHostnames")
             || test_case.description.contains("single-threaded")
@@ -316,6 +324,20 @@ async fn parse_with_no_default_database() {
     );
 }
 
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+async fn parse_timeout_ms() {
+    let options = ClientOptions::parse("mongodb://localhost/?timeoutMS=1500")
+        .await
+        .unwrap();
+    assert_eq!(options.timeout, Some(Duration::from_millis(1500)));
+
+    let error = ClientOptions::parse("mongodb://localhost/?timeoutMS=-1")
+        .await
+        .unwrap_err();
+    assert!(matches!(*error.kind, ErrorKind::InvalidArgument { .. }));
+}
+
 #[cfg_attr(feature = "tokio-runtime", tokio::test)]
 #[cfg_attr(feature = "async-std-runtime", async_std::test)]
 async fn options_debug_omits_uri() {
@@ -338,3 +360,159 @@ async fn options_enforce_min_heartbeat_frequency() {
 
     Client::with_options(options).unwrap_err();
 }
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+async fn parse_proxy_options() {
+    let options = ClientOptions::parse(
+        "mongodb://localhost/?proxyHost=bastion.example.com&proxyPort=1081&proxyUsername=user&\
+         proxyPassword=p%40ss",
+    )
+    .await
+    .unwrap();
+    assert_eq!(options.proxy_host.as_deref(), Some("bastion.example.com"));
+    assert_eq!(options.proxy_port, Some(1081));
+    assert_eq!(options.proxy_username.as_deref(), Some("user"));
+    assert_eq!(options.proxy_password.as_deref(), Some("p@ss"));
+    assert!(!format!("{:?}", options).contains("p@ss"));
+
+    let proxy = options.socks5_proxy().unwrap();
+    assert_eq!(proxy.address.to_string(), "bastion.example.com:1081");
+    assert_eq!(
+        proxy.credentials,
+        Some(("user".to_string(), "p@ss".to_string()))
+    );
+
+    let options = ClientOptions::parse("mongodb://localhost/?proxyHost=bastion")
+        .await
+        .unwrap();
+    assert_eq!(
+        options.socks5_proxy().unwrap().address.to_string(),
+        "bastion:1080"
+    );
+
+    for uri in [
+        "mongodb://localhost/?proxyPort=1080",
+        "mongodb://localhost/?proxyUsername=user&proxyPassword=pass",
+        "mongodb://localhost/?proxyHost=bastion&proxyUsername=user",
+        "mongodb://localhost/?proxyHost=bastion&proxyPassword=pass",
+        "mongodb://localhost/?proxyHost=bastion&proxyPort=-1",
+    ] {
+        let error = ClientOptions::parse(uri).await.unwrap_err();
+        assert!(
+            matches!(*error.kind, ErrorKind::InvalidArgument { .. }),
+            "{}: {:?}",
+            uri,
+            error
+        );
+    }
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+async fn srv_options_validation() {
+    for uri in [
+        "mongodb://localhost/?srvMaxHosts=1",
+        "mongodb://localhost/?srvServiceName=customname",
+    ] {
+        let error = ClientOptions::parse(uri).await.unwrap_err();
+        assert!(
+            matches!(*error.kind, ErrorKind::InvalidArgument { .. }),
+            "{}: {:?}",
+            uri,
+            error
+        );
+    }
+
+    let options = ClientOptions::builder()
+        .srv_max_hosts(1)
+        .repl_set_name("repl0".to_string())
+        .build();
+    options.validate().unwrap_err();
+
+    let options = ClientOptions::builder()
+        .srv_max_hosts(0)
+        .repl_set_name("repl0".to_string())
+        .build();
+    options.validate().unwrap();
+}
+
+/// A `DnsResolver` that returns fixed records and keeps track of the names it was asked for.
+#[derive(Default)]
+struct StubDnsResolver {
+    lookups: Mutex<Vec<String>>,
+}
+
+impl DnsResolver for StubDnsResolver {
+    fn srv_lookup<'a>(
+        &'a self,
+        name: &'a str,
+    ) -> BoxFuture<'a, crate::error::Result<Vec<SrvRecord>>> {
+        self.lookups.lock().unwrap().push(name.to_string());
+        let records = [27017, 27018]
+            .into_iter()
+            .map(|port| SrvRecord {
+                target: "localhost.test.build.10gen.cc.".to_string(),
+                port,
+                ttl: Duration::from_secs(60),
+            })
+            .collect();
+        Box::pin(async move { Ok(records) })
+    }
+
+    fn txt_lookup<'a>(&'a self, name: &'a str) -> BoxFuture<'a, crate::error::Result<Vec<String>>> {
+        self.lookups.lock().unwrap().push(name.to_string());
+        Box::pin(async move { Ok(vec!["replicaSet=repl0".to_string()]) })
+    }
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+async fn parse_with_custom_dns_resolver() {
+    let resolver = Arc::new(StubDnsResolver::default());
+    let options = ClientOptions::parse_with_dns_resolver(
+        "mongodb+srv://test1.test.build.10gen.cc/?srvServiceName=customname",
+        resolver.clone(),
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(
+        options.hosts,
+        vec![
+            ServerAddress::Tcp {
+                host: "localhost.test.build.10gen.cc".to_string(),
+                port: Some(27017),
+            },
+            ServerAddress::Tcp {
+                host: "localhost.test.build.10gen.cc".to_string(),
+                port: Some(27018),
+            },
+        ]
+    );
+    assert_eq!(options.repl_set_name.as_deref(), Some("repl0"));
+    assert!(options.dns_resolver.is_some());
+    assert_eq!(
+        *resolver.lookups.lock().unwrap(),
+        vec![
+            "_customname._tcp.test1.test.build.10gen.cc".to_string(),
+            "test1.test.build.10gen.cc".to_string(),
+        ]
+    );
+}
+
+#[test]
+fn resolver_config_from_nameservers() {
+    let ipv4: SocketAddr = "10.0.0.1:53".parse().unwrap();
+    let ipv6: SocketAddr = "[::1]:5353".parse().unwrap();
+    let config = ResolverConfig::from_nameservers([ipv4, ipv6]);
+
+    let addresses: Vec<_> = config
+        .inner
+        .name_servers()
+        .iter()
+        .map(|ns| ns.socket_addr)
+        .collect();
+    // Each nameserver is queried over both UDP and TCP.
+    assert_eq!(addresses, vec![ipv4, ipv4, ipv6, ipv6]);
+}
@@ -211,6 +211,7 @@ impl ClientSession {
     ) -> Self {
         let timeout = client.inner.topology.logical_session_timeout();
         let server_session = client.inner.session_pool.check_out(timeout).await;
+        let snapshot_time = options.as_ref().and_then(|opts| opts.snapshot_time);
         Self {
             drop_token: client.register_async_drop(),
             client,
@@ -219,7 +220,7 @@ impl ClientSession {
             is_implicit,
             options,
             transaction: Default::default(),
-            snapshot_time: None,
+            snapshot_time,
             operation_time: None,
             #[cfg(test)]
             convenient_transaction_timeout: None,
@@ -281,6 +282,13 @@ impl ClientSession {
         self.operation_time
     }
 
+    /// The point in time that reads in this session observe if it is a snapshot session. This is
+    /// either the time specified via [`SessionOptions::snapshot_time`] or, if none was given, the
+    /// time returned by the server in response to the first read in the session.
+    pub fn snapshot_time(&self) -> Option<Timestamp> {
+        self.snapshot_time
+    }
+
     pub(crate) fn causal_consistency(&self) -> bool {
         self.options()
             .and_then(|opts| opts.causal_consistency)
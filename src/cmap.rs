@@ -9,13 +9,20 @@ pub(crate) mod options;
 mod status;
 mod worker;
 
-use std::time::Instant;
+use std::time::{Duration, Instant};
 
 use derivative::Derivative;
 
 pub use self::conn::ConnectionInfo;
 pub(crate) use self::{
-    conn::{Command, Connection, RawCommand, RawCommandResponse, StreamDescription},
+    conn::{
+        Command,
+        Connection,
+        DocumentSequence,
+        RawCommand,
+        RawCommandResponse,
+        StreamDescription,
+    },
     status::PoolGenerationSubscriber,
     worker::PoolGeneration,
 };
@@ -36,7 +43,7 @@ use crate::{
         PoolCreatedEvent,
     },
     options::ServerAddress,
-    runtime::AcknowledgmentReceiver,
+    runtime::{self, AcknowledgmentReceiver},
     sdam::{BroadcastMessage, TopologyUpdater},
 };
 use connection_requester::ConnectionRequester;
@@ -120,7 +127,10 @@ impl ConnectionPool {
     /// Checks out a connection from the pool. This method will yield until this thread is at the
     /// front of the wait queue, and then will block again if no available connections are in the
     /// pool and the total number of connections is not less than the max pool size.
-    pub(crate) async fn check_out(&self) -> Result<Connection> {
+    ///
+    /// If a timeout is provided and no connection could be checked out within it, a timeout
+    /// error is returned.
+    pub(crate) async fn check_out(&self, timeout: Option<Duration>) -> Result<Connection> {
         let time_started = Instant::now();
         self.event_emitter.emit_event(|| {
             ConnectionCheckoutStartedEvent {
@@ -129,32 +139,51 @@ impl ConnectionPool {
             .into()
         });
 
-        let response = self.connection_requester.request().await;
-
-        let conn = match response {
-            ConnectionRequestResult::Pooled(c) => Ok(*c),
-            ConnectionRequestResult::Establishing(task) => task.await,
-            ConnectionRequestResult::PoolCleared(e) => {
-                Err(Error::pool_cleared_error(&self.address, &e))
-            }
-            ConnectionRequestResult::PoolWarmed => {
-                Err(Error::internal("Invalid result from connection requester"))
+        let acquire = async {
+            match self.connection_requester.request().await {
+                ConnectionRequestResult::Pooled(c) => Ok(*c),
+                ConnectionRequestResult::Establishing(task) => task.await,
+                ConnectionRequestResult::PoolCleared(e) => {
+                    Err(Error::pool_cleared_error(&self.address, &e))
+                }
+                ConnectionRequestResult::PoolWarmed => {
+                    Err(Error::internal("Invalid result from connection requester"))
+                }
             }
         };
 
+        // If the timeout elapses, the pending request is dropped; the worker will either discard
+        // it or check the connection it was fulfilled with back into the pool.
+        let conn = match timeout {
+            Some(timeout) => runtime::timeout(timeout, acquire)
+                .await
+                .unwrap_or_else(|_| {
+                    Err(Error::operation_timeout(format!(
+                        "timed out after {:?} while checking out a connection to {}",
+                        timeout, self.address
+                    )))
+                }),
+            None => acquire.await,
+        };
+
         match conn {
             Ok(ref conn) => {
                 self.event_emitter
                     .emit_event(|| conn.checked_out_event(time_started).into());
             }
 
-            Err(ref _err) => {
+            Err(ref err) => {
+                let reason = if err.is_timeout_error() {
+                    ConnectionCheckoutFailedReason::Timeout
+                } else {
+                    ConnectionCheckoutFailedReason::ConnectionError
+                };
                 self.event_emitter.emit_event(|| {
                     ConnectionCheckoutFailedEvent {
                         address: self.address.clone(),
-                        reason: ConnectionCheckoutFailedReason::ConnectionError,
+                        reason,
                         #[cfg(feature = "tracing-unstable")]
-                        error: Some(_err.clone()),
+                        error: Some(err.clone()),
                         duration: Instant::now() - time_started,
                     }
                     .into()
@@ -1,6 +1,6 @@
 mod command;
 mod stream_description;
-mod wire;
+pub(crate) mod wire;
 
 use std::{
     sync::Arc,
@@ -33,7 +33,7 @@ use crate::{
     options::ServerAddress,
     runtime::AsyncStream,
 };
-pub(crate) use command::{Command, RawCommand, RawCommandResponse};
+pub(crate) use command::{Command, DocumentSequence, RawCommand, RawCommandResponse};
 pub(crate) use stream_description::StreamDescription;
 pub(crate) use wire::next_request_id;
 
@@ -121,6 +121,11 @@ pub(crate) struct Connection {
     /// monitoring connections as we do not emit events for those.
     #[derivative(Debug = "ignore")]
     event_emitter: Option<CmapEventEmitter>,
+
+    /// The MONGODB-OIDC access token this connection was last authenticated with, if any. This is
+    /// used to avoid discarding a newer cached token when reauthenticating.
+    #[derivative(Debug = "ignore")]
+    pub(crate) oidc_access_token: Option<String>,
 }
 
 impl Connection {
@@ -147,6 +152,7 @@ impl Connection {
             pinned_sender: None,
             compressor: None,
             more_to_come: false,
+            oidc_access_token: None,
         }
     }
 
@@ -446,6 +452,7 @@ impl Connection {
             pinned_sender: self.pinned_sender.clone(),
             compressor: self.compressor.clone(),
             more_to_come: false,
+            oidc_access_token: self.oidc_access_token.take(),
         }
     }
 
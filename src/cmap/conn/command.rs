@@ -22,6 +22,8 @@ pub(crate) struct RawCommand {
     /// Whether or not the server may respond to this command multiple times via the moreToComeBit.
     pub(crate) exhaust_allowed: bool,
     pub(crate) bytes: Vec<u8>,
+    /// Documents to be sent alongside the command body as OP_MSG document sequences.
+    pub(crate) document_sequences: Vec<DocumentSequence>,
 }
 
 impl RawCommand {
@@ -31,6 +33,15 @@ impl RawCommand {
     }
 }
 
+/// A set of documents sent as a kind 1 section of an OP_MSG rather than as an array field in the
+/// command body, which allows the total size of the documents to exceed `maxBsonObjectSize`.
+#[derive(Clone, Debug)]
+pub(crate) struct DocumentSequence {
+    /// The name of the command field the documents are sent for.
+    pub(crate) identifier: String,
+    pub(crate) documents: Vec<RawDocumentBuf>,
+}
+
 /// Driver-side model of a database command.
 #[serde_with::skip_serializing_none]
 #[derive(Clone, Debug, Serialize, Default)]
@@ -42,6 +53,9 @@ pub(crate) struct Command<T = Document> {
     #[serde(skip)]
     pub(crate) exhaust_allowed: bool,
 
+    #[serde(skip)]
+    pub(crate) document_sequences: Vec<DocumentSequence>,
+
     #[serde(flatten)]
     pub(crate) body: T,
 
@@ -65,7 +79,7 @@ pub(crate) struct Command<T = Document> {
 
     autocommit: Option<bool>,
 
-    read_concern: Option<ReadConcernInternal>,
+    pub(crate) read_concern: Option<ReadConcernInternal>,
 
     recovery_token: Option<Document>,
 }
@@ -76,6 +90,7 @@ impl<T> Command<T> {
             name,
             target_db,
             exhaust_allowed: false,
+            document_sequences: Vec::new(),
             body,
             lsid: None,
             cluster_time: None,
@@ -99,6 +114,7 @@ impl<T> Command<T> {
             name,
             target_db,
             exhaust_allowed: false,
+            document_sequences: Vec::new(),
             body,
             lsid: None,
             cluster_time: None,
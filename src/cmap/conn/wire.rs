@@ -2,6 +2,8 @@ mod header;
 mod message;
 mod util;
 
+#[cfg(feature = "mock-server")]
+pub(crate) use self::message::MessageSection;
 pub(crate) use self::{
     message::{Message, MessageFlags},
     util::next_request_id,
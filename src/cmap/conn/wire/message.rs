@@ -1,6 +1,7 @@
 use std::io::Read;
 
 use bitflags::bitflags;
+use bson::RawDocumentBuf;
 use tokio::io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt};
 
 use super::header::{Header, OpCode};
@@ -38,6 +39,7 @@ impl Message {
                 target_db: command.target_db,
                 name: command.name,
                 exhaust_allowed: command.exhaust_allowed,
+                document_sequences: command.document_sequences,
             },
             request_id,
         ))
@@ -52,10 +54,29 @@ impl Message {
             flags |= MessageFlags::EXHAUST_ALLOWED;
         }
 
+        let mut sections = vec![MessageSection::Document(command.bytes)];
+        for sequence in command.document_sequences {
+            let documents: Vec<Vec<u8>> = sequence
+                .documents
+                .into_iter()
+                .map(RawDocumentBuf::into_bytes)
+                .collect();
+            // The size includes the size field itself and the null-terminated identifier.
+            let size = std::mem::size_of::<i32>()
+                + sequence.identifier.len()
+                + 1
+                + documents.iter().map(Vec::len).sum::<usize>();
+            sections.push(MessageSection::Sequence {
+                size: size as i32,
+                identifier: sequence.identifier,
+                documents,
+            });
+        }
+
         Self {
             response_to: 0,
             flags,
-            sections: vec![MessageSection::Document(command.bytes)],
+            sections,
             checksum: None,
             request_id,
         }
@@ -221,7 +242,7 @@ impl Message {
             flags,
             sections,
             checksum,
-            request_id: None,
+            request_id: Some(header.request_id),
         })
     }
 
@@ -109,6 +109,12 @@ impl ConnectionRequest {
     pub(super) fn is_warm_pool(&self) -> bool {
         self.warm_pool
     }
+
+    /// Whether the requester stopped listening for a response, e.g. because its checkout timed
+    /// out while the request was waiting in the queue.
+    pub(super) fn is_abandoned(&self) -> bool {
+        self.sender.is_closed()
+    }
 }
 
 #[derive(Debug)]
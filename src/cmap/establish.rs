@@ -2,6 +2,7 @@ pub(super) mod handshake;
 
 use std::time::Duration;
 
+pub(crate) use self::handshake::is_faas;
 use self::handshake::{Handshaker, HandshakerOptions};
 use super::{
     conn::{ConnectionGeneration, LoadBalancedGeneration, PendingConnection},
@@ -15,7 +16,7 @@ use crate::{
     },
     error::{Error as MongoError, ErrorKind, Result},
     hello::HelloReply,
-    runtime::{self, stream::DEFAULT_CONNECT_TIMEOUT, AsyncStream, TlsConfig},
+    runtime::{self, socks5::Socks5Proxy, stream::DEFAULT_CONNECT_TIMEOUT, AsyncStream, TlsConfig},
     sdam::HandshakePhase,
 };
 
@@ -29,12 +30,16 @@ pub(crate) struct ConnectionEstablisher {
     /// Cached configuration needed to create TLS connections, if needed.
     tls_config: Option<TlsConfig>,
 
+    /// The SOCKS5 proxy that connections are tunneled through, if any.
+    proxy: Option<Socks5Proxy>,
+
     connect_timeout: Duration,
 }
 
 pub(crate) struct EstablisherOptions {
     handshake_options: HandshakerOptions,
     tls_options: Option<TlsOptions>,
+    proxy: Option<Socks5Proxy>,
     connect_timeout: Option<Duration>,
 }
 
@@ -49,6 +54,7 @@ impl EstablisherOptions {
                 load_balanced: opts.load_balanced.unwrap_or(false),
             },
             tls_options: opts.tls_options(),
+            proxy: opts.socks5_proxy(),
             connect_timeout: opts.connect_timeout,
         }
     }
@@ -74,6 +80,7 @@ impl ConnectionEstablisher {
         Ok(Self {
             handshaker,
             tls_config,
+            proxy: options.proxy,
             connect_timeout,
         })
     }
@@ -81,7 +88,7 @@ impl ConnectionEstablisher {
     async fn make_stream(&self, address: ServerAddress) -> Result<AsyncStream> {
         runtime::timeout(
             self.connect_timeout,
-            AsyncStream::connect(address, self.tls_config.as_ref()),
+            AsyncStream::connect(address, self.tls_config.as_ref(), self.proxy.as_ref()),
         )
         .await?
     }
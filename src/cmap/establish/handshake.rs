@@ -216,6 +216,12 @@ impl RuntimeEnvironment {
     }
 }
 
+/// Whether the driver is running in a function-as-a-service (FaaS) environment, as detected for the
+/// `env` field of the handshake's client metadata.
+pub(crate) fn is_faas() -> bool {
+    FaasEnvironmentName::new().is_some()
+}
+
 fn var_set(name: &str) -> bool {
     env::var_os(name).map_or(false, |v| !v.is_empty())
 }
@@ -258,7 +258,7 @@ impl Operation {
             }
             Operation::CheckOut { label } => {
                 if let Some(pool) = state.pool.read().await.deref() {
-                    let conn = pool.check_out().await?;
+                    let conn = pool.check_out(None).await?;
 
                     if let Some(label) = label {
                         state.connections.write().await.insert(label, conn);
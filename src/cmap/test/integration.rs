@@ -52,7 +52,7 @@ async fn acquire_connection_and_send_command() {
         bson::oid::ObjectId::new(),
         Some(pool_options),
     );
-    let mut connection = pool.check_out().await.unwrap();
+    let mut connection = pool.check_out(None).await.unwrap();
 
     let body = doc! { "listDatabases": 1 };
     let read_pref = ReadPreference::PrimaryPreferred {
@@ -133,7 +133,7 @@ async fn concurrent_connections() {
     let tasks = (0..2).map(|_| {
         let pool_clone = pool.clone();
         runtime::spawn(async move {
-            pool_clone.check_out().await.unwrap();
+            pool_clone.check_out(None).await.unwrap();
         })
     });
     futures::future::join_all(tasks).await;
@@ -219,7 +219,9 @@ async fn connection_error_during_establishment() {
         Some(options),
     );
 
-    pool.check_out().await.expect_err("check out should fail");
+    pool.check_out(None)
+        .await
+        .expect_err("check out should fail");
 
     subscriber
         .wait_for_event(EVENT_TIMEOUT, |e| match e {
@@ -345,8 +345,14 @@ impl ConnectionPoolWorker {
             }
 
             if self.can_service_connection_request() {
-                if let Some(request) = self.wait_queue.pop_front() {
+                // Requests whose checkout already timed out are discarded rather than serviced,
+                // so that no connection is established on behalf of a requester that is gone.
+                while let Some(request) = self.wait_queue.pop_front() {
+                    if request.is_abandoned() {
+                        continue;
+                    }
                     self.check_out(request);
+                    break;
                 }
             }
         }
@@ -1,6 +1,14 @@
 pub mod options;
 
-use std::{borrow::Borrow, collections::HashSet, fmt, fmt::Debug, str::FromStr, sync::Arc};
+use std::{
+    borrow::Borrow,
+    collections::HashSet,
+    fmt,
+    fmt::Debug,
+    str::FromStr,
+    sync::Arc,
+    time::Duration,
+};
 
 use futures_util::{
     future,
@@ -26,24 +34,40 @@ use crate::{
     client::options::ServerAddress,
     cmap::conn::PinnedConnectionHandle,
     concern::{ReadConcern, WriteConcern},
+    cursor::resumable::FindArgs,
     error::{convert_bulk_errors, BulkWriteError, BulkWriteFailure, Error, ErrorKind, Result},
     index::IndexModel,
     operation::{
+        is_out_or_merge,
         Aggregate,
+        BulkDelete,
+        BulkUpdate,
+        CollMod,
         Count,
         CountDocuments,
         CreateIndexes,
+        CreateSearchIndexes,
         Delete,
+        DeleteStatement,
         Distinct,
         DropCollection,
         DropIndexes,
+        DropSearchIndex,
+        Explain,
         Find,
         FindAndModify,
         Insert,
         ListIndexes,
+        RenameCollection,
         Update,
+        UpdateOrReplace,
+        UpdateSearchIndex,
+        UpdateStatement,
     },
     results::{
+        BulkWriteResult,
+        CollectionLatencyStats,
+        CollectionStorageStats,
         CreateIndexResult,
         CreateIndexesResult,
         DeleteResult,
@@ -51,11 +75,21 @@ use crate::{
         InsertOneResult,
         UpdateResult,
     },
+    search_index::{
+        options::{
+            CreateSearchIndexOptions,
+            DropSearchIndexOptions,
+            ListSearchIndexOptions,
+            UpdateSearchIndexOptions,
+        },
+        SearchIndexModel,
+    },
     selection_criteria::SelectionCriteria,
     Client,
     ClientSession,
     Cursor,
     Database,
+    ResumableCursor,
     SessionCursor,
 };
 
@@ -143,6 +177,7 @@ struct CollectionInner {
     selection_criteria: Option<SelectionCriteria>,
     read_concern: Option<ReadConcern>,
     write_concern: Option<WriteConcern>,
+    timeout: Option<Duration>,
     human_readable_serialization: bool,
 }
 
@@ -158,6 +193,8 @@ impl<T> Collection<T> {
         let write_concern = options
             .write_concern
             .or_else(|| db.write_concern().cloned());
+
+        let timeout = options.timeout.or_else(|| db.timeout());
         #[allow(deprecated)]
         let human_readable_serialization = options.human_readable_serialization.unwrap_or_default();
 
@@ -169,6 +206,7 @@ impl<T> Collection<T> {
                 selection_criteria,
                 read_concern,
                 write_concern,
+                timeout,
                 human_readable_serialization,
             }),
             _phantom: Default::default(),
@@ -221,6 +259,11 @@ impl<T> Collection<T> {
         self.inner.write_concern.as_ref()
     }
 
+    /// Gets the default operation timeout of the `Collection`.
+    pub fn timeout(&self) -> Option<Duration> {
+        self.inner.timeout
+    }
+
     #[allow(clippy::needless_option_as_deref)]
     async fn drop_common(
         &self,
@@ -257,6 +300,73 @@ impl<T> Collection<T> {
         self.drop_common(options, session).await
     }
 
+    async fn modify_common(
+        &self,
+        options: impl Into<Option<CollModOptions>>,
+        session: impl Into<Option<&mut ClientSession>>,
+    ) -> Result<()> {
+        let mut options: Option<CollModOptions> = options.into();
+        resolve_options!(self, options, [write_concern]);
+
+        let coll_mod = CollMod::new(self.namespace(), options);
+        self.client().execute_operation(coll_mod, session).await
+    }
+
+    /// Modifies the collection with the
+    /// [`collMod`](https://www.mongodb.com/docs/manual/reference/command/collMod/) command, e.g. to
+    /// change its validator, hide one of its indexes, or change how long documents are kept by a
+    /// TTL index.
+    pub async fn modify(&self, options: CollModOptions) -> Result<()> {
+        self.modify_common(options, None).await
+    }
+
+    /// Modifies the collection with the
+    /// [`collMod`](https://www.mongodb.com/docs/manual/reference/command/collMod/) command using the
+    /// provided `ClientSession`.
+    pub async fn modify_with_session(
+        &self,
+        options: CollModOptions,
+        session: &mut ClientSession,
+    ) -> Result<()> {
+        self.modify_common(options, session).await
+    }
+
+    async fn rename_common(
+        &self,
+        new_namespace: Namespace,
+        drop_target: bool,
+        session: impl Into<Option<&mut ClientSession>>,
+    ) -> Result<()> {
+        let rename = RenameCollection::new(
+            self.namespace(),
+            new_namespace,
+            drop_target,
+            self.write_concern().cloned(),
+        );
+        self.client().execute_operation(rename, session).await
+    }
+
+    /// Renames the collection to `new_namespace`, which may be in a different database. If
+    /// `drop_target` is true, any existing collection at `new_namespace` will be dropped first;
+    /// otherwise, the rename will fail if such a collection exists.
+    ///
+    /// This handle will continue to refer to the old namespace after the rename.
+    pub async fn rename(&self, new_namespace: Namespace, drop_target: bool) -> Result<()> {
+        self.rename_common(new_namespace, drop_target, None).await
+    }
+
+    /// Renames the collection to `new_namespace` using the provided `ClientSession`. See
+    /// [`Collection::rename`] for more details.
+    pub async fn rename_with_session(
+        &self,
+        new_namespace: Namespace,
+        drop_target: bool,
+        session: &mut ClientSession,
+    ) -> Result<()> {
+        self.rename_common(new_namespace, drop_target, session)
+            .await
+    }
+
     #[cfg(feature = "in-use-encryption-unstable")]
     #[allow(clippy::needless_option_as_deref)]
     async fn drop_aux_collections(
@@ -327,12 +437,13 @@ impl<T> Collection<T> {
         pipeline: impl IntoIterator<Item = Document>,
         options: impl Into<Option<AggregateOptions>>,
     ) -> Result<Cursor<Document>> {
+        let pipeline: Vec<Document> = pipeline.into_iter().collect();
         let mut options = options.into();
-        resolve_options!(
-            self,
-            options,
-            [read_concern, write_concern, selection_criteria]
-        );
+        resolve_options!(self, options, [read_concern, selection_criteria, timeout]);
+        // Only aggregations that end in $out or $merge write, so only they inherit a write concern.
+        if is_out_or_merge(&pipeline) {
+            resolve_options!(self, options, [write_concern]);
+        }
 
         let aggregate = Aggregate::new(self.namespace(), pipeline, options);
         let client = self.client();
@@ -349,10 +460,14 @@ impl<T> Collection<T> {
         options: impl Into<Option<AggregateOptions>>,
         session: &mut ClientSession,
     ) -> Result<SessionCursor<Document>> {
+        let pipeline: Vec<Document> = pipeline.into_iter().collect();
         let mut options = options.into();
         resolve_read_concern_with_session!(self, options, Some(&mut *session))?;
-        resolve_write_concern_with_session!(self, options, Some(&mut *session))?;
+        if is_out_or_merge(&pipeline) {
+            resolve_write_concern_with_session!(self, options, Some(&mut *session))?;
+        }
         resolve_selection_criteria_with_session!(self, options, Some(&mut *session))?;
+        resolve_timeout_with_session!(self, options, Some(&mut *session));
 
         let aggregate = Aggregate::new(self.namespace(), pipeline, options);
         let client = self.client();
@@ -377,7 +492,7 @@ impl<T> Collection<T> {
         options: impl Into<Option<EstimatedDocumentCountOptions>>,
     ) -> Result<u64> {
         let mut options = options.into();
-        resolve_options!(self, options, [read_concern, selection_criteria]);
+        resolve_options!(self, options, [read_concern, selection_criteria, timeout]);
 
         let op = Count::new(self.namespace(), options);
 
@@ -395,6 +510,7 @@ impl<T> Collection<T> {
         let mut options = options.into();
         resolve_read_concern_with_session!(self, options, session.as_ref())?;
         resolve_selection_criteria_with_session!(self, options, session.as_ref())?;
+        resolve_timeout_with_session!(self, options, session.as_ref());
 
         let op = CountDocuments::new(self.namespace(), filter.into(), options)?;
         self.client().execute_operation(op, session).await
@@ -433,6 +549,7 @@ impl<T> Collection<T> {
 
         let mut options = options.into();
         resolve_write_concern_with_session!(self, options, session.as_ref())?;
+        resolve_timeout_with_session!(self, options, session.as_ref());
 
         let delete = Delete::new(self.namespace(), query, None, options);
         self.client().execute_operation(delete, session).await
@@ -537,6 +654,7 @@ impl<T> Collection<T> {
 
         let mut options = options.into();
         resolve_write_concern_with_session!(self, options, session.as_ref())?;
+        resolve_timeout_with_session!(self, options, session.as_ref());
 
         let delete = Delete::new(self.namespace(), query, Some(1), options);
         self.client().execute_operation(delete, session).await
@@ -583,6 +701,7 @@ impl<T> Collection<T> {
         let mut options = options.into();
         resolve_read_concern_with_session!(self, options, session.as_ref())?;
         resolve_selection_criteria_with_session!(self, options, session.as_ref())?;
+        resolve_timeout_with_session!(self, options, session.as_ref());
 
         let op = Distinct::new(
             self.namespace(),
@@ -691,7 +810,10 @@ impl<T> Collection<T> {
         &self,
         options: impl Into<Option<ListIndexesOptions>>,
     ) -> Result<Cursor<IndexModel>> {
-        let list_indexes = ListIndexes::new(self.namespace(), options.into());
+        let mut options = options.into();
+        resolve_options!(self, options, [timeout]);
+
+        let list_indexes = ListIndexes::new(self.namespace(), options);
         let client = self.client();
         client.execute_cursor_operation(list_indexes).await
     }
@@ -702,7 +824,10 @@ impl<T> Collection<T> {
         options: impl Into<Option<ListIndexesOptions>>,
         session: &mut ClientSession,
     ) -> Result<SessionCursor<IndexModel>> {
-        let list_indexes = ListIndexes::new(self.namespace(), options.into());
+        let mut options = options.into();
+        resolve_timeout_with_session!(self, options, Some(&mut *session));
+
+        let list_indexes = ListIndexes::new(self.namespace(), options);
         let client = self.client();
         client
             .execute_session_cursor_operation(list_indexes, session)
@@ -734,6 +859,141 @@ impl<T> Collection<T> {
         self.list_index_names_common(cursor.stream(session)).await
     }
 
+    /// Creates the given Atlas Search index on this collection. Returns the name of the created
+    /// index.
+    ///
+    /// Note that the index may not be queryable immediately; use
+    /// [`Collection::list_search_indexes`] to check its status.
+    pub async fn create_search_index(
+        &self,
+        model: SearchIndexModel,
+        options: impl Into<Option<CreateSearchIndexOptions>>,
+    ) -> Result<String> {
+        let mut names = self.create_search_indexes(vec![model], options).await?;
+        match names.len() {
+            1 => Ok(names.pop().unwrap()),
+            n => Err(ErrorKind::InvalidResponse {
+                message: format!("expected 1 index name, got {}", n),
+            }
+            .into()),
+        }
+    }
+
+    /// Creates the given Atlas Search indexes on this collection. Returns the names of the created
+    /// indexes.
+    ///
+    /// Note that the indexes may not be queryable immediately; use
+    /// [`Collection::list_search_indexes`] to check their status.
+    pub async fn create_search_indexes(
+        &self,
+        models: impl IntoIterator<Item = SearchIndexModel>,
+        options: impl Into<Option<CreateSearchIndexOptions>>,
+    ) -> Result<Vec<String>> {
+        let op = CreateSearchIndexes::new(
+            self.namespace(),
+            models.into_iter().collect(),
+            options.into(),
+        );
+        self.client().execute_operation(op, None).await
+    }
+
+    /// Updates the definition of the Atlas Search index with the given name on this collection.
+    pub async fn update_search_index(
+        &self,
+        name: impl AsRef<str>,
+        definition: Document,
+        options: impl Into<Option<UpdateSearchIndexOptions>>,
+    ) -> Result<()> {
+        let op = UpdateSearchIndex::new(
+            self.namespace(),
+            name.as_ref().to_string(),
+            definition,
+            options.into(),
+        );
+        self.client().execute_operation(op, None).await
+    }
+
+    /// Drops the Atlas Search index with the given name from this collection. Succeeds if the
+    /// collection does not exist.
+    pub async fn drop_search_index(
+        &self,
+        name: impl AsRef<str>,
+        options: impl Into<Option<DropSearchIndexOptions>>,
+    ) -> Result<()> {
+        let op = DropSearchIndex::new(self.namespace(), name.as_ref().to_string(), options.into());
+        self.client().execute_operation(op, None).await
+    }
+
+    /// Lists the Atlas Search indexes on this collection, or only the index with the given name if
+    /// one is provided. This is executed as an aggregation with a `$listSearchIndexes` stage.
+    pub async fn list_search_indexes(
+        &self,
+        name: impl Into<Option<&str>>,
+        options: impl Into<Option<ListSearchIndexOptions>>,
+    ) -> Result<Cursor<Document>> {
+        let mut stage = doc! {};
+        if let Some(name) = name.into() {
+            stage.insert("name", name);
+        }
+        let options = options
+            .into()
+            .map(ListSearchIndexOptions::into_aggregate_options);
+        self.clone_with_type::<Document>()
+            .aggregate(vec![doc! { "$listSearchIndexes": stage }], options)
+            .await
+    }
+
+    /// Gets storage statistics about the collection, such as its size and the sizes of its
+    /// indexes. This is executed as an aggregation with a `$collStats` stage, so on a sharded
+    /// cluster one set of statistics is returned for each shard the collection is stored on.
+    pub async fn storage_stats(
+        &self,
+        options: impl Into<Option<StorageStatsOptions>>,
+    ) -> Result<Vec<CollectionStorageStats>> {
+        let options = options.into().unwrap_or_default();
+        let mut storage_stats = doc! {};
+        if let Some(scale) = options.scale {
+            storage_stats.insert("scale", i64::from(scale));
+        }
+        self.coll_stats(
+            doc! { "storageStats": storage_stats },
+            options.into_aggregate_options(),
+        )
+        .await
+    }
+
+    /// Gets statistics about the latency of the reads, writes and commands performed on the
+    /// collection. This is executed as an aggregation with a `$collStats` stage, so on a sharded
+    /// cluster one set of statistics is returned for each shard the collection is stored on.
+    pub async fn latency_stats(
+        &self,
+        options: impl Into<Option<LatencyStatsOptions>>,
+    ) -> Result<Vec<CollectionLatencyStats>> {
+        let options = options.into().unwrap_or_default();
+        let mut latency_stats = doc! {};
+        if let Some(histograms) = options.histograms {
+            latency_stats.insert("histograms", histograms);
+        }
+        self.coll_stats(
+            doc! { "latencyStats": latency_stats },
+            options.into_aggregate_options(),
+        )
+        .await
+    }
+
+    async fn coll_stats<S: DeserializeOwned>(
+        &self,
+        spec: Document,
+        options: AggregateOptions,
+    ) -> Result<Vec<S>> {
+        self.clone_with_type::<Document>()
+            .aggregate(vec![doc! { "$collStats": spec }], options)
+            .await?
+            .and_then(|doc| async move { bson::from_document(doc).map_err(Error::from) })
+            .try_collect()
+            .await
+    }
+
     async fn update_many_common(
         &self,
         query: Document,
@@ -751,6 +1011,7 @@ impl<T> Collection<T> {
 
         let mut options = options.into();
         resolve_write_concern_with_session!(self, options, session.as_ref())?;
+        resolve_timeout_with_session!(self, options, session.as_ref());
 
         let update = Update::with_update(
             self.namespace(),
@@ -811,6 +1072,7 @@ impl<T> Collection<T> {
 
         let mut options = options.into();
         resolve_write_concern_with_session!(self, options, session.as_ref())?;
+        resolve_timeout_with_session!(self, options, session.as_ref());
 
         let update = Update::with_update(
             self.namespace(),
@@ -951,7 +1213,7 @@ impl<T> Collection<T> {
         options: impl Into<Option<FindOptions>>,
     ) -> Result<Cursor<T>> {
         let mut options = options.into();
-        resolve_options!(self, options, [read_concern, selection_criteria]);
+        resolve_options!(self, options, [read_concern, selection_criteria, timeout]);
 
         let find = Find::new(self.namespace(), filter.into(), options);
         let client = self.client();
@@ -969,12 +1231,219 @@ impl<T> Collection<T> {
         let mut options = options.into();
         resolve_read_concern_with_session!(self, options, Some(&mut *session))?;
         resolve_selection_criteria_with_session!(self, options, Some(&mut *session))?;
+        resolve_timeout_with_session!(self, options, Some(&mut *session));
 
         let find = Find::new(self.namespace(), filter.into(), options);
         let client = self.client();
 
         client.execute_session_cursor_operation(find, session).await
     }
+
+    /// Finds the documents in the collection matching `filter`, returning a [`ResumableCursor`]
+    /// that re-issues the `find` after the last returned document if the cursor is lost to a
+    /// resumable error, e.g. a network error during a primary failover or a `CursorNotFound`.
+    ///
+    /// The results are ordered by the [`sort`](FindOptions::sort) option, with `_id` appended
+    /// if it is not already present, and that sort key is used to resume. Tailable cursors are not
+    /// supported. See the [`ResumableCursor`] documentation for more details.
+    pub async fn find_resumable(
+        &self,
+        filter: impl Into<Option<Document>>,
+        options: impl Into<Option<FindOptions>>,
+    ) -> Result<ResumableCursor<T>>
+    where
+        T: DeserializeOwned,
+    {
+        let args = FindArgs::new(self.clone_with_type(), filter.into(), options.into())?;
+        let cursor = self
+            .find(args.filter().clone(), args.options().clone())
+            .await?;
+        Ok(ResumableCursor::new(cursor, args))
+    }
+
+    /// Explains a [`Collection::find`] operation with the given verbosity, returning the raw
+    /// output of the server's `explain` command.
+    pub async fn explain_find(
+        &self,
+        filter: impl Into<Option<Document>>,
+        options: impl Into<Option<FindOptions>>,
+        verbosity: ExplainVerbosity,
+    ) -> Result<Document> {
+        let mut options = options.into();
+        resolve_options!(self, options, [read_concern, selection_criteria, timeout]);
+
+        let find = Find::new(self.namespace(), filter.into(), options);
+        self.client()
+            .execute_operation(Explain::new(find, verbosity), None)
+            .await
+    }
+
+    /// Explains a [`Collection::aggregate`] operation with the given verbosity, returning the raw
+    /// output of the server's `explain` command.
+    pub async fn explain_aggregate(
+        &self,
+        pipeline: impl IntoIterator<Item = Document>,
+        options: impl Into<Option<AggregateOptions>>,
+        verbosity: ExplainVerbosity,
+    ) -> Result<Document> {
+        let mut options = options.into();
+        resolve_options!(self, options, [read_concern, selection_criteria, timeout]);
+
+        let aggregate = Aggregate::new(self.namespace(), pipeline, options);
+        self.client()
+            .execute_operation(Explain::new(aggregate, verbosity), None)
+            .await
+    }
+
+    /// Explains a [`Collection::count_documents`] operation with the given verbosity, returning
+    /// the raw output of the server's `explain` command.
+    pub async fn explain_count_documents(
+        &self,
+        filter: impl Into<Option<Document>>,
+        options: impl Into<Option<CountOptions>>,
+        verbosity: ExplainVerbosity,
+    ) -> Result<Document> {
+        let mut options = options.into();
+        resolve_options!(self, options, [read_concern, selection_criteria, timeout]);
+
+        let op = CountDocuments::new(self.namespace(), filter.into(), options)?;
+        self.client()
+            .execute_operation(Explain::new(op, verbosity), None)
+            .await
+    }
+
+    /// Explains a [`Collection::estimated_document_count`] operation with the given verbosity,
+    /// returning the raw output of the server's `explain` command.
+    pub async fn explain_estimated_document_count(
+        &self,
+        options: impl Into<Option<EstimatedDocumentCountOptions>>,
+        verbosity: ExplainVerbosity,
+    ) -> Result<Document> {
+        let mut options = options.into();
+        resolve_options!(self, options, [read_concern, selection_criteria, timeout]);
+
+        let op = Count::new(self.namespace(), options);
+        self.client()
+            .execute_operation(Explain::new(op, verbosity), None)
+            .await
+    }
+
+    /// Explains a [`Collection::distinct`] operation with the given verbosity, returning the raw
+    /// output of the server's `explain` command.
+    pub async fn explain_distinct(
+        &self,
+        field_name: impl AsRef<str>,
+        filter: impl Into<Option<Document>>,
+        options: impl Into<Option<DistinctOptions>>,
+        verbosity: ExplainVerbosity,
+    ) -> Result<Document> {
+        let mut options = options.into();
+        resolve_options!(self, options, [read_concern, selection_criteria, timeout]);
+
+        let op = Distinct::new(
+            self.namespace(),
+            field_name.as_ref().to_string(),
+            filter.into(),
+            options,
+        );
+        self.client()
+            .execute_operation(Explain::new(op, verbosity), None)
+            .await
+    }
+
+    async fn explain_update_common(
+        &self,
+        query: Document,
+        update: UpdateModifications,
+        multi: bool,
+        options: Option<UpdateOptions>,
+        verbosity: ExplainVerbosity,
+    ) -> Result<Document> {
+        if let UpdateModifications::Document(ref d) = update {
+            bson_util::update_document_check(d)?;
+        }
+
+        let mut options = options;
+        resolve_options!(self, options, [timeout]);
+
+        let update = Update::with_update(
+            self.namespace(),
+            query,
+            update,
+            multi,
+            options,
+            self.inner.human_readable_serialization,
+        );
+        self.client()
+            .execute_operation(Explain::new(update, verbosity), None)
+            .await
+    }
+
+    /// Explains a [`Collection::update_one`] operation with the given verbosity, returning the
+    /// raw output of the server's `explain` command. The update is not performed.
+    pub async fn explain_update_one(
+        &self,
+        query: Document,
+        update: impl Into<UpdateModifications>,
+        options: impl Into<Option<UpdateOptions>>,
+        verbosity: ExplainVerbosity,
+    ) -> Result<Document> {
+        self.explain_update_common(query, update.into(), false, options.into(), verbosity)
+            .await
+    }
+
+    /// Explains a [`Collection::update_many`] operation with the given verbosity, returning the
+    /// raw output of the server's `explain` command. The update is not performed.
+    pub async fn explain_update_many(
+        &self,
+        query: Document,
+        update: impl Into<UpdateModifications>,
+        options: impl Into<Option<UpdateOptions>>,
+        verbosity: ExplainVerbosity,
+    ) -> Result<Document> {
+        self.explain_update_common(query, update.into(), true, options.into(), verbosity)
+            .await
+    }
+
+    async fn explain_delete_common(
+        &self,
+        query: Document,
+        limit: Option<u32>,
+        options: Option<DeleteOptions>,
+        verbosity: ExplainVerbosity,
+    ) -> Result<Document> {
+        let mut options = options;
+        resolve_options!(self, options, [timeout]);
+
+        let delete = Delete::new(self.namespace(), query, limit, options);
+        self.client()
+            .execute_operation(Explain::new(delete, verbosity), None)
+            .await
+    }
+
+    /// Explains a [`Collection::delete_one`] operation with the given verbosity, returning the
+    /// raw output of the server's `explain` command. The delete is not performed.
+    pub async fn explain_delete_one(
+        &self,
+        query: Document,
+        options: impl Into<Option<DeleteOptions>>,
+        verbosity: ExplainVerbosity,
+    ) -> Result<Document> {
+        self.explain_delete_common(query, Some(1), options.into(), verbosity)
+            .await
+    }
+
+    /// Explains a [`Collection::delete_many`] operation with the given verbosity, returning the
+    /// raw output of the server's `explain` command. The delete is not performed.
+    pub async fn explain_delete_many(
+        &self,
+        query: Document,
+        options: impl Into<Option<DeleteOptions>>,
+        verbosity: ExplainVerbosity,
+    ) -> Result<Document> {
+        self.explain_delete_common(query, None, options.into(), verbosity)
+            .await
+    }
 }
 
 impl<T> Collection<T>
@@ -988,7 +1457,7 @@ where
         options: impl Into<Option<FindOneOptions>>,
     ) -> Result<Option<T>> {
         let mut options = options.into();
-        resolve_options!(self, options, [read_concern, selection_criteria]);
+        resolve_options!(self, options, [read_concern, selection_criteria, timeout]);
 
         let options: FindOptions = options.map(Into::into).unwrap_or_else(Default::default);
         let mut cursor = self.find(filter, Some(options)).await?;
@@ -1006,6 +1475,7 @@ where
         let mut options = options.into();
         resolve_read_concern_with_session!(self, options, Some(&mut *session))?;
         resolve_selection_criteria_with_session!(self, options, Some(&mut *session))?;
+        resolve_timeout_with_session!(self, options, Some(&mut *session));
 
         let options: FindOptions = options.map(Into::into).unwrap_or_else(Default::default);
         let mut cursor = self
@@ -1030,6 +1500,7 @@ where
 
         let mut options = options.into();
         resolve_write_concern_with_session!(self, options, session.as_ref())?;
+        resolve_timeout_with_session!(self, options, session.as_ref());
 
         let op = FindAndModify::with_delete(self.namespace(), filter, options);
         self.client().execute_operation(op, session).await
@@ -1079,6 +1550,7 @@ where
 
         let mut options = options.into();
         resolve_write_concern_with_session!(self, options, session.as_ref())?;
+        resolve_timeout_with_session!(self, options, session.as_ref());
 
         let op = FindAndModify::with_update(self.namespace(), filter, update, options)?;
         self.client().execute_operation(op, session).await
@@ -1138,6 +1610,7 @@ where
         let mut options = options.into();
         let session = session.into();
         resolve_write_concern_with_session!(self, options, session.as_ref())?;
+        resolve_timeout_with_session!(self, options, session.as_ref());
 
         let op = FindAndModify::with_replace(
             self.namespace(),
@@ -1199,6 +1672,7 @@ where
         let ds: Vec<_> = docs.into_iter().collect();
         let mut options = options.into();
         resolve_write_concern_with_session!(self, options, session.as_ref())?;
+        resolve_timeout_with_session!(self, options, session.as_ref());
 
         if ds.is_empty() {
             return Err(ErrorKind::InvalidArgument {
@@ -1339,6 +1813,312 @@ where
         self.insert_many_common(docs, options, Some(session)).await
     }
 
+    #[allow(clippy::needless_option_as_deref)]
+    async fn bulk_write_common(
+        &self,
+        models: impl IntoIterator<Item = impl Into<CollectionWriteModel<T>>>,
+        options: impl Into<Option<CollectionBulkWriteOptions>>,
+        mut session: Option<&mut ClientSession>,
+    ) -> Result<BulkWriteResult> {
+        let models: Vec<CollectionWriteModel<T>> = models.into_iter().map(Into::into).collect();
+        let mut options = options.into();
+        resolve_write_concern_with_session!(self, options, session.as_ref())?;
+        resolve_timeout_with_session!(self, options, session.as_ref());
+
+        if models.is_empty() {
+            return Err(ErrorKind::InvalidArgument {
+                message: "No models provided to bulk_write".to_string(),
+            }
+            .into());
+        }
+        for model in &models {
+            model.validate()?;
+        }
+
+        let ordered = options.as_ref().and_then(|o| o.ordered).unwrap_or(true);
+        #[cfg(feature = "in-use-encryption-unstable")]
+        let encrypted = self.client().auto_encryption_opts().await.is_some();
+        #[cfg(not(feature = "in-use-encryption-unstable"))]
+        let encrypted = false;
+
+        let mut cumulative_failure: Option<BulkWriteFailure> = None;
+        let mut error_labels: HashSet<String> = Default::default();
+        let verbose = options
+            .as_ref()
+            .and_then(|o| o.verbose_results)
+            .unwrap_or(false);
+        let mut cumulative_result = BulkWriteResult::new(verbose);
+
+        let mut n_attempted = 0;
+
+        while n_attempted < models.len() {
+            // consecutive models that are sent with the same command are grouped together.
+            let command_name = models[n_attempted].command_name();
+            let group_size = models[n_attempted..]
+                .iter()
+                .take_while(|model| model.command_name() == command_name)
+                .count();
+            let group = &models[n_attempted..n_attempted + group_size];
+
+            let (current_batch_size, result) = self
+                .execute_bulk_write_batch(
+                    group,
+                    options.as_ref(),
+                    encrypted,
+                    session.as_deref_mut(),
+                )
+                .await;
+
+            match result {
+                Ok(result) => cumulative_result.merge(result, n_attempted),
+                Err(e) => {
+                    let labels = e.labels().clone();
+                    match *e.kind {
+                        ErrorKind::BulkWrite(bw) => {
+                            // writes that succeeded alongside the failed writes are still
+                            // reported.
+                            if let Some(partial_result) = bw.partial_result {
+                                cumulative_result.merge(partial_result, n_attempted);
+                            }
+
+                            let failure_ref =
+                                cumulative_failure.get_or_insert_with(BulkWriteFailure::new);
+                            let has_write_errors = bw.write_errors.is_some();
+                            if let Some(write_errors) = bw.write_errors {
+                                for err in write_errors {
+                                    let index = n_attempted + err.index;
+
+                                    failure_ref
+                                        .write_errors
+                                        .get_or_insert_with(Default::default)
+                                        .push(BulkWriteError { index, ..err });
+                                }
+                            }
+
+                            if let Some(wc_error) = bw.write_concern_error {
+                                failure_ref.write_concern_error = Some(wc_error);
+                            }
+
+                            error_labels.extend(labels);
+
+                            if ordered && has_write_errors {
+                                break;
+                            }
+                        }
+                        // any other error, e.g. a network error, ends the bulk write. the writes
+                        // that were already performed are reported alongside it.
+                        _ if n_attempted == 0 => return Err(e),
+                        _ => {
+                            let mut failure =
+                                cumulative_failure.unwrap_or_else(BulkWriteFailure::new);
+                            failure.partial_result = Some(cumulative_result);
+                            error_labels.extend(labels);
+                            return Err(Error::new(
+                                ErrorKind::BulkWrite(failure),
+                                Some(error_labels),
+                            )
+                            .with_source(e));
+                        }
+                    }
+                }
+            }
+
+            n_attempted += current_batch_size;
+        }
+
+        match cumulative_failure {
+            Some(mut failure) => {
+                failure.partial_result = Some(cumulative_result);
+                Err(Error::new(
+                    ErrorKind::BulkWrite(failure),
+                    Some(error_labels),
+                ))
+            }
+            None => Ok(cumulative_result),
+        }
+    }
+
+    /// Sends as many of `models`, which must all be sent with the same command, as fit into a
+    /// single command. Returns the number of models that were sent along with the outcome.
+    async fn execute_bulk_write_batch(
+        &self,
+        models: &[CollectionWriteModel<T>],
+        options: Option<&CollectionBulkWriteOptions>,
+        encrypted: bool,
+        session: Option<&mut ClientSession>,
+    ) -> (usize, Result<BulkWriteResult>) {
+        let human_readable_serialization = self.inner.human_readable_serialization;
+        let ordered = options.and_then(|opts| opts.ordered).unwrap_or(true);
+        let verbose = options
+            .and_then(|opts| opts.verbose_results)
+            .unwrap_or(false);
+
+        match models[0] {
+            CollectionWriteModel::InsertOne(_) => {
+                let documents = models
+                    .iter()
+                    .filter_map(|model| match model {
+                        CollectionWriteModel::InsertOne(model) => Some(&model.document),
+                        _ => None,
+                    })
+                    .collect();
+                let mut insert = Insert::new_encrypted(
+                    self.namespace(),
+                    documents,
+                    options.map(InsertManyOptions::from_bulk_write_options),
+                    encrypted,
+                    human_readable_serialization,
+                );
+                let result = self
+                    .client()
+                    .execute_operation::<Insert<T>>(&mut insert, session)
+                    .await
+                    .map(|result| BulkWriteResult::from_inserted_ids(result.inserted_ids, verbose))
+                    .map_err(|mut error| {
+                        // the documents that were inserted before the failure are reported in
+                        // the same way as the updates and deletes that were performed.
+                        if let ErrorKind::BulkWrite(ref mut failure) = *error.kind {
+                            let inserted_ids = std::mem::take(&mut failure.inserted_ids);
+                            failure.partial_result =
+                                Some(BulkWriteResult::from_inserted_ids(inserted_ids, verbose));
+                        }
+                        error
+                    });
+                (insert.n_attempted(), result)
+            }
+            CollectionWriteModel::UpdateOne(_)
+            | CollectionWriteModel::UpdateMany(_)
+            | CollectionWriteModel::ReplaceOne(_) => {
+                let statements = models
+                    .iter()
+                    .filter_map(|model| match model {
+                        CollectionWriteModel::UpdateOne(model) => Some(UpdateStatement {
+                            filter: model.filter.clone(),
+                            update: UpdateOrReplace::UpdateModifications(model.update.clone()),
+                            multi: false,
+                            upsert: model.upsert,
+                            array_filters: model.array_filters.clone(),
+                            collation: model.collation.clone(),
+                            hint: model.hint.clone(),
+                        }),
+                        CollectionWriteModel::UpdateMany(model) => Some(UpdateStatement {
+                            filter: model.filter.clone(),
+                            update: UpdateOrReplace::UpdateModifications(model.update.clone()),
+                            multi: true,
+                            upsert: model.upsert,
+                            array_filters: model.array_filters.clone(),
+                            collation: model.collation.clone(),
+                            hint: model.hint.clone(),
+                        }),
+                        CollectionWriteModel::ReplaceOne(model) => Some(UpdateStatement {
+                            filter: model.filter.clone(),
+                            update: UpdateOrReplace::Replacement(&model.replacement),
+                            multi: false,
+                            upsert: model.upsert,
+                            array_filters: None,
+                            collation: model.collation.clone(),
+                            hint: model.hint.clone(),
+                        }),
+                        _ => None,
+                    })
+                    .collect();
+                let mut update = BulkUpdate::new(
+                    self.namespace(),
+                    statements,
+                    ordered,
+                    options.map(UpdateOptions::from_bulk_write_options),
+                    verbose,
+                    human_readable_serialization,
+                );
+                let result = self
+                    .client()
+                    .execute_operation::<BulkUpdate<T>>(&mut update, session)
+                    .await;
+                (update.n_attempted(), result)
+            }
+            CollectionWriteModel::DeleteOne(_) | CollectionWriteModel::DeleteMany(_) => {
+                let statements = models
+                    .iter()
+                    .filter_map(|model| match model {
+                        CollectionWriteModel::DeleteOne(model) => Some(DeleteStatement {
+                            filter: model.filter.clone(),
+                            limit: 1,
+                            collation: model.collation.clone(),
+                            hint: model.hint.clone(),
+                        }),
+                        CollectionWriteModel::DeleteMany(model) => Some(DeleteStatement {
+                            filter: model.filter.clone(),
+                            limit: 0, // 0 = no limit
+                            collation: model.collation.clone(),
+                            hint: model.hint.clone(),
+                        }),
+                        _ => None,
+                    })
+                    .collect();
+                let mut delete = BulkDelete::new(
+                    self.namespace(),
+                    statements,
+                    ordered,
+                    options.map(DeleteOptions::from_bulk_write_options),
+                    verbose,
+                );
+                let result = self
+                    .client()
+                    .execute_operation::<BulkDelete>(&mut delete, session)
+                    .await;
+                (delete.n_attempted(), result)
+            }
+        }
+    }
+
+    /// Performs the writes described by `models` against the collection. Consecutive writes of
+    /// the same kind are sent to the server together in a single command, and the results of all
+    /// of the commands are combined into a single [`BulkWriteResult`].
+    ///
+    /// If any of the writes fail, a [`ErrorKind::BulkWrite`] error is returned whose write errors
+    /// are indexed by the position of the failed write in `models`. If the writes are ordered,
+    /// no further writes are attempted after the first failure. The results of the writes that
+    /// did succeed are reported in [`BulkWriteFailure::partial_result`]. Any other error that
+    /// occurs after some of the writes were sent is returned the same way, with the original
+    /// error as its source.
+    ///
+    /// This operation will retry once upon failure if the connection and encountered error support
+    /// retryability and none of the writes in the failed command can affect multiple documents.
+    /// See the documentation [here](https://www.mongodb.com/docs/manual/core/retryable-writes/)
+    /// for more information on retryable writes.
+    pub async fn bulk_write(
+        &self,
+        models: impl IntoIterator<Item = impl Into<CollectionWriteModel<T>>>,
+        options: impl Into<Option<CollectionBulkWriteOptions>>,
+    ) -> Result<BulkWriteResult> {
+        self.bulk_write_common(models, options, None).await
+    }
+
+    /// Performs the writes described by `models` against the collection using the provided
+    /// `ClientSession`. Consecutive writes of the same kind are sent to the server together in a
+    /// single command, and the results of all of the commands are combined into a single
+    /// [`BulkWriteResult`].
+    ///
+    /// If any of the writes fail, a [`ErrorKind::BulkWrite`] error is returned whose write errors
+    /// are indexed by the position of the failed write in `models`. If the writes are ordered,
+    /// no further writes are attempted after the first failure. The results of the writes that
+    /// did succeed are reported in [`BulkWriteFailure::partial_result`]. Any other error that
+    /// occurs after some of the writes were sent is returned the same way, with the original
+    /// error as its source.
+    ///
+    /// This operation will retry once upon failure if the connection and encountered error support
+    /// retryability and none of the writes in the failed command can affect multiple documents.
+    /// See the documentation [here](https://www.mongodb.com/docs/manual/core/retryable-writes/)
+    /// for more information on retryable writes.
+    pub async fn bulk_write_with_session(
+        &self,
+        models: impl IntoIterator<Item = impl Into<CollectionWriteModel<T>>>,
+        options: impl Into<Option<CollectionBulkWriteOptions>>,
+        session: &mut ClientSession,
+    ) -> Result<BulkWriteResult> {
+        self.bulk_write_common(models, options, Some(session)).await
+    }
+
     async fn insert_one_common(
         &self,
         doc: &T,
@@ -1349,6 +2129,7 @@ where
 
         let mut options = options.into();
         resolve_write_concern_with_session!(self, options, session.as_ref())?;
+        resolve_timeout_with_session!(self, options, session.as_ref());
 
         let insert = Insert::new(
             self.namespace(),
@@ -1410,6 +2191,7 @@ where
         let session = session.into();
 
         resolve_write_concern_with_session!(self, options, session.as_ref())?;
+        resolve_timeout_with_session!(self, options, session.as_ref());
 
         let update = Update::with_replace(
             self.namespace(),
@@ -1458,7 +2240,7 @@ where
 }
 
 /// A struct modeling the canonical name for a collection in MongoDB.
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct Namespace {
     /// The name of the database associated with this namespace.
     pub db: String,
@@ -6,9 +6,10 @@ use typed_builder::TypedBuilder;
 
 use crate::{
     bson::{doc, serde_helpers, Bson, Document, RawBson, RawDocumentBuf},
+    bson_util,
     concern::{ReadConcern, WriteConcern},
     error::Result,
-    options::Collation,
+    options::{ChangeStreamPreAndPostImages, Collation, ValidationAction, ValidationLevel},
     selection_criteria::SelectionCriteria,
     serde_util,
 };
@@ -37,6 +38,16 @@ mod suppress_warning {
         /// The default write concern for operations.
         pub write_concern: Option<WriteConcern>,
 
+        /// The default timeout for operations. Operations that exceed this amount of time,
+        /// including server selection, connection checkout and any retries, will fail with an
+        /// [`ErrorKind::Timeout`](crate::error::ErrorKind::Timeout) error.
+        #[serde(
+            rename = "timeoutMS",
+            deserialize_with = "serde_util::deserialize_duration_option_from_u64_millis",
+            default
+        )]
+        pub timeout: Option<Duration>,
+
         /// Sets the [`bson::SerializerOptions::human_readable`] option for the [`Bson`]
         /// serializer. The default value is `false`.
         /// Note: Specifying `true` for this value will decrease the performance of insert
@@ -112,6 +123,25 @@ pub enum CursorType {
     TailableAwait,
 }
 
+/// The level of detail to return when explaining an operation.
+///
+/// See the [documentation](https://www.mongodb.com/docs/manual/reference/command/explain/#verbosity-modes)
+/// for more information on each verbosity mode.
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub enum ExplainVerbosity {
+    /// Return the plan selected by the query optimizer without executing it.
+    QueryPlanner,
+
+    /// Execute the winning plan and return statistics describing its execution.
+    ExecutionStats,
+
+    /// Execute the winning plan and return statistics for both it and the other candidate plans
+    /// considered during plan selection.
+    AllPlansExecution,
+}
+
 /// Specifies the options to a
 /// [`Collection::insert_one`](../struct.Collection.html#method.insert_one) operation.
 #[skip_serializing_none]
@@ -131,6 +161,15 @@ pub struct InsertOneOptions {
     ///
     /// This option is only available on server versions 4.4+.
     pub comment: Option<Bson>,
+
+    /// The maximum amount of time to allow the operation to run, including any retries.
+    #[serde(
+        rename = "timeoutMS",
+        skip_serializing,
+        deserialize_with = "serde_util::deserialize_duration_option_from_u64_millis",
+        default
+    )]
+    pub timeout: Option<Duration>,
 }
 
 /// Specifies the options to a
@@ -159,6 +198,15 @@ pub struct InsertManyOptions {
     ///
     /// This option is only available on server versions 4.4+.
     pub comment: Option<Bson>,
+
+    /// The maximum amount of time to allow the operation to run, including any retries.
+    #[serde(
+        rename = "timeoutMS",
+        skip_serializing,
+        deserialize_with = "serde_util::deserialize_duration_option_from_u64_millis",
+        default
+    )]
+    pub timeout: Option<Duration>,
 }
 
 impl InsertManyOptions {
@@ -168,6 +216,17 @@ impl InsertManyOptions {
             ordered: None,
             write_concern: options.write_concern,
             comment: options.comment,
+            timeout: options.timeout,
+        }
+    }
+
+    pub(crate) fn from_bulk_write_options(options: &CollectionBulkWriteOptions) -> Self {
+        Self {
+            bypass_document_validation: options.bypass_document_validation,
+            ordered: options.ordered,
+            write_concern: options.write_concern.clone(),
+            comment: options.comment.clone(),
+            timeout: options.timeout,
         }
     }
 }
@@ -248,6 +307,15 @@ pub struct UpdateOptions {
     ///
     /// This option is only available on server versions 4.4+.
     pub comment: Option<Bson>,
+
+    /// The maximum amount of time to allow the operation to run, including any retries.
+    #[serde(
+        rename = "timeoutMS",
+        skip_serializing,
+        deserialize_with = "serde_util::deserialize_duration_option_from_u64_millis",
+        default
+    )]
+    pub timeout: Option<Duration>,
 }
 
 impl UpdateOptions {
@@ -260,6 +328,18 @@ impl UpdateOptions {
             collation: options.collation,
             let_vars: options.let_vars,
             comment: options.comment,
+            timeout: options.timeout,
+            ..Default::default()
+        }
+    }
+
+    pub(crate) fn from_bulk_write_options(options: &CollectionBulkWriteOptions) -> Self {
+        Self {
+            bypass_document_validation: options.bypass_document_validation,
+            write_concern: options.write_concern.clone(),
+            let_vars: options.let_vars.clone(),
+            comment: options.comment.clone(),
+            timeout: options.timeout,
             ..Default::default()
         }
     }
@@ -307,6 +387,15 @@ pub struct ReplaceOptions {
     ///
     /// This option is only available on server versions 4.4+.
     pub comment: Option<Bson>,
+
+    /// The maximum amount of time to allow the operation to run, including any retries.
+    #[serde(
+        rename = "timeoutMS",
+        skip_serializing,
+        deserialize_with = "serde_util::deserialize_duration_option_from_u64_millis",
+        default
+    )]
+    pub timeout: Option<Duration>,
 }
 
 /// Specifies the options to a
@@ -344,6 +433,27 @@ pub struct DeleteOptions {
     ///
     /// This option is only available on server versions 4.4+.
     pub comment: Option<Bson>,
+
+    /// The maximum amount of time to allow the operation to run, including any retries.
+    #[serde(
+        rename = "timeoutMS",
+        skip_serializing,
+        deserialize_with = "serde_util::deserialize_duration_option_from_u64_millis",
+        default
+    )]
+    pub timeout: Option<Duration>,
+}
+
+impl DeleteOptions {
+    pub(crate) fn from_bulk_write_options(options: &CollectionBulkWriteOptions) -> Self {
+        Self {
+            write_concern: options.write_concern.clone(),
+            let_vars: options.let_vars.clone(),
+            comment: options.comment.clone(),
+            timeout: options.timeout,
+            ..Default::default()
+        }
+    }
 }
 
 /// Specifies the options to a
@@ -393,6 +503,15 @@ pub struct FindOneAndDeleteOptions {
     ///
     /// This option is only available on server versions 4.4+.
     pub comment: Option<Bson>,
+
+    /// The maximum amount of time to allow the operation to run, including any retries.
+    #[serde(
+        rename = "timeoutMS",
+        skip_serializing,
+        deserialize_with = "serde_util::deserialize_duration_option_from_u64_millis",
+        default
+    )]
+    pub timeout: Option<Duration>,
 }
 
 /// Specifies the options to a
@@ -451,6 +570,15 @@ pub struct FindOneAndReplaceOptions {
     ///
     /// This option is only available on server versions 4.4+.
     pub comment: Option<Bson>,
+
+    /// The maximum amount of time to allow the operation to run, including any retries.
+    #[serde(
+        rename = "timeoutMS",
+        skip_serializing,
+        deserialize_with = "serde_util::deserialize_duration_option_from_u64_millis",
+        default
+    )]
+    pub timeout: Option<Duration>,
 }
 
 /// Specifies the options to a
@@ -515,6 +643,15 @@ pub struct FindOneAndUpdateOptions {
     ///
     /// This option is only available on server versions 4.4+.
     pub comment: Option<Bson>,
+
+    /// The maximum amount of time to allow the operation to run, including any retries.
+    #[serde(
+        rename = "timeoutMS",
+        skip_serializing,
+        deserialize_with = "serde_util::deserialize_duration_option_from_u64_millis",
+        default
+    )]
+    pub timeout: Option<Duration>,
 }
 
 /// Specifies the options to a [`Collection::aggregate`](../struct.Collection.html#method.aggregate)
@@ -622,6 +759,15 @@ pub struct AggregateOptions {
     /// This feature is only available on server versions 5.0 and above.
     #[serde(rename = "let")]
     pub let_vars: Option<Document>,
+
+    /// The maximum amount of time to allow the operation to run, including any retries.
+    #[serde(
+        rename = "timeoutMS",
+        skip_serializing,
+        deserialize_with = "serde_util::deserialize_duration_option_from_u64_millis",
+        default
+    )]
+    pub timeout: Option<Duration>,
 }
 
 /// Specifies the options to a
@@ -671,6 +817,15 @@ pub struct CountOptions {
     ///
     /// This option is only available on server versions 4.4+.
     pub comment: Option<Bson>,
+
+    /// The maximum amount of time to allow the operation to run, including any retries.
+    #[serde(
+        rename = "timeoutMS",
+        skip_serializing,
+        deserialize_with = "serde_util::deserialize_duration_option_from_u64_millis",
+        default
+    )]
+    pub timeout: Option<Duration>,
 }
 
 // rustfmt tries to split the link up when it's all on one line, which breaks the link, so we wrap
@@ -715,6 +870,15 @@ pub struct EstimatedDocumentCountOptions {
     /// value on server versions 4.4.14+. On server versions between 4.4.0 and 4.4.14, only
     /// [`Bson::String`] values are supported.
     pub comment: Option<Bson>,
+
+    /// The maximum amount of time to allow the operation to run, including any retries.
+    #[serde(
+        rename = "timeoutMS",
+        skip_serializing,
+        deserialize_with = "serde_util::deserialize_duration_option_from_u64_millis",
+        default
+    )]
+    pub timeout: Option<Duration>,
 }
 
 /// Specifies the options to a [`Collection::distinct`](../struct.Collection.html#method.distinct)
@@ -758,6 +922,15 @@ pub struct DistinctOptions {
     ///
     /// This option is only available on server versions 4.4+.
     pub comment: Option<Bson>,
+
+    /// The maximum amount of time to allow the operation to run, including any retries.
+    #[serde(
+        rename = "timeoutMS",
+        skip_serializing,
+        deserialize_with = "serde_util::deserialize_duration_option_from_u64_millis",
+        default
+    )]
+    pub timeout: Option<Duration>,
 }
 
 /// Specifies the options to a [`Collection::find`](../struct.Collection.html#method.find)
@@ -888,6 +1061,15 @@ pub struct FindOptions {
     /// Only available in MongoDB 5.0+.
     #[serde(rename = "let")]
     pub let_vars: Option<Document>,
+
+    /// The maximum amount of time to allow the operation to run, including any retries.
+    #[serde(
+        rename = "timeoutMS",
+        skip_serializing,
+        deserialize_with = "serde_util::deserialize_duration_option_from_u64_millis",
+        default
+    )]
+    pub timeout: Option<Duration>,
 }
 
 impl From<FindOneOptions> for FindOptions {
@@ -916,6 +1098,7 @@ impl From<FindOneOptions> for FindOptions {
             no_cursor_timeout: None,
             sort: options.sort,
             let_vars: options.let_vars,
+            timeout: options.timeout,
         }
     }
 }
@@ -1026,6 +1209,15 @@ pub struct FindOneOptions {
     /// Only available in MongoDB 5.0+.
     #[serde(rename = "let")]
     pub let_vars: Option<Document>,
+
+    /// The maximum amount of time to allow the operation to run, including any retries.
+    #[serde(
+        rename = "timeoutMS",
+        skip_serializing,
+        deserialize_with = "serde_util::deserialize_duration_option_from_u64_millis",
+        default
+    )]
+    pub timeout: Option<Duration>,
 }
 
 /// Specifies the options to a
@@ -1083,6 +1275,136 @@ pub struct DropCollectionOptions {
     pub encrypted_fields: Option<Document>,
 }
 
+/// Specifies the options to a [`Collection::modify`](crate::Collection::modify) operation, which
+/// runs the [`collMod`](https://www.mongodb.com/docs/manual/reference/command/collMod/) command.
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, TypedBuilder, Serialize)]
+#[serde(rename_all = "camelCase")]
+#[builder(field_defaults(default, setter(into)))]
+#[non_exhaustive]
+pub struct CollModOptions {
+    /// A new validator to restrict the schema of documents which can exist in the collection.
+    pub validator: Option<Document>,
+
+    /// Specifies how strictly the database should apply the validation rules to existing documents
+    /// during an update.
+    pub validation_level: Option<ValidationLevel>,
+
+    /// Specifies whether the database should return an error or simply raise a warning if inserted
+    /// documents do not pass the validation.
+    pub validation_action: Option<ValidationAction>,
+
+    /// Changes the index to modify, e.g. to hide it from the query planner or to change its TTL.
+    pub index: Option<CollModIndex>,
+
+    /// Changes the amount of time after which documents in a time series or clustered collection
+    /// are automatically deleted.
+    #[serde(default, with = "serde_util::duration_option_as_int_seconds")]
+    pub expire_after_seconds: Option<Duration>,
+
+    /// Options for supporting change stream pre- and post-images.
+    pub change_stream_pre_and_post_images: Option<ChangeStreamPreAndPostImages>,
+
+    /// The name of the new source collection or view of a view. This option may only be used when
+    /// modifying a view.
+    pub view_on: Option<String>,
+
+    /// The new aggregation pipeline defining a view. This option may only be used when modifying a
+    /// view.
+    pub pipeline: Option<Vec<Document>>,
+
+    /// The write concern for the operation.
+    pub write_concern: Option<WriteConcern>,
+
+    /// Tags the query with an arbitrary [`Bson`] value to help trace the operation through the
+    /// database profiler, currentOp and logs.
+    pub comment: Option<Bson>,
+}
+
+/// Specifies an index to modify as part of a [`Collection::modify`](crate::Collection::modify)
+/// operation. Exactly one of `key_pattern` and `name` must be set.
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, TypedBuilder, Serialize)]
+#[serde(rename_all = "camelCase")]
+#[builder(field_defaults(default, setter(into)))]
+#[non_exhaustive]
+pub struct CollModIndex {
+    /// The key pattern of the index to modify.
+    pub key_pattern: Option<Document>,
+
+    /// The name of the index to modify.
+    pub name: Option<String>,
+
+    /// Whether the index should be hidden from the query planner.
+    pub hidden: Option<bool>,
+
+    /// The new amount of time after which documents are deleted from the collection by this TTL
+    /// index.
+    #[serde(default, with = "serde_util::duration_option_as_int_seconds")]
+    pub expire_after_seconds: Option<Duration>,
+}
+
+/// Specifies the options to a
+/// [`Collection::storage_stats`](../struct.Collection.html#method.storage_stats) operation.
+#[derive(Clone, Debug, Default, Deserialize, TypedBuilder)]
+#[serde(rename_all = "camelCase")]
+#[builder(field_defaults(default, setter(into)))]
+#[non_exhaustive]
+pub struct StorageStatsOptions {
+    /// The factor by which the server should divide the sizes it reports, e.g. `1024` to report
+    /// kibibytes instead of bytes.
+    pub scale: Option<u32>,
+
+    /// The criteria used to select a server for this operation.
+    ///
+    /// If none is specified, the selection criteria defined on the collection will be used.
+    #[serde(rename = "readPreference")]
+    pub selection_criteria: Option<SelectionCriteria>,
+
+    /// Tags the query with an arbitrary [`Bson`] value to help trace the operation through the
+    /// database profiler, currentOp and logs.
+    pub comment: Option<Bson>,
+}
+
+impl StorageStatsOptions {
+    pub(crate) fn into_aggregate_options(self) -> AggregateOptions {
+        AggregateOptions::builder()
+            .selection_criteria(self.selection_criteria)
+            .comment_bson(self.comment)
+            .build()
+    }
+}
+
+/// Specifies the options to a
+/// [`Collection::latency_stats`](../struct.Collection.html#method.latency_stats) operation.
+#[derive(Clone, Debug, Default, Deserialize, TypedBuilder)]
+#[serde(rename_all = "camelCase")]
+#[builder(field_defaults(default, setter(into)))]
+#[non_exhaustive]
+pub struct LatencyStatsOptions {
+    /// Whether to include a histogram of the latencies of each type of operation.
+    pub histograms: Option<bool>,
+
+    /// The criteria used to select a server for this operation.
+    ///
+    /// If none is specified, the selection criteria defined on the collection will be used.
+    #[serde(rename = "readPreference")]
+    pub selection_criteria: Option<SelectionCriteria>,
+
+    /// Tags the query with an arbitrary [`Bson`] value to help trace the operation through the
+    /// database profiler, currentOp and logs.
+    pub comment: Option<Bson>,
+}
+
+impl LatencyStatsOptions {
+    pub(crate) fn into_aggregate_options(self) -> AggregateOptions {
+        AggregateOptions::builder()
+            .selection_criteria(self.selection_criteria)
+            .comment_bson(self.comment)
+            .build()
+    }
+}
+
 /// Specifies the options to a
 /// [`Collection::drop_index`](../struct.Collection.html#method.drop_index) or
 /// [`Collection::drop_indexes`](../struct.Collection.html#method.drop_indexes) operation.
@@ -1143,6 +1465,15 @@ pub struct ListIndexesOptions {
     ///
     /// This option is only available on server versions 4.4+.
     pub comment: Option<Bson>,
+
+    /// The maximum amount of time to allow the operation to run, including any retries.
+    #[serde(
+        rename = "timeoutMS",
+        skip_serializing,
+        deserialize_with = "serde_util::deserialize_duration_option_from_u64_millis",
+        default
+    )]
+    pub timeout: Option<Duration>,
 }
 
 /// The minimum number of data-bearing voting replica set members (i.e. commit quorum), including
@@ -1205,3 +1536,266 @@ impl<'de> Deserialize<'de> for CommitQuorum {
         }
     }
 }
+
+/// Specifies the options to a [`Collection::bulk_write`](crate::Collection::bulk_write)
+/// operation.
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, TypedBuilder)]
+#[serde(rename_all = "camelCase")]
+#[builder(field_defaults(default, setter(into)))]
+#[non_exhaustive]
+pub struct CollectionBulkWriteOptions {
+    /// If true, when a write fails, return without performing the remaining writes. If false,
+    /// when a write fails, continue with the remaining writes, if any.
+    ///
+    /// Defaults to true.
+    pub ordered: Option<bool>,
+
+    /// Opt out of document-level validation.
+    pub bypass_document_validation: Option<bool>,
+
+    /// The write concern for the operation.
+    pub write_concern: Option<WriteConcern>,
+
+    /// Map of parameter names and values. Values must be constant or closed
+    /// expressions that do not reference document fields. Parameters can then be
+    /// accessed as variables in an aggregate expression context (e.g. "$$var").
+    ///
+    /// Only available in MongoDB 5.0+.
+    #[serde(rename = "let")]
+    pub let_vars: Option<Document>,
+
+    /// Tags the query with an arbitrary [`Bson`] value to help trace the operation through the
+    /// database profiler, currentOp and logs.
+    ///
+    /// This option is only available on server versions 4.4+.
+    pub comment: Option<Bson>,
+
+    /// Whether the result of each successful write should be included in the returned
+    /// [`BulkWriteResult`](crate::results::BulkWriteResult). When this is set, updates and deletes
+    /// are sent to the server one at a time so that the result of each of them is known.
+    ///
+    /// Defaults to false.
+    pub verbose_results: Option<bool>,
+
+    /// The maximum amount of time to allow the operation to run, including any retries.
+    #[serde(skip)]
+    pub timeout: Option<Duration>,
+}
+
+/// A single write to be performed as part of a
+/// [`Collection::bulk_write`](crate::Collection::bulk_write) operation.
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub enum CollectionWriteModel<T> {
+    /// Inserts a single document.
+    InsertOne(InsertOne<T>),
+
+    /// Updates a single document.
+    UpdateOne(UpdateOne),
+
+    /// Updates multiple documents.
+    UpdateMany(UpdateMany),
+
+    /// Replaces a single document.
+    ReplaceOne(ReplaceOne<T>),
+
+    /// Deletes a single document.
+    DeleteOne(DeleteOne),
+
+    /// Deletes multiple documents.
+    DeleteMany(DeleteMany),
+}
+
+impl<T> CollectionWriteModel<T> {
+    /// The name of the write command this model is sent to the server with.
+    pub(crate) fn command_name(&self) -> &'static str {
+        match self {
+            Self::InsertOne(_) => "insert",
+            Self::UpdateOne(_) | Self::UpdateMany(_) | Self::ReplaceOne(_) => "update",
+            Self::DeleteOne(_) | Self::DeleteMany(_) => "delete",
+        }
+    }
+
+    /// Checks that the update document of this write is well-formed. Replacement documents are
+    /// checked when they are serialized.
+    pub(crate) fn validate(&self) -> Result<()> {
+        match self {
+            Self::UpdateOne(UpdateOne {
+                update: UpdateModifications::Document(update),
+                ..
+            })
+            | Self::UpdateMany(UpdateMany {
+                update: UpdateModifications::Document(update),
+                ..
+            }) => bson_util::update_document_check(update),
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Inserts a single document.
+#[derive(Clone, Debug, TypedBuilder)]
+#[builder(field_defaults(setter(into)))]
+#[non_exhaustive]
+pub struct InsertOne<T> {
+    /// The document to insert. An `_id` will be generated for the document if it does not
+    /// already have one.
+    pub document: T,
+}
+
+impl<T> From<InsertOne<T>> for CollectionWriteModel<T> {
+    fn from(model: InsertOne<T>) -> Self {
+        Self::InsertOne(model)
+    }
+}
+
+/// Updates a single document.
+#[derive(Clone, Debug, TypedBuilder)]
+#[builder(field_defaults(setter(into)))]
+#[non_exhaustive]
+pub struct UpdateOne {
+    /// The filter to use. The first document matching this filter will be updated.
+    pub filter: Document,
+
+    /// The update to perform.
+    pub update: UpdateModifications,
+
+    /// A set of filters specifying to which array elements an update should apply.
+    #[builder(default)]
+    pub array_filters: Option<Vec<Document>>,
+
+    /// The collation to use.
+    #[builder(default)]
+    pub collation: Option<Collation>,
+
+    /// The index to use.
+    #[builder(default)]
+    pub hint: Option<Hint>,
+
+    /// Whether a new document should be created if no document matches the filter.
+    ///
+    /// Defaults to false.
+    #[builder(default)]
+    pub upsert: Option<bool>,
+}
+
+impl<T> From<UpdateOne> for CollectionWriteModel<T> {
+    fn from(model: UpdateOne) -> Self {
+        Self::UpdateOne(model)
+    }
+}
+
+/// Updates multiple documents.
+#[derive(Clone, Debug, TypedBuilder)]
+#[builder(field_defaults(setter(into)))]
+#[non_exhaustive]
+pub struct UpdateMany {
+    /// The filter to use. All documents matching this filter will be updated.
+    pub filter: Document,
+
+    /// The update to perform.
+    pub update: UpdateModifications,
+
+    /// A set of filters specifying to which array elements an update should apply.
+    #[builder(default)]
+    pub array_filters: Option<Vec<Document>>,
+
+    /// The collation to use.
+    #[builder(default)]
+    pub collation: Option<Collation>,
+
+    /// The index to use.
+    #[builder(default)]
+    pub hint: Option<Hint>,
+
+    /// Whether a new document should be created if no document matches the filter.
+    ///
+    /// Defaults to false.
+    #[builder(default)]
+    pub upsert: Option<bool>,
+}
+
+impl<T> From<UpdateMany> for CollectionWriteModel<T> {
+    fn from(model: UpdateMany) -> Self {
+        Self::UpdateMany(model)
+    }
+}
+
+/// Replaces a single document.
+#[derive(Clone, Debug, TypedBuilder)]
+#[builder(field_defaults(setter(into)))]
+#[non_exhaustive]
+pub struct ReplaceOne<T> {
+    /// The filter to use. The first document matching this filter will be replaced.
+    pub filter: Document,
+
+    /// The replacement document.
+    pub replacement: T,
+
+    /// The collation to use.
+    #[builder(default)]
+    pub collation: Option<Collation>,
+
+    /// The index to use.
+    #[builder(default)]
+    pub hint: Option<Hint>,
+
+    /// Whether a new document should be created if no document matches the filter.
+    ///
+    /// Defaults to false.
+    #[builder(default)]
+    pub upsert: Option<bool>,
+}
+
+impl<T> From<ReplaceOne<T>> for CollectionWriteModel<T> {
+    fn from(model: ReplaceOne<T>) -> Self {
+        Self::ReplaceOne(model)
+    }
+}
+
+/// Deletes a single document.
+#[derive(Clone, Debug, TypedBuilder)]
+#[builder(field_defaults(setter(into)))]
+#[non_exhaustive]
+pub struct DeleteOne {
+    /// The filter to use. The first document matching this filter will be deleted.
+    pub filter: Document,
+
+    /// The collation to use.
+    #[builder(default)]
+    pub collation: Option<Collation>,
+
+    /// The index to use.
+    #[builder(default)]
+    pub hint: Option<Hint>,
+}
+
+impl<T> From<DeleteOne> for CollectionWriteModel<T> {
+    fn from(model: DeleteOne) -> Self {
+        Self::DeleteOne(model)
+    }
+}
+
+/// Deletes multiple documents.
+#[derive(Clone, Debug, TypedBuilder)]
+#[builder(field_defaults(setter(into)))]
+#[non_exhaustive]
+pub struct DeleteMany {
+    /// The filter to use. All documents matching this filter will be deleted.
+    pub filter: Document,
+
+    /// The collation to use.
+    #[builder(default)]
+    pub collation: Option<Collation>,
+
+    /// The index to use.
+    #[builder(default)]
+    pub hint: Option<Hint>,
+}
+
+impl<T> From<DeleteMany> for CollectionWriteModel<T> {
+    fn from(model: DeleteMany) -> Self {
+        Self::DeleteMany(model)
+    }
+}
@@ -27,28 +27,38 @@ use crate::{
 pub struct ReadConcern {
     /// The level of the read concern.
     pub level: ReadConcernLevel,
+
+    /// The point in time that a read with level `ReadConcernLevel::Snapshot` should read from.
+    ///
+    /// This can be set with [`ReadConcern::snapshot_at`] and requires MongoDB 5.0+.
+    pub at_cluster_time: Option<Timestamp>,
 }
 
 impl ReadConcern {
     /// A `ReadConcern` with level `ReadConcernLevel::Local`.
     pub const LOCAL: ReadConcern = ReadConcern {
         level: ReadConcernLevel::Local,
+        at_cluster_time: None,
     };
     /// A `ReadConcern` with level `ReadConcernLevel::Majority`.
     pub const MAJORITY: ReadConcern = ReadConcern {
         level: ReadConcernLevel::Majority,
+        at_cluster_time: None,
     };
     /// A `ReadConcern` with level `ReadConcernLevel::Linearizable`.
     pub const LINEARIZABLE: ReadConcern = ReadConcern {
         level: ReadConcernLevel::Linearizable,
+        at_cluster_time: None,
     };
     /// A `ReadConcern` with level `ReadConcernLevel::Available`.
     pub const AVAILABLE: ReadConcern = ReadConcern {
         level: ReadConcernLevel::Available,
+        at_cluster_time: None,
     };
     /// A `ReadConcern` with level `ReadConcernLevel::Snapshot`.
     pub const SNAPSHOT: ReadConcern = ReadConcern {
         level: ReadConcernLevel::Snapshot,
+        at_cluster_time: None,
     };
 }
 
@@ -101,6 +111,17 @@ impl ReadConcern {
         ReadConcernLevel::Snapshot.into()
     }
 
+    /// Creates a read concern with level "snapshot" that reads from the given point in time
+    /// rather than the most recent majority-committed data. This can be used to make reads outside
+    /// of a session observe the same snapshot as a session started with
+    /// [`SessionOptions::snapshot_time`](crate::options::SessionOptions::snapshot_time).
+    pub fn snapshot_at(at_cluster_time: Timestamp) -> Self {
+        Self {
+            level: ReadConcernLevel::Snapshot,
+            at_cluster_time: Some(at_cluster_time),
+        }
+    }
+
     /// Creates a read concern with a custom read concern level. This is present to provide forwards
     /// compatibility with any future read concerns which may be added to new versions of
     /// MongoDB.
@@ -132,7 +153,7 @@ impl From<ReadConcern> for ReadConcernInternal {
     fn from(rc: ReadConcern) -> Self {
         ReadConcernInternal {
             level: Some(rc.level),
-            at_cluster_time: None,
+            at_cluster_time: rc.at_cluster_time,
             after_cluster_time: None,
         }
     }
@@ -140,7 +161,10 @@ impl From<ReadConcern> for ReadConcernInternal {
 
 impl From<ReadConcernLevel> for ReadConcern {
     fn from(level: ReadConcernLevel) -> Self {
-        Self { level }
+        Self {
+            level,
+            at_cluster_time: None,
+        }
     }
 }
 
@@ -1,4 +1,5 @@
 mod common;
+pub(crate) mod resumable;
 pub(crate) mod session;
 
 #[cfg(test)]
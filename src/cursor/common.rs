@@ -2,7 +2,7 @@ use std::{
     collections::VecDeque,
     pin::Pin,
     task::{Context, Poll},
-    time::Duration,
+    time::{Duration, Instant},
 };
 
 use bson::{RawDocument, RawDocumentBuf};
@@ -380,6 +380,7 @@ impl CursorSpecification {
                 batch_size: batch_size.into(),
                 max_time: max_time.into(),
                 comment: comment.into(),
+                deadline: None,
             },
             initial_buffer: info.first_batch,
             post_batch_resume_token: ResumeToken::from_raw(info.post_batch_resume_token),
@@ -415,6 +416,9 @@ pub(crate) struct CursorInformation {
     pub(crate) batch_size: Option<u32>,
     pub(crate) max_time: Option<Duration>,
     pub(crate) comment: Option<Bson>,
+    /// The deadline of the operation that created this cursor, if any. Each `getMore` issued for
+    /// the cursor only gets the time remaining until this point.
+    pub(crate) deadline: Option<Instant>,
 }
 
 #[derive(Debug)]
@@ -0,0 +1,411 @@
+use std::{
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use bson::{doc, Bson, Document, RawBsonRef, RawDocument};
+use derivative::Derivative;
+use futures_core::{future::BoxFuture, Stream};
+use serde::de::DeserializeOwned;
+
+use crate::{
+    bson_util,
+    cursor::{stream_poll_next, BatchValue, CursorStream, NextInBatchFuture},
+    error::{Error, Result},
+    options::{CursorType, FindOptions},
+    Collection,
+    Cursor,
+};
+
+/// A [`ResumableCursor`] streams the results of a `find` like a regular [`Cursor`], but can
+/// transparently recover from failures that would otherwise end the query. Instances are created
+/// with [`Collection::find_resumable`].
+///
+/// The cursor records the sort key of the most recently returned document. If a `getMore`
+/// encounters a resumable error, such as a network error, a primary stepping down, or the cursor
+/// being killed on the server (`CursorNotFound`), the `find` is re-issued with its filter bound to
+/// only match documents that sort after that key, and iteration continues from the new cursor.
+/// Server selection for the new `find` follows the collection's selection criteria, so the query
+/// will move to another eligible server if the original one is no longer available. As with
+/// [`ChangeStream`](crate::change_stream::ChangeStream)s, a single resume attempt is made per
+/// error.
+///
+/// The sort key is taken from the [`sort`](FindOptions::sort) option, which defaults to
+/// `{ "_id": 1 }`. If the sort does not include `_id`, it is appended as a final ascending
+/// component so that the key uniquely identifies a position in the results. Every field in the
+/// sort key must have a non-array value in each returned document; if a
+/// [`projection`](FindOptions::projection) is applied, the fields must also be present in the
+/// projected documents. If the last returned document does not have a usable key, the original
+/// error is returned instead of resuming. The values may be null, missing or of differing BSON
+/// types: the resumed `find` compares them in an `$expr`, which follows the same cross-type
+/// ordering as the sort. Because of this, the bound on the resumed `find` cannot use an index.
+///
+/// Note that documents inserted or modified during iteration may or may not be returned after a
+/// resume, depending on where their sort key falls relative to the last returned document.
+///
+/// ```rust
+/// # use mongodb::{bson::{doc, Document}, Client, error::Result};
+/// #
+/// # async fn do_stuff() -> Result<()> {
+/// # let client = Client::with_uri_str("mongodb://example.com").await?;
+/// # let coll = client.database("foo").collection::<Document>("bar");
+/// #
+/// use futures::stream::TryStreamExt;
+///
+/// let mut cursor = coll.find_resumable(doc! { "archived": false }, None).await?;
+/// while let Some(doc) = cursor.try_next().await? {
+///     println!("{}", doc);
+/// }
+/// #
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Derivative)]
+#[derivative(Debug)]
+pub struct ResumableCursor<T>
+where
+    T: DeserializeOwned,
+{
+    /// The cursor for the most recently issued `find`.
+    cursor: Cursor<T>,
+
+    /// Arguments to `find_resumable` that created this cursor.
+    args: FindArgs,
+
+    /// Dynamic information associated with this cursor.
+    data: ResumeData,
+
+    /// A pending future for a resume.
+    #[derivative(Debug = "ignore")]
+    pending_resume: Option<BoxFuture<'static, Result<Cursor<T>>>>,
+}
+
+impl<T> ResumableCursor<T>
+where
+    T: DeserializeOwned,
+{
+    pub(crate) fn new(cursor: Cursor<T>, args: FindArgs) -> Self {
+        Self {
+            cursor,
+            args,
+            data: ResumeData::default(),
+            pending_resume: None,
+        }
+    }
+
+    /// Returns the sort key of the most recently returned document, keyed by sort field path.
+    ///
+    /// This can be persisted and used to build a filter that continues the query from the same
+    /// position after the cursor itself has been dropped.
+    pub fn resume_key(&self) -> Option<Document> {
+        self.data.last_key.as_ref().map(|values| {
+            self.args
+                .sort_key
+                .iter()
+                .zip(values)
+                .map(|(field, value)| (field.path.clone(), value.clone()))
+                .collect()
+        })
+    }
+
+    /// Update the type streamed values will be parsed as.
+    pub fn with_type<D: DeserializeOwned>(self) -> ResumableCursor<D> {
+        ResumableCursor {
+            cursor: self.cursor.with_type(),
+            args: self.args,
+            data: self.data,
+            pending_resume: None,
+        }
+    }
+
+    /// Retrieves the next result from the cursor, if any.
+    ///
+    /// Where calling `StreamExt::next` will internally loop until a document is received, this
+    /// will make at most one request and return `None` if the returned document batch is empty.
+    pub async fn next_if_any(&mut self) -> Result<Option<T>> {
+        Ok(match NextInBatchFuture::new(self).await? {
+            BatchValue::Some { doc, .. } => Some(bson::from_slice(doc.as_bytes())?),
+            BatchValue::Empty | BatchValue::Exhausted => None,
+        })
+    }
+
+    /// Starts a new `find` that continues after the last returned document, or returns `None` if
+    /// this cursor cannot be resumed.
+    fn start_resume(&mut self) -> Option<BoxFuture<'static, Result<Cursor<T>>>> {
+        let last_key = self.data.last_key.as_ref()?;
+        let filter = self.args.resume_filter(last_key);
+        let options = self.args.resume_options(self.data.returned)?;
+        let coll = self.args.coll.clone();
+        Some(Box::pin(async move {
+            let cursor: Cursor<Document> = coll.find(filter, options).await?;
+            Ok(cursor.with_type::<T>())
+        }))
+    }
+}
+
+impl<T> CursorStream for ResumableCursor<T>
+where
+    T: DeserializeOwned,
+{
+    fn poll_next_in_batch(&mut self, cx: &mut Context<'_>) -> Poll<Result<BatchValue>> {
+        loop {
+            if let Some(mut pending) = self.pending_resume.take() {
+                match Pin::new(&mut pending).poll(cx) {
+                    Poll::Pending => {
+                        self.pending_resume = Some(pending);
+                        return Poll::Pending;
+                    }
+                    Poll::Ready(Ok(new_cursor)) => {
+                        // Ensure that the old cursor is killed on the server selected for the new
+                        // one.
+                        self.cursor.set_drop_address(new_cursor.address().clone());
+                        self.cursor = new_cursor;
+                        // After a successful resume, another resume must be allowed.
+                        self.data.resume_attempted = false;
+                        continue;
+                    }
+                    Poll::Ready(Err(e)) => return Poll::Ready(Err(e)),
+                }
+            }
+            let out = self.cursor.poll_next_in_batch(cx);
+            match &out {
+                Poll::Ready(Ok(BatchValue::Some { doc, .. })) => {
+                    self.data.last_key = self.args.extract_key(doc)?;
+                    self.data.returned += 1;
+                }
+                Poll::Ready(Err(e)) if is_resumable(e) && !self.data.resume_attempted => {
+                    self.data.resume_attempted = true;
+                    if let Some(pending) = self.start_resume() {
+                        self.pending_resume = Some(pending);
+                        // Iterate the loop so the new future gets polled and can register wakers.
+                        continue;
+                    }
+                }
+                _ => {}
+            }
+            return out;
+        }
+    }
+}
+
+impl<T> Stream for ResumableCursor<T>
+where
+    T: DeserializeOwned,
+{
+    type Item = Result<T>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        stream_poll_next(Pin::into_inner(self), cx)
+    }
+}
+
+/// Whether a `getMore` failure should cause a resumable cursor to re-issue its `find`.
+fn is_resumable(error: &Error) -> bool {
+    const CURSOR_NOT_FOUND: i32 = 43;
+
+    error.is_network_error()
+        || error.is_state_change_error()
+        || error.is_shutting_down()
+        || error.sdam_code() == Some(CURSOR_NOT_FOUND)
+}
+
+/// Arguments to `find_resumable`, normalized so that they can be used to re-issue the `find`.
+#[derive(Clone, Debug)]
+pub(crate) struct FindArgs {
+    coll: Collection<Document>,
+    filter: Document,
+    options: FindOptions,
+    sort_key: Vec<SortField>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+struct SortField {
+    path: String,
+    ascending: bool,
+}
+
+impl FindArgs {
+    /// Validates the provided options and sets the `sort` used for the initial `find` to the full
+    /// sort key.
+    pub(crate) fn new(
+        coll: Collection<Document>,
+        filter: Option<Document>,
+        options: Option<FindOptions>,
+    ) -> Result<Self> {
+        let mut options = options.unwrap_or_default();
+        if matches!(
+            options.cursor_type,
+            Some(CursorType::Tailable | CursorType::TailableAwait)
+        ) {
+            return Err(Error::invalid_argument(
+                "resumable cursors cannot be tailable",
+            ));
+        }
+
+        let mut sort_key = Vec::new();
+        for (path, direction) in options.sort.iter().flatten() {
+            let ascending = match bson_util::get_int(direction) {
+                Some(1) => true,
+                Some(-1) => false,
+                _ => {
+                    return Err(Error::invalid_argument(format!(
+                        "resumable cursors require the sort direction for \"{}\" to be 1 or -1, \
+                         got {}",
+                        path, direction
+                    )))
+                }
+            };
+            sort_key.push(SortField {
+                path: path.clone(),
+                ascending,
+            });
+        }
+        // `_id` is unique, so including it guarantees that a sort key identifies exactly one
+        // position in the results.
+        if !sort_key.iter().any(|field| field.path == "_id") {
+            sort_key.push(SortField {
+                path: "_id".to_string(),
+                ascending: true,
+            });
+        }
+        options.sort = Some(
+            sort_key
+                .iter()
+                .map(|field| {
+                    let direction = if field.ascending { 1 } else { -1 };
+                    (field.path.clone(), Bson::Int32(direction))
+                })
+                .collect(),
+        );
+
+        Ok(Self {
+            coll,
+            filter: filter.unwrap_or_default(),
+            options,
+            sort_key,
+        })
+    }
+
+    pub(crate) fn filter(&self) -> &Document {
+        &self.filter
+    }
+
+    pub(crate) fn options(&self) -> &FindOptions {
+        &self.options
+    }
+
+    /// Reads the values of the sort key from a returned document, returning `None` if any of them
+    /// are arrays. Missing values sort as null, but if a projection is applied, a missing value
+    /// may have been projected away, so `None` is returned for them as well.
+    fn extract_key(&self, doc: &RawDocument) -> Result<Option<Vec<Bson>>> {
+        let mut values = Vec::with_capacity(self.sort_key.len());
+        for field in &self.sort_key {
+            match lookup_path(doc, &field.path)? {
+                Some(Some(value)) => values.push(value),
+                Some(None) if self.options.projection.is_none() => values.push(Bson::Null),
+                Some(None) | None => return Ok(None),
+            }
+        }
+        Ok(Some(values))
+    }
+
+    /// Builds a filter matching the documents that sort strictly after the given key, e.g. for a
+    /// sort of `{ a: 1, _id: 1 }`:
+    ///
+    /// ```text
+    /// { $expr: { $or: [
+    ///     { $gt: [$a, <a>] },
+    ///     { $and: [{ $eq: [$a, <a>] }, { $gt: [$_id, <_id>] }] },
+    /// ] } }
+    /// ```
+    ///
+    /// Query operators like `$gt` only match values of the same BSON type, so the comparisons are
+    /// made in an `$expr`, which orders values of different types the same way the sort does. A
+    /// missing field sorts as null, so each field is read through `$ifNull`.
+    fn resume_filter(&self, last_key: &[Bson]) -> Document {
+        let field_value = |field: &SortField| -> Bson {
+            doc! { "$ifNull": [format!("${}", field.path), Bson::Null] }.into()
+        };
+        let literal = |value: &Bson| -> Bson { doc! { "$literal": value.clone() }.into() };
+
+        let mut clauses: Vec<Bson> = Vec::with_capacity(self.sort_key.len());
+        for (i, field) in self.sort_key.iter().enumerate() {
+            let mut conditions: Vec<Bson> = self.sort_key[..i]
+                .iter()
+                .zip(last_key)
+                .map(|(prev, value)| doc! { "$eq": [field_value(prev), literal(value)] }.into())
+                .collect();
+            let op = if field.ascending { "$gt" } else { "$lt" };
+            conditions.push(doc! { op: [field_value(field), literal(&last_key[i])] }.into());
+            let clause = if conditions.len() == 1 {
+                conditions.remove(0)
+            } else {
+                doc! { "$and": conditions }.into()
+            };
+            clauses.push(clause);
+        }
+
+        let bound = if clauses.len() == 1 {
+            clauses.remove(0)
+        } else {
+            doc! { "$or": clauses }.into()
+        };
+        let bound = doc! { "$expr": bound };
+        if self.filter.is_empty() {
+            bound
+        } else {
+            doc! { "$and": [self.filter.clone(), bound] }
+        }
+    }
+
+    /// Adjusts the options for a resumed `find` given the number of documents already returned,
+    /// returning `None` if no further documents should be returned.
+    fn resume_options(&self, returned: u64) -> Option<FindOptions> {
+        let mut options = self.options.clone();
+        // The skipped documents all sort before the ones already returned.
+        options.skip = None;
+        if let Some(limit) = options.limit.filter(|limit| *limit > 0) {
+            let remaining = limit - i64::try_from(returned).ok()?;
+            if remaining <= 0 {
+                return None;
+            }
+            options.limit = Some(remaining);
+        }
+        Some(options)
+    }
+}
+
+/// Looks up the value at a dotted path, returning `Some(None)` if it is missing and `None` if it
+/// is an array.
+fn lookup_path(doc: &RawDocument, path: &str) -> Result<Option<Option<Bson>>> {
+    let mut current = doc;
+    let mut parts = path.split('.').peekable();
+    while let Some(part) = parts.next() {
+        let value = match current.get(part)? {
+            Some(value) => value,
+            None => return Ok(Some(None)),
+        };
+        match value {
+            // Arrays are sorted by their minimum or maximum element, which can't be used to bound
+            // the filter.
+            RawBsonRef::Array(_) => return Ok(None),
+            RawBsonRef::Document(d) if parts.peek().is_some() => current = d,
+            _ if parts.peek().is_some() => return Ok(Some(None)),
+            value => return Ok(Some(Some(Bson::try_from(value.to_raw_bson())?))),
+        }
+    }
+    Ok(Some(None))
+}
+
+#[derive(Debug, Default)]
+struct ResumeData {
+    /// The sort key of the most recently returned document.
+    last_key: Option<Vec<Bson>>,
+
+    /// The number of documents returned so far.
+    returned: u64,
+
+    /// Whether or not a resume has been attempted since the last successful resume.
+    resume_attempted: bool,
+}
@@ -1,6 +1,6 @@
 pub mod options;
 
-use std::{fmt::Debug, sync::Arc};
+use std::{fmt::Debug, sync::Arc, time::Duration};
 
 #[cfg(feature = "in-use-encryption-unstable")]
 use bson::doc;
@@ -21,24 +21,44 @@ use crate::{
     error::{Error, ErrorKind, Result},
     gridfs::{options::GridFsBucketOptions, GridFsBucket},
     operation::{
+        is_out_or_merge,
         Aggregate,
         AggregateTarget,
         Create,
+        CreateRole,
+        CreateUser,
+        DbStats,
         DropDatabase,
+        DropRole,
+        DropUser,
+        GrantRolesToUser,
         ListCollections,
+        RolesInfo,
         RunCommand,
         RunCursorCommand,
+        UpdateUser,
+        UsersInfo,
     },
     options::{
         AggregateOptions,
         CollectionOptions,
         CreateCollectionOptions,
+        CreateRoleOptions,
+        CreateUserOptions,
         DatabaseOptions,
+        DbStatsOptions,
         DropDatabaseOptions,
+        DropRoleOptions,
+        DropUserOptions,
+        GrantRolesToUserOptions,
         ListCollectionsOptions,
+        Role,
+        RolesInfoOptions,
         RunCursorCommandOptions,
+        UpdateUserOptions,
+        UsersInfoOptions,
     },
-    results::CollectionSpecification,
+    results::{CollectionSpecification, DatabaseStats, RoleInfo, UserInfo},
     selection_criteria::SelectionCriteria,
     Client,
     ClientSession,
@@ -96,6 +116,7 @@ struct DatabaseInner {
     selection_criteria: Option<SelectionCriteria>,
     read_concern: Option<ReadConcern>,
     write_concern: Option<WriteConcern>,
+    timeout: Option<Duration>,
 }
 
 impl Database {
@@ -113,6 +134,8 @@ impl Database {
             .write_concern
             .or_else(|| client.write_concern().cloned());
 
+        let timeout = options.timeout.or_else(|| client.timeout());
+
         Self {
             inner: Arc::new(DatabaseInner {
                 client,
@@ -120,6 +143,7 @@ impl Database {
                 selection_criteria,
                 read_concern,
                 write_concern,
+                timeout,
             }),
         }
     }
@@ -149,6 +173,11 @@ impl Database {
         self.inner.write_concern.as_ref()
     }
 
+    /// Gets the default operation timeout of the `Database`.
+    pub fn timeout(&self) -> Option<Duration> {
+        self.inner.timeout
+    }
+
     /// Gets a handle to a collection in this database with the provided name. The
     /// [`Collection`] options (e.g. read preference and write concern) will default to those of
     /// this [`Database`].
@@ -215,12 +244,11 @@ impl Database {
         filter: impl Into<Option<Document>>,
         options: impl Into<Option<ListCollectionsOptions>>,
     ) -> Result<Cursor<CollectionSpecification>> {
-        let list_collections = ListCollections::new(
-            self.name().to_string(),
-            filter.into(),
-            false,
-            options.into(),
-        );
+        let mut options = options.into();
+        resolve_options!(self, options, [timeout]);
+
+        let list_collections =
+            ListCollections::new(self.name().to_string(), filter.into(), false, options);
         self.client()
             .execute_cursor_operation(list_collections)
             .await
@@ -235,12 +263,11 @@ impl Database {
         options: impl Into<Option<ListCollectionsOptions>>,
         session: &mut ClientSession,
     ) -> Result<SessionCursor<CollectionSpecification>> {
-        let list_collections = ListCollections::new(
-            self.name().to_string(),
-            filter.into(),
-            false,
-            options.into(),
-        );
+        let mut options = options.into();
+        resolve_timeout_with_session!(self, options, Some(&mut *session));
+
+        let list_collections =
+            ListCollections::new(self.name().to_string(), filter.into(), false, options);
         self.client()
             .execute_session_cursor_operation(list_collections, session)
             .await
@@ -270,8 +297,11 @@ impl Database {
         &self,
         filter: impl Into<Option<Document>>,
     ) -> Result<Vec<String>> {
+        let mut options: Option<ListCollectionsOptions> = None;
+        resolve_options!(self, options, [timeout]);
+
         let list_collections =
-            ListCollections::new(self.name().to_string(), filter.into(), true, None);
+            ListCollections::new(self.name().to_string(), filter.into(), true, options);
         let cursor: Cursor<Document> = self
             .client()
             .execute_cursor_operation(list_collections)
@@ -286,8 +316,11 @@ impl Database {
         filter: impl Into<Option<Document>>,
         session: &mut ClientSession,
     ) -> Result<Vec<String>> {
+        let mut options: Option<ListCollectionsOptions> = None;
+        resolve_timeout_with_session!(self, options, Some(&mut *session));
+
         let list_collections =
-            ListCollections::new(self.name().to_string(), filter.into(), true, None);
+            ListCollections::new(self.name().to_string(), filter.into(), true, options);
         let mut cursor: SessionCursor<Document> = self
             .client()
             .execute_session_cursor_operation(list_collections, &mut *session)
@@ -307,6 +340,7 @@ impl Database {
         let mut options: Option<CreateCollectionOptions> = options.into();
         resolve_options!(self, options, [write_concern]);
         let mut session = session.into();
+        resolve_timeout_with_session!(self, options, session.as_ref());
 
         let ns = Namespace {
             db: self.name().to_string(),
@@ -447,6 +481,154 @@ impl Database {
         self.create_collection_common(name, options, session).await
     }
 
+    /// Creates a new view named `name` in the database, whose contents are the result of running
+    /// the aggregation `pipeline` against the collection or view named `source` in this database.
+    pub async fn create_view(
+        &self,
+        name: impl AsRef<str>,
+        source: impl Into<String>,
+        pipeline: impl IntoIterator<Item = Document>,
+    ) -> Result<()> {
+        self.create_collection_common(name, view_options(source, pipeline), None)
+            .await
+    }
+
+    /// Creates a new view named `name` in the database using the provided `ClientSession`. See
+    /// [`Database::create_view`] for more details.
+    pub async fn create_view_with_session(
+        &self,
+        name: impl AsRef<str>,
+        source: impl Into<String>,
+        pipeline: impl IntoIterator<Item = Document>,
+        session: &mut ClientSession,
+    ) -> Result<()> {
+        self.create_collection_common(name, view_options(source, pipeline), session)
+            .await
+    }
+
+    /// Gets statistics about the database, such as the number of documents it holds and the
+    /// amount of storage allocated to it.
+    pub async fn stats(&self, options: impl Into<Option<DbStatsOptions>>) -> Result<DatabaseStats> {
+        let mut options = options.into();
+        resolve_options!(self, options, [selection_criteria]);
+
+        let db_stats = DbStats::new(self.name().to_string(), options);
+        self.client().execute_operation(db_stats, None).await
+    }
+
+    /// Creates a new user named `name` in the database. The password in `options`, if any, is
+    /// redacted from command monitoring events and tracing output.
+    pub async fn create_user(
+        &self,
+        name: impl AsRef<str>,
+        options: impl Into<Option<CreateUserOptions>>,
+    ) -> Result<()> {
+        let mut options = options.into();
+        resolve_options!(self, options, [write_concern]);
+
+        let create_user =
+            CreateUser::new(self.name().to_string(), name.as_ref().to_string(), options);
+        self.client().execute_operation(create_user, None).await
+    }
+
+    /// Updates the user named `name` in the database. Only the fields set in `options` are
+    /// changed; fields such as `roles` replace the user's existing values rather than being
+    /// merged with them.
+    pub async fn update_user(
+        &self,
+        name: impl AsRef<str>,
+        options: impl Into<Option<UpdateUserOptions>>,
+    ) -> Result<()> {
+        let mut options = options.into();
+        resolve_options!(self, options, [write_concern]);
+
+        let update_user =
+            UpdateUser::new(self.name().to_string(), name.as_ref().to_string(), options);
+        self.client().execute_operation(update_user, None).await
+    }
+
+    /// Removes the user named `name` from the database.
+    pub async fn drop_user(
+        &self,
+        name: impl AsRef<str>,
+        options: impl Into<Option<DropUserOptions>>,
+    ) -> Result<()> {
+        let mut options = options.into();
+        resolve_options!(self, options, [write_concern]);
+
+        let drop_user = DropUser::new(self.name().to_string(), name.as_ref().to_string(), options);
+        self.client().execute_operation(drop_user, None).await
+    }
+
+    /// Gets information about the users with the given `names` in the database, or about all of
+    /// the database's users if `names` is `None`.
+    pub async fn users_info(
+        &self,
+        names: impl Into<Option<Vec<String>>>,
+        options: impl Into<Option<UsersInfoOptions>>,
+    ) -> Result<Vec<UserInfo>> {
+        let users_info = UsersInfo::new(self.name().to_string(), names.into(), options.into());
+        self.client().execute_operation(users_info, None).await
+    }
+
+    /// Grants the given `roles` to the user named `name` in the database, in addition to the
+    /// roles it already has.
+    pub async fn grant_roles_to_user(
+        &self,
+        name: impl AsRef<str>,
+        roles: impl IntoIterator<Item = Role>,
+        options: impl Into<Option<GrantRolesToUserOptions>>,
+    ) -> Result<()> {
+        let mut options = options.into();
+        resolve_options!(self, options, [write_concern]);
+
+        let grant_roles = GrantRolesToUser::new(
+            self.name().to_string(),
+            name.as_ref().to_string(),
+            roles.into_iter().collect(),
+            options,
+        );
+        self.client().execute_operation(grant_roles, None).await
+    }
+
+    /// Creates a new user-defined role named `name` in the database.
+    pub async fn create_role(
+        &self,
+        name: impl AsRef<str>,
+        options: impl Into<Option<CreateRoleOptions>>,
+    ) -> Result<()> {
+        let mut options = options.into();
+        resolve_options!(self, options, [write_concern]);
+
+        let create_role =
+            CreateRole::new(self.name().to_string(), name.as_ref().to_string(), options);
+        self.client().execute_operation(create_role, None).await
+    }
+
+    /// Removes the user-defined role named `name` from the database.
+    pub async fn drop_role(
+        &self,
+        name: impl AsRef<str>,
+        options: impl Into<Option<DropRoleOptions>>,
+    ) -> Result<()> {
+        let mut options = options.into();
+        resolve_options!(self, options, [write_concern]);
+
+        let drop_role = DropRole::new(self.name().to_string(), name.as_ref().to_string(), options);
+        self.client().execute_operation(drop_role, None).await
+    }
+
+    /// Gets information about the roles with the given `names` in the database, or about all of
+    /// the database's user-defined roles if `names` is `None`.
+    pub async fn roles_info(
+        &self,
+        names: impl Into<Option<Vec<String>>>,
+        options: impl Into<Option<RolesInfoOptions>>,
+    ) -> Result<Vec<RoleInfo>> {
+        let roles_info = RolesInfo::new(self.name().to_string(), names.into(), options.into());
+        self.client().execute_operation(roles_info, None).await
+    }
+
     pub(crate) async fn run_command_common(
         &self,
         command: Document,
@@ -454,11 +636,17 @@ impl Database {
         session: impl Into<Option<&mut ClientSession>>,
         pinned_connection: Option<&PinnedConnectionHandle>,
     ) -> Result<Document> {
+        let session = session.into();
+        let timeout = session
+            .as_ref()
+            .and_then(|session| session.options().and_then(|opts| opts.default_timeout))
+            .or_else(|| self.timeout());
         let operation = RunCommand::new(
             self.name().into(),
             command,
             selection_criteria.into(),
             pinned_connection,
+            timeout,
         )?;
         self.client().execute_operation(operation, session).await
     }
@@ -485,11 +673,18 @@ impl Database {
         command: Document,
         options: impl Into<Option<RunCursorCommandOptions>>,
     ) -> Result<Cursor<Document>> {
-        let options: Option<RunCursorCommandOptions> = options.into();
+        let mut options: Option<RunCursorCommandOptions> = options.into();
+        resolve_options!(self, options, [timeout]);
         let selection_criteria = options
             .as_ref()
             .and_then(|options| options.selection_criteria.clone());
-        let rcc = RunCommand::new(self.name().to_string(), command, selection_criteria, None)?;
+        let rcc = RunCommand::new(
+            self.name().to_string(),
+            command,
+            selection_criteria,
+            None,
+            None,
+        )?;
         let rc_command = RunCursorCommand::new(rcc, options)?;
         let client = self.client();
         client.execute_cursor_operation(rc_command).await
@@ -504,10 +699,17 @@ impl Database {
     ) -> Result<SessionCursor<Document>> {
         let mut options: Option<RunCursorCommandOptions> = options.into();
         resolve_selection_criteria_with_session!(self, options, Some(&mut *session))?;
+        resolve_timeout_with_session!(self, options, Some(&mut *session));
         let selection_criteria = options
             .as_ref()
             .and_then(|options| options.selection_criteria.clone());
-        let rcc = RunCommand::new(self.name().to_string(), command, selection_criteria, None)?;
+        let rcc = RunCommand::new(
+            self.name().to_string(),
+            command,
+            selection_criteria,
+            None,
+            None,
+        )?;
         let rc_command = RunCursorCommand::new(rcc, options)?;
         let client = self.client();
         client
@@ -565,12 +767,13 @@ impl Database {
         pipeline: impl IntoIterator<Item = Document>,
         options: impl Into<Option<AggregateOptions>>,
     ) -> Result<Cursor<Document>> {
+        let pipeline: Vec<Document> = pipeline.into_iter().collect();
         let mut options = options.into();
-        resolve_options!(
-            self,
-            options,
-            [read_concern, write_concern, selection_criteria]
-        );
+        resolve_options!(self, options, [read_concern, selection_criteria, timeout]);
+        // Only aggregations that end in $out or $merge write, so only they inherit a write concern.
+        if is_out_or_merge(&pipeline) {
+            resolve_options!(self, options, [write_concern]);
+        }
 
         let aggregate = Aggregate::new(self.name().to_string(), pipeline, options);
         let client = self.client();
@@ -587,12 +790,13 @@ impl Database {
         options: impl Into<Option<AggregateOptions>>,
         session: &mut ClientSession,
     ) -> Result<SessionCursor<Document>> {
+        let pipeline: Vec<Document> = pipeline.into_iter().collect();
         let mut options = options.into();
-        resolve_options!(
-            self,
-            options,
-            [read_concern, write_concern, selection_criteria]
-        );
+        resolve_options!(self, options, [read_concern, selection_criteria]);
+        resolve_timeout_with_session!(self, options, Some(&mut *session));
+        if is_out_or_merge(&pipeline) {
+            resolve_options!(self, options, [write_concern]);
+        }
 
         let aggregate = Aggregate::new(self.name().to_string(), pipeline, options);
         let client = self.client();
@@ -653,3 +857,13 @@ impl Database {
         GridFsBucket::new(self.clone(), options.into().unwrap_or_default())
     }
 }
+
+fn view_options(
+    source: impl Into<String>,
+    pipeline: impl IntoIterator<Item = Document>,
+) -> CreateCollectionOptions {
+    CreateCollectionOptions::builder()
+        .view_on(source.into())
+        .pipeline(pipeline.into_iter().collect::<Vec<_>>())
+        .build()
+}
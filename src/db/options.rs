@@ -1,4 +1,4 @@
-use std::time::Duration;
+use std::{fmt, time::Duration};
 
 use bson::doc;
 use serde::{Deserialize, Serialize};
@@ -27,6 +27,16 @@ pub struct DatabaseOptions {
 
     /// The default write concern for operations.
     pub write_concern: Option<WriteConcern>,
+
+    /// The default timeout for operations. Operations that exceed this amount of time, including
+    /// server selection, connection checkout and any retries, will fail with an
+    /// [`ErrorKind::Timeout`](crate::error::ErrorKind::Timeout) error.
+    #[serde(
+        rename = "timeoutMS",
+        deserialize_with = "serde_util::deserialize_duration_option_from_u64_millis",
+        default
+    )]
+    pub timeout: Option<Duration>,
 }
 
 /// These are the valid options for creating a collection with
@@ -116,6 +126,15 @@ pub struct CreateCollectionOptions {
     /// Map of encrypted fields for the created collection.
     #[cfg(feature = "in-use-encryption-unstable")]
     pub encrypted_fields: Option<Document>,
+
+    /// The maximum amount of time to allow the operation to run, including any retries.
+    #[serde(
+        rename = "timeoutMS",
+        skip_serializing,
+        deserialize_with = "serde_util::deserialize_duration_option_from_u64_millis",
+        default
+    )]
+    pub timeout: Option<Duration>,
 }
 
 /// Specifies how strictly the database should apply validation rules to existing documents during
@@ -282,6 +301,15 @@ pub struct ListCollectionsOptions {
     ///
     /// This option is only available on server versions 4.4+.
     pub comment: Option<Bson>,
+
+    /// The maximum amount of time to allow the operation to run, including any retries.
+    #[serde(
+        rename = "timeoutMS",
+        skip_serializing,
+        deserialize_with = "serde_util::deserialize_duration_option_from_u64_millis",
+        default
+    )]
+    pub timeout: Option<Duration>,
 }
 
 /// Specifies the options to a
@@ -335,4 +363,349 @@ pub struct RunCursorCommandOptions {
     /// Optional BSON value. Use this value to configure the comment option sent on subsequent
     /// getMore commands.
     pub comment: Option<Bson>,
+    /// The maximum amount of time to allow the operation to run, including any retries.
+    #[serde(
+        rename = "timeoutMS",
+        skip_serializing,
+        deserialize_with = "serde_util::deserialize_duration_option_from_u64_millis",
+        default
+    )]
+    pub timeout: Option<Duration>,
+}
+
+/// A reference to a role, identified by its name and the database in which it is defined.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct Role {
+    /// The name of the role.
+    pub role: String,
+
+    /// The database in which the role is defined.
+    pub db: String,
+}
+
+impl Role {
+    /// Creates a reference to the role named `role` defined in the database `db`.
+    pub fn new(role: impl Into<String>, db: impl Into<String>) -> Self {
+        Self {
+            role: role.into(),
+            db: db.into(),
+        }
+    }
+}
+
+/// A set of actions that a role permits on a resource. See the
+/// [MongoDB manual](https://www.mongodb.com/docs/manual/reference/resource-document/) for the
+/// format of resource documents.
+#[derive(Clone, Debug, PartialEq, Deserialize, TypedBuilder, Serialize)]
+#[builder(field_defaults(setter(into)))]
+#[non_exhaustive]
+pub struct Privilege {
+    /// The resource the actions apply to, e.g. `{ "db": "products", "collection": "" }`.
+    pub resource: Document,
+
+    /// The actions permitted on the resource, e.g. `"find"` or `"insert"`.
+    pub actions: Vec<String>,
+}
+
+/// Restricts the addresses from which a user may authenticate and the server addresses to which
+/// they may connect.
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, PartialEq, Deserialize, TypedBuilder, Serialize)]
+#[serde(rename_all = "camelCase")]
+#[builder(field_defaults(default, setter(into)))]
+#[non_exhaustive]
+pub struct AuthenticationRestriction {
+    /// The IP addresses or CIDR ranges from which the user may authenticate.
+    pub client_source: Option<Vec<String>>,
+
+    /// The IP addresses or CIDR ranges of the servers to which the user may connect.
+    pub server_address: Option<Vec<String>>,
+}
+
+/// Specifies the options to a [`Database::create_user`](../struct.Database.html#method.create_user)
+/// operation.
+///
+/// The `Debug` implementation of this type does not print the password.
+#[skip_serializing_none]
+#[derive(Clone, Default, Deserialize, TypedBuilder, Serialize)]
+#[serde(rename_all = "camelCase")]
+#[builder(field_defaults(default, setter(into)))]
+#[non_exhaustive]
+pub struct CreateUserOptions {
+    /// The user's password. This may be omitted for users authenticating with an external
+    /// mechanism such as X.509 or GSSAPI.
+    #[serde(rename = "pwd")]
+    pub password: Option<String>,
+
+    /// The roles granted to the user. Defaults to no roles.
+    pub roles: Option<Vec<Role>>,
+
+    /// Arbitrary information to store with the user.
+    pub custom_data: Option<Document>,
+
+    /// The restrictions on where the user may authenticate from.
+    pub authentication_restrictions: Option<Vec<AuthenticationRestriction>>,
+
+    /// The SCRAM mechanisms to create credentials for, e.g. `"SCRAM-SHA-256"`.
+    pub mechanisms: Option<Vec<String>>,
+
+    /// Whether the server or the client should digest the password.
+    pub digest_password: Option<bool>,
+
+    /// The write concern for the operation.
+    pub write_concern: Option<WriteConcern>,
+
+    /// Tags the query with an arbitrary [`Bson`] value to help trace the operation through the
+    /// database profiler, currentOp and logs.
+    pub comment: Option<Bson>,
+}
+
+impl fmt::Debug for CreateUserOptions {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CreateUserOptions")
+            .field("password", &self.password.as_ref().map(|_| "REDACTED"))
+            .field("roles", &self.roles)
+            .field("custom_data", &self.custom_data)
+            .field(
+                "authentication_restrictions",
+                &self.authentication_restrictions,
+            )
+            .field("mechanisms", &self.mechanisms)
+            .field("digest_password", &self.digest_password)
+            .field("write_concern", &self.write_concern)
+            .field("comment", &self.comment)
+            .finish()
+    }
+}
+
+/// Specifies the options to a [`Database::update_user`](../struct.Database.html#method.update_user)
+/// operation. Fields which are not set are left unchanged on the user.
+///
+/// The `Debug` implementation of this type does not print the password.
+#[skip_serializing_none]
+#[derive(Clone, Default, Deserialize, TypedBuilder, Serialize)]
+#[serde(rename_all = "camelCase")]
+#[builder(field_defaults(default, setter(into)))]
+#[non_exhaustive]
+pub struct UpdateUserOptions {
+    /// The user's new password.
+    #[serde(rename = "pwd")]
+    pub password: Option<String>,
+
+    /// The roles granted to the user, replacing any roles it previously had.
+    pub roles: Option<Vec<Role>>,
+
+    /// Arbitrary information to store with the user, replacing any previous custom data.
+    pub custom_data: Option<Document>,
+
+    /// The restrictions on where the user may authenticate from.
+    pub authentication_restrictions: Option<Vec<AuthenticationRestriction>>,
+
+    /// The SCRAM mechanisms to create credentials for, e.g. `"SCRAM-SHA-256"`.
+    pub mechanisms: Option<Vec<String>>,
+
+    /// Whether the server or the client should digest the password.
+    pub digest_password: Option<bool>,
+
+    /// The write concern for the operation.
+    pub write_concern: Option<WriteConcern>,
+
+    /// Tags the query with an arbitrary [`Bson`] value to help trace the operation through the
+    /// database profiler, currentOp and logs.
+    pub comment: Option<Bson>,
+}
+
+impl fmt::Debug for UpdateUserOptions {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("UpdateUserOptions")
+            .field("password", &self.password.as_ref().map(|_| "REDACTED"))
+            .field("roles", &self.roles)
+            .field("custom_data", &self.custom_data)
+            .field(
+                "authentication_restrictions",
+                &self.authentication_restrictions,
+            )
+            .field("mechanisms", &self.mechanisms)
+            .field("digest_password", &self.digest_password)
+            .field("write_concern", &self.write_concern)
+            .field("comment", &self.comment)
+            .finish()
+    }
+}
+
+/// Specifies the options to a [`Database::drop_user`](../struct.Database.html#method.drop_user)
+/// operation.
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, TypedBuilder, Serialize)]
+#[serde(rename_all = "camelCase")]
+#[builder(field_defaults(default, setter(into)))]
+#[non_exhaustive]
+pub struct DropUserOptions {
+    /// The write concern for the operation.
+    pub write_concern: Option<WriteConcern>,
+
+    /// Tags the query with an arbitrary [`Bson`] value to help trace the operation through the
+    /// database profiler, currentOp and logs.
+    pub comment: Option<Bson>,
+}
+
+/// Specifies the options to a
+/// [`Database::grant_roles_to_user`](../struct.Database.html#method.grant_roles_to_user) operation.
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, TypedBuilder, Serialize)]
+#[serde(rename_all = "camelCase")]
+#[builder(field_defaults(default, setter(into)))]
+#[non_exhaustive]
+pub struct GrantRolesToUserOptions {
+    /// The write concern for the operation.
+    pub write_concern: Option<WriteConcern>,
+
+    /// Tags the query with an arbitrary [`Bson`] value to help trace the operation through the
+    /// database profiler, currentOp and logs.
+    pub comment: Option<Bson>,
+}
+
+/// Specifies the options to a [`Database::users_info`](../struct.Database.html#method.users_info)
+/// operation.
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, TypedBuilder, Serialize)]
+#[serde(rename_all = "camelCase")]
+#[builder(field_defaults(default, setter(into)))]
+#[non_exhaustive]
+pub struct UsersInfoOptions {
+    /// Whether to include the users' credentials in the result.
+    pub show_credentials: Option<bool>,
+
+    /// Whether to include the users' custom data in the result.
+    pub show_custom_data: Option<bool>,
+
+    /// Whether to include the users' inherited privileges in the result.
+    pub show_privileges: Option<bool>,
+
+    /// Whether to include the users' authentication restrictions in the result.
+    pub show_authentication_restrictions: Option<bool>,
+
+    /// A filter to apply to the user documents returned by the server.
+    pub filter: Option<Document>,
+
+    /// Tags the query with an arbitrary [`Bson`] value to help trace the operation through the
+    /// database profiler, currentOp and logs.
+    pub comment: Option<Bson>,
+}
+
+/// Specifies the options to a [`Database::create_role`](../struct.Database.html#method.create_role)
+/// operation.
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, TypedBuilder, Serialize)]
+#[serde(rename_all = "camelCase")]
+#[builder(field_defaults(default, setter(into)))]
+#[non_exhaustive]
+pub struct CreateRoleOptions {
+    /// The privileges granted by the role. Defaults to no privileges.
+    pub privileges: Option<Vec<Privilege>>,
+
+    /// The roles from which the role inherits privileges. Defaults to no roles.
+    pub roles: Option<Vec<Role>>,
+
+    /// The restrictions on where users with the role may authenticate from.
+    pub authentication_restrictions: Option<Vec<AuthenticationRestriction>>,
+
+    /// The write concern for the operation.
+    pub write_concern: Option<WriteConcern>,
+
+    /// Tags the query with an arbitrary [`Bson`] value to help trace the operation through the
+    /// database profiler, currentOp and logs.
+    pub comment: Option<Bson>,
+}
+
+/// Specifies the options to a [`Database::drop_role`](../struct.Database.html#method.drop_role)
+/// operation.
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, TypedBuilder, Serialize)]
+#[serde(rename_all = "camelCase")]
+#[builder(field_defaults(default, setter(into)))]
+#[non_exhaustive]
+pub struct DropRoleOptions {
+    /// The write concern for the operation.
+    pub write_concern: Option<WriteConcern>,
+
+    /// Tags the query with an arbitrary [`Bson`] value to help trace the operation through the
+    /// database profiler, currentOp and logs.
+    pub comment: Option<Bson>,
+}
+
+/// Specifies the options to a [`Database::roles_info`](../struct.Database.html#method.roles_info)
+/// operation.
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, TypedBuilder, Serialize)]
+#[serde(rename_all = "camelCase")]
+#[builder(field_defaults(default, setter(into)))]
+#[non_exhaustive]
+pub struct RolesInfoOptions {
+    /// Whether to include the roles' privileges, including inherited ones, in the result.
+    pub show_privileges: Option<bool>,
+
+    /// Whether to include the built-in roles in the result when listing all roles in the
+    /// database.
+    pub show_built_in_roles: Option<bool>,
+
+    /// Whether to include the roles' authentication restrictions in the result.
+    pub show_authentication_restrictions: Option<bool>,
+
+    /// Tags the query with an arbitrary [`Bson`] value to help trace the operation through the
+    /// database profiler, currentOp and logs.
+    pub comment: Option<Bson>,
+}
+
+/// Specifies the options to a [`Database::stats`](../struct.Database.html#method.stats) operation.
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, TypedBuilder, Serialize)]
+#[serde(rename_all = "camelCase")]
+#[builder(field_defaults(default, setter(into)))]
+#[non_exhaustive]
+pub struct DbStatsOptions {
+    /// The factor by which the server should divide the sizes it reports, e.g. `1024` to report
+    /// kibibytes instead of bytes.
+    #[serde(serialize_with = "serde_util::serialize_u32_option_as_i32")]
+    pub scale: Option<u32>,
+
+    /// Whether to report the amount of free space allocated to the database's collections and
+    /// indexes.
+    pub free_storage: Option<bool>,
+
+    /// The criteria used to select a server for this operation.
+    ///
+    /// If none is specified, the selection criteria defined on the database will be used.
+    #[serde(skip_serializing)]
+    #[serde(rename = "readPreference")]
+    pub selection_criteria: Option<SelectionCriteria>,
+
+    /// Tags the query with an arbitrary [`Bson`] value to help trace the operation through the
+    /// database profiler, currentOp and logs.
+    pub comment: Option<Bson>,
+}
+
+/// Specifies the options to a [`Client::server_status`](../struct.Client.html#method.server_status)
+/// operation.
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, TypedBuilder, Serialize)]
+#[serde(rename_all = "camelCase")]
+#[builder(field_defaults(default, setter(into)))]
+#[non_exhaustive]
+pub struct ServerStatusOptions {
+    /// Sections of the output to include or exclude, e.g. `{ "repl": 0, "latchAnalysis": 1 }`.
+    #[serde(flatten)]
+    pub sections: Option<Document>,
+
+    /// The criteria used to select the server to report the status of.
+    ///
+    /// If none is specified, the selection criteria defined on the client will be used.
+    #[serde(skip_serializing)]
+    #[serde(rename = "readPreference")]
+    pub selection_criteria: Option<SelectionCriteria>,
+
+    /// Tags the query with an arbitrary [`Bson`] value to help trace the operation through the
+    /// database profiler, currentOp and logs.
+    pub comment: Option<Bson>,
 }
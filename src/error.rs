@@ -11,7 +11,12 @@ use bson::Bson;
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
-use crate::{bson::Document, options::ServerAddress, sdam::TopologyVersion};
+use crate::{
+    bson::Document,
+    options::ServerAddress,
+    results::BulkWriteResult,
+    sdam::TopologyVersion,
+};
 
 const RECOVERING_CODES: [i32; 5] = [11600, 11602, 13436, 189, 91];
 const NOTWRITABLEPRIMARY_CODES: [i32; 3] = [10107, 13435, 10058];
@@ -125,6 +130,14 @@ impl Error {
         ErrorKind::Io(Arc::new(std::io::ErrorKind::TimedOut.into())).into()
     }
 
+    /// Construct an error indicating that an operation's `timeout` expired.
+    pub(crate) fn operation_timeout(message: impl Into<String>) -> Error {
+        ErrorKind::Timeout {
+            message: message.into(),
+        }
+        .into()
+    }
+
     pub(crate) fn invalid_argument(message: impl Into<String>) -> Error {
         ErrorKind::InvalidArgument {
             message: message.into(),
@@ -153,10 +166,21 @@ impl Error {
         matches!(self.kind.as_ref(), ErrorKind::Command(ref err) if err.code == 26)
     }
 
+    /// Whether this error indicates that the connection's credentials have expired and it must
+    /// reauthenticate before the command can be retried.
+    pub(crate) fn is_reauthentication_required(&self) -> bool {
+        matches!(self.kind.as_ref(), ErrorKind::Command(ref err) if err.code == 391)
+    }
+
     pub(crate) fn is_server_selection_error(&self) -> bool {
         matches!(self.kind.as_ref(), ErrorKind::ServerSelection { .. })
     }
 
+    /// Whether this error was caused by an operation exceeding its configured `timeout`.
+    pub fn is_timeout_error(&self) -> bool {
+        matches!(self.kind.as_ref(), ErrorKind::Timeout { .. })
+    }
+
     pub(crate) fn is_max_time_ms_expired_error(&self) -> bool {
         self.sdam_code() == Some(50)
     }
@@ -213,6 +237,7 @@ impl Error {
             self.kind.as_ref(),
             ErrorKind::Authentication { .. }
                 | ErrorKind::BulkWrite(_)
+                | ErrorKind::ClientBulkWrite(_)
                 | ErrorKind::Command(_)
                 | ErrorKind::Write(_)
         )
@@ -301,7 +326,7 @@ impl Error {
             ErrorKind::BulkWrite(BulkWriteFailure {
                 write_concern_error,
                 write_errors,
-                inserted_ids: _,
+                ..
             }) => {
                 let mut msg = "".to_string();
                 if let Some(wc_error) = write_concern_error {
@@ -314,6 +339,22 @@ impl Error {
                 }
                 Some(msg)
             }
+            ErrorKind::ClientBulkWrite(ClientBulkWriteFailure {
+                write_errors,
+                write_concern_errors,
+                ..
+            }) => {
+                let mut msg = "".to_string();
+                for wc_error in write_concern_errors {
+                    msg.push_str(wc_error.message.as_str());
+                }
+                let mut indexes: Vec<_> = write_errors.keys().collect();
+                indexes.sort();
+                for index in indexes {
+                    msg.push_str(write_errors[index].message.as_str());
+                }
+                Some(msg)
+            }
             ErrorKind::Write(WriteFailure::WriteConcernError(wc_error)) => {
                 Some(wc_error.message.clone())
             }
@@ -438,6 +479,14 @@ impl Error {
                     wce.redact();
                 }
             }
+            ErrorKind::ClientBulkWrite(ref mut failure) => {
+                for we in failure.write_errors.values_mut() {
+                    we.redact();
+                }
+                for wce in failure.write_concern_errors.iter_mut() {
+                    wce.redact();
+                }
+            }
             ErrorKind::Command(ref mut command_error) => {
                 command_error.redact();
             }
@@ -466,6 +515,7 @@ impl Error {
             | ErrorKind::Authentication { .. }
             | ErrorKind::Custom(_)
             | ErrorKind::Shutdown
+            | ErrorKind::Timeout { .. }
             | ErrorKind::GridFs(_) => {}
             #[cfg(feature = "in-use-encryption-unstable")]
             ErrorKind::Encryption(_) => {}
@@ -549,6 +599,13 @@ pub enum ErrorKind {
     #[error("An error occurred when trying to execute a write operation: {0:?}")]
     BulkWrite(BulkWriteFailure),
 
+    /// An error occurred when trying to execute a
+    /// [`Client::bulk_write`](crate::Client::bulk_write) operation. If the bulk write could not
+    /// be completed because of an error that did not pertain to a specific write, that error is
+    /// the [`source`](std::error::Error::source) of this error.
+    #[error("An error occurred when trying to execute a bulk write operation: {0:?}")]
+    ClientBulkWrite(ClientBulkWriteFailure),
+
     /// The server returned an error to an attempted operation.
     #[error("Command failed: {0}")]
     // note that if this Display impl changes, COMMAND_ERROR_REGEX in the unified runner matching
@@ -629,6 +686,11 @@ pub enum ErrorKind {
     /// A method was called on a client that was shut down.
     #[error("Client has been shut down")]
     Shutdown,
+
+    /// The operation did not complete within the `timeout` configured for it.
+    #[error("Operation timed out: {message}")]
+    #[non_exhaustive]
+    Timeout { message: String },
 }
 
 impl ErrorKind {
@@ -807,6 +869,12 @@ pub struct BulkWriteFailure {
 
     #[serde(skip)]
     pub(crate) inserted_ids: HashMap<usize, Bson>,
+
+    /// The results of the writes that were successfully performed before the operation failed, if
+    /// any. This is only populated for errors returned from
+    /// [`Collection::bulk_write`](crate::Collection::bulk_write).
+    #[serde(skip)]
+    pub partial_result: Option<BulkWriteResult>,
 }
 
 impl BulkWriteFailure {
@@ -815,6 +883,35 @@ impl BulkWriteFailure {
             write_errors: None,
             write_concern_error: None,
             inserted_ids: Default::default(),
+            partial_result: None,
+        }
+    }
+}
+
+/// The set of errors that occurred during a [`Client::bulk_write`](crate::Client::bulk_write)
+/// operation.
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub struct ClientBulkWriteFailure {
+    /// The errors that occurred for individual writes, keyed by the index of the write in the list
+    /// of models provided.
+    pub write_errors: HashMap<usize, WriteError>,
+
+    /// The write concern errors that occurred. The models are sent to the server in one or more
+    /// batches, each of which may produce a write concern error.
+    pub write_concern_errors: Vec<WriteConcernError>,
+
+    /// The results of the writes that were successfully performed before the operation failed, if
+    /// any.
+    pub partial_result: Option<BulkWriteResult>,
+}
+
+impl ClientBulkWriteFailure {
+    pub(crate) fn new() -> Self {
+        ClientBulkWriteFailure {
+            write_errors: HashMap::new(),
+            write_concern_errors: Vec::new(),
+            partial_result: None,
         }
     }
 }
@@ -2,4 +2,6 @@
 
 pub mod cmap;
 pub mod command;
+#[cfg(feature = "metrics")]
+pub mod metrics;
 pub mod sdam;
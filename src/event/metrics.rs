@@ -0,0 +1,424 @@
+//! Contains a ready-made set of event handlers that record metrics about a `Client`'s connection
+//! pools, commands and server monitoring.
+//!
+//! A [`ClientMetrics`] implements [`CmapEventHandler`], [`CommandEventHandler`] and
+//! [`SdamEventHandler`] and reports the following metrics to a [`MetricsExporter`]:
+//!
+//! | Name                                      | Type      | Labels              |
+//! |:------------------------------------------|:----------|:--------------------|
+//! | `mongodb_pool_connections`                | gauge     | `address`           |
+//! | `mongodb_pool_checked_out_connections`    | gauge     | `address`           |
+//! | `mongodb_pool_wait_queue_size`            | gauge     | `address`           |
+//! | `mongodb_pool_checkout_duration_seconds`  | histogram | `address`           |
+//! | `mongodb_command_duration_seconds`        | histogram | `command`           |
+//! | `mongodb_command_failures_total`          | counter   | `command`           |
+//! | `mongodb_heartbeat_duration_seconds`      | histogram | `address`           |
+//!
+//! Applications can implement [`MetricsExporter`] to forward these to the metrics library of their
+//! choice (e.g. OpenTelemetry), or use the provided [`MetricsRegistry`], which keeps the values in
+//! memory and can render them in the Prometheus text exposition format.
+//!
+//! ```rust
+//! # use std::sync::Arc;
+//! # use mongodb::{
+//! #     error::Result,
+//! #     event::metrics::{ClientMetrics, MetricsRegistry},
+//! #     options::ClientOptions,
+//! # };
+//! # #[cfg(any(feature = "sync", feature = "tokio-sync"))]
+//! # use mongodb::sync::Client;
+//! # #[cfg(all(not(feature = "sync"), not(feature = "tokio-sync")))]
+//! # use mongodb::Client;
+//! #
+//! # fn do_stuff() -> Result<()> {
+//! let registry = Arc::new(MetricsRegistry::new());
+//! let mut options = ClientOptions::builder().build();
+//! ClientMetrics::new(registry.clone()).install(&mut options);
+//! let client = Client::with_options(options)?;
+//!
+//! // Do things with the client, then serve the metrics from an HTTP endpoint.
+//! let body = registry.render_prometheus();
+//! # Ok(())
+//! # }
+//! ```
+
+#[cfg(test)]
+mod test;
+
+use std::{
+    collections::BTreeMap,
+    fmt::Write,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use crate::{
+    event::{
+        cmap::{
+            CmapEventHandler,
+            ConnectionCheckedInEvent,
+            ConnectionCheckedOutEvent,
+            ConnectionCheckoutFailedEvent,
+            ConnectionCheckoutStartedEvent,
+            ConnectionClosedEvent,
+            ConnectionCreatedEvent,
+        },
+        command::{CommandEventHandler, CommandFailedEvent, CommandSucceededEvent},
+        sdam::{SdamEventHandler, ServerHeartbeatSucceededEvent},
+    },
+    options::{ClientOptions, ServerAddress},
+};
+
+/// The number of connections in a pool, including those that are checked out.
+pub const POOL_CONNECTIONS: &str = "mongodb_pool_connections";
+
+/// The number of connections that are currently checked out of a pool.
+pub const POOL_CHECKED_OUT_CONNECTIONS: &str = "mongodb_pool_checked_out_connections";
+
+/// The number of operations currently waiting to check a connection out of a pool.
+pub const POOL_WAIT_QUEUE_SIZE: &str = "mongodb_pool_wait_queue_size";
+
+/// The time it took to check a connection out of a pool.
+pub const POOL_CHECKOUT_DURATION: &str = "mongodb_pool_checkout_duration_seconds";
+
+/// The time it took for a command to complete, whether it succeeded or failed.
+pub const COMMAND_DURATION: &str = "mongodb_command_duration_seconds";
+
+/// The number of commands that failed.
+pub const COMMAND_FAILURES: &str = "mongodb_command_failures_total";
+
+/// The round-trip time of non-awaited server monitoring heartbeats.
+pub const HEARTBEAT_DURATION: &str = "mongodb_heartbeat_duration_seconds";
+
+/// The label used for the address of the server a metric refers to.
+pub const ADDRESS_LABEL: &str = "address";
+
+/// The label used for the name of the command a metric refers to.
+pub const COMMAND_LABEL: &str = "command";
+
+/// The upper bounds, in seconds, of the buckets used by [`MetricsRegistry`] histograms.
+pub const DEFAULT_BUCKETS: &[f64] = &[
+    0.001, 0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0,
+];
+
+/// A set of label names and values attached to a single metric observation.
+pub type Labels<'a> = &'a [(&'static str, &'a str)];
+
+/// Applications can implement this trait to receive the metrics recorded by a [`ClientMetrics`].
+///
+/// Metric names are the constants defined in this module. Implementations are called from the
+/// driver's event emission paths, so they should not block.
+pub trait MetricsExporter: Send + Sync {
+    /// Adds `delta` (which may be negative) to the gauge with the given name and labels.
+    fn add_to_gauge(&self, name: &'static str, labels: Labels, delta: f64);
+
+    /// Increments the counter with the given name and labels by one.
+    fn increment_counter(&self, name: &'static str, labels: Labels);
+
+    /// Records an observation of `duration` in the histogram with the given name and labels.
+    fn record_duration(&self, name: &'static str, labels: Labels, duration: Duration);
+}
+
+/// Event handler that records connection pool, command and heartbeat metrics to a
+/// [`MetricsExporter`]. See the [module-level documentation](self) for the recorded metrics.
+#[derive(Clone)]
+pub struct ClientMetrics {
+    exporter: Arc<dyn MetricsExporter>,
+}
+
+impl std::fmt::Debug for ClientMetrics {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ClientMetrics").finish()
+    }
+}
+
+impl ClientMetrics {
+    /// Creates a new `ClientMetrics` that reports to the given exporter.
+    pub fn new(exporter: Arc<dyn MetricsExporter>) -> Self {
+        Self { exporter }
+    }
+
+    /// Registers this as the CMAP, command and SDAM event handler in the given options.
+    ///
+    /// This replaces any event handlers that were previously set on the options.
+    pub fn install(self, options: &mut ClientOptions) {
+        let metrics = Arc::new(self);
+        options.cmap_event_handler = Some(metrics.clone());
+        options.command_event_handler = Some(metrics.clone());
+        options.sdam_event_handler = Some(metrics);
+    }
+
+    fn add_to_pool_gauge(&self, name: &'static str, address: &ServerAddress, delta: f64) {
+        let address = address.to_string();
+        self.exporter
+            .add_to_gauge(name, &[(ADDRESS_LABEL, address.as_str())], delta);
+    }
+}
+
+impl CmapEventHandler for ClientMetrics {
+    fn handle_connection_created_event(&self, event: ConnectionCreatedEvent) {
+        self.add_to_pool_gauge(POOL_CONNECTIONS, &event.address, 1.0);
+    }
+
+    fn handle_connection_closed_event(&self, event: ConnectionClosedEvent) {
+        self.add_to_pool_gauge(POOL_CONNECTIONS, &event.address, -1.0);
+    }
+
+    fn handle_connection_checkout_started_event(&self, event: ConnectionCheckoutStartedEvent) {
+        self.add_to_pool_gauge(POOL_WAIT_QUEUE_SIZE, &event.address, 1.0);
+    }
+
+    fn handle_connection_checkout_failed_event(&self, event: ConnectionCheckoutFailedEvent) {
+        self.add_to_pool_gauge(POOL_WAIT_QUEUE_SIZE, &event.address, -1.0);
+    }
+
+    fn handle_connection_checked_out_event(&self, event: ConnectionCheckedOutEvent) {
+        self.add_to_pool_gauge(POOL_WAIT_QUEUE_SIZE, &event.address, -1.0);
+        self.add_to_pool_gauge(POOL_CHECKED_OUT_CONNECTIONS, &event.address, 1.0);
+        let address = event.address.to_string();
+        self.exporter.record_duration(
+            POOL_CHECKOUT_DURATION,
+            &[(ADDRESS_LABEL, address.as_str())],
+            event.duration,
+        );
+    }
+
+    fn handle_connection_checked_in_event(&self, event: ConnectionCheckedInEvent) {
+        self.add_to_pool_gauge(POOL_CHECKED_OUT_CONNECTIONS, &event.address, -1.0);
+    }
+}
+
+impl CommandEventHandler for ClientMetrics {
+    fn handle_command_succeeded_event(&self, event: CommandSucceededEvent) {
+        self.exporter.record_duration(
+            COMMAND_DURATION,
+            &[(COMMAND_LABEL, event.command_name.as_str())],
+            event.duration,
+        );
+    }
+
+    fn handle_command_failed_event(&self, event: CommandFailedEvent) {
+        let labels = [(COMMAND_LABEL, event.command_name.as_str())];
+        self.exporter
+            .record_duration(COMMAND_DURATION, &labels, event.duration);
+        self.exporter.increment_counter(COMMAND_FAILURES, &labels);
+    }
+}
+
+impl SdamEventHandler for ClientMetrics {
+    fn handle_server_heartbeat_succeeded_event(&self, event: ServerHeartbeatSucceededEvent) {
+        // Awaited heartbeats include the time the server spent waiting for a topology change, so
+        // they don't reflect the round-trip time.
+        if event.awaited {
+            return;
+        }
+        let address = event.server_address.to_string();
+        self.exporter.record_duration(
+            HEARTBEAT_DURATION,
+            &[(ADDRESS_LABEL, address.as_str())],
+            event.duration,
+        );
+    }
+}
+
+type MetricKey = (&'static str, Vec<(&'static str, String)>);
+
+fn metric_key(name: &'static str, labels: Labels) -> MetricKey {
+    let mut labels: Vec<_> = labels
+        .iter()
+        .map(|(name, value)| (*name, value.to_string()))
+        .collect();
+    labels.sort();
+    (name, labels)
+}
+
+/// A snapshot of the observations recorded in a [`MetricsRegistry`] histogram.
+#[derive(Clone, Debug, PartialEq)]
+#[non_exhaustive]
+pub struct HistogramSnapshot {
+    /// The number of observations in each bucket of [`DEFAULT_BUCKETS`]. Unlike the Prometheus
+    /// representation, these counts are not cumulative.
+    pub bucket_counts: Vec<u64>,
+
+    /// The total number of observations, including those larger than the largest bucket.
+    pub count: u64,
+
+    /// The sum of all observations, in seconds.
+    pub sum: f64,
+}
+
+impl HistogramSnapshot {
+    fn new() -> Self {
+        Self {
+            bucket_counts: vec![0; DEFAULT_BUCKETS.len()],
+            count: 0,
+            sum: 0.0,
+        }
+    }
+
+    fn observe(&mut self, value: f64) {
+        if let Some(index) = DEFAULT_BUCKETS.iter().position(|bound| value <= *bound) {
+            self.bucket_counts[index] += 1;
+        }
+        self.count += 1;
+        self.sum += value;
+    }
+}
+
+#[derive(Debug, Default)]
+struct RegistryState {
+    gauges: BTreeMap<MetricKey, f64>,
+    counters: BTreeMap<MetricKey, u64>,
+    histograms: BTreeMap<MetricKey, HistogramSnapshot>,
+}
+
+/// A [`MetricsExporter`] that keeps all recorded metrics in memory.
+///
+/// The current values can be inspected individually or rendered in the Prometheus text exposition
+/// format via [`MetricsRegistry::render_prometheus`].
+#[derive(Debug, Default)]
+pub struct MetricsRegistry {
+    state: Mutex<RegistryState>,
+}
+
+impl MetricsRegistry {
+    /// Creates a new, empty registry.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Returns the current value of the gauge with the given name and labels, if it has been
+    /// recorded.
+    pub fn gauge(&self, name: &'static str, labels: Labels) -> Option<f64> {
+        let state = self.state.lock().unwrap();
+        state.gauges.get(&metric_key(name, labels)).copied()
+    }
+
+    /// Returns the current value of the counter with the given name and labels, if it has been
+    /// recorded.
+    pub fn counter(&self, name: &'static str, labels: Labels) -> Option<u64> {
+        let state = self.state.lock().unwrap();
+        state.counters.get(&metric_key(name, labels)).copied()
+    }
+
+    /// Returns the observations recorded in the histogram with the given name and labels, if any.
+    pub fn histogram(&self, name: &'static str, labels: Labels) -> Option<HistogramSnapshot> {
+        let state = self.state.lock().unwrap();
+        state.histograms.get(&metric_key(name, labels)).cloned()
+    }
+
+    /// Renders all recorded metrics in the Prometheus text exposition format.
+    pub fn render_prometheus(&self) -> String {
+        let state = self.state.lock().unwrap();
+        let mut out = String::new();
+
+        let mut previous = None;
+        for ((name, labels), value) in &state.gauges {
+            write_header(&mut out, &mut previous, name, "gauge");
+            let _ = writeln!(out, "{}{} {}", name, format_labels(labels, None), value);
+        }
+        for ((name, labels), value) in &state.counters {
+            write_header(&mut out, &mut previous, name, "counter");
+            let _ = writeln!(out, "{}{} {}", name, format_labels(labels, None), value);
+        }
+        for ((name, labels), histogram) in &state.histograms {
+            write_header(&mut out, &mut previous, name, "histogram");
+            let mut cumulative = 0;
+            for (bound, count) in DEFAULT_BUCKETS.iter().zip(&histogram.bucket_counts) {
+                cumulative += count;
+                let _ = writeln!(
+                    out,
+                    "{}_bucket{} {}",
+                    name,
+                    format_labels(labels, Some(&bound.to_string())),
+                    cumulative
+                );
+            }
+            let _ = writeln!(
+                out,
+                "{}_bucket{} {}",
+                name,
+                format_labels(labels, Some("+Inf")),
+                histogram.count
+            );
+            let labels = format_labels(labels, None);
+            let _ = writeln!(out, "{}_sum{} {}", name, labels, histogram.sum);
+            let _ = writeln!(out, "{}_count{} {}", name, labels, histogram.count);
+        }
+
+        out
+    }
+}
+
+impl MetricsExporter for MetricsRegistry {
+    fn add_to_gauge(&self, name: &'static str, labels: Labels, delta: f64) {
+        let mut state = self.state.lock().unwrap();
+        *state.gauges.entry(metric_key(name, labels)).or_default() += delta;
+    }
+
+    fn increment_counter(&self, name: &'static str, labels: Labels) {
+        let mut state = self.state.lock().unwrap();
+        *state.counters.entry(metric_key(name, labels)).or_default() += 1;
+    }
+
+    fn record_duration(&self, name: &'static str, labels: Labels, duration: Duration) {
+        let mut state = self.state.lock().unwrap();
+        state
+            .histograms
+            .entry(metric_key(name, labels))
+            .or_insert_with(HistogramSnapshot::new)
+            .observe(duration.as_secs_f64());
+    }
+}
+
+fn help(name: &str) -> &'static str {
+    match name {
+        POOL_CONNECTIONS => "The number of connections in the pool, including checked out ones.",
+        POOL_CHECKED_OUT_CONNECTIONS => "The number of connections checked out of the pool.",
+        POOL_WAIT_QUEUE_SIZE => "The number of operations waiting to check out a connection.",
+        POOL_CHECKOUT_DURATION => "The time it took to check out a connection.",
+        COMMAND_DURATION => "The time it took for a command to complete.",
+        COMMAND_FAILURES => "The number of commands that failed.",
+        HEARTBEAT_DURATION => "The round-trip time of server monitoring heartbeats.",
+        _ => "",
+    }
+}
+
+/// Writes the `# HELP` and `# TYPE` lines for a metric family if they haven't been written yet.
+/// Entries are sorted by name within each kind of metric, so only the previous name needs to be
+/// tracked.
+fn write_header(
+    out: &mut String,
+    previous: &mut Option<&'static str>,
+    name: &'static str,
+    kind: &str,
+) {
+    if *previous == Some(name) {
+        return;
+    }
+    *previous = Some(name);
+    let _ = writeln!(out, "# HELP {} {}", name, help(name));
+    let _ = writeln!(out, "# TYPE {} {}", name, kind);
+}
+
+fn format_labels(labels: &[(&'static str, String)], le: Option<&str>) -> String {
+    let mut pairs: Vec<String> = labels
+        .iter()
+        .map(|(name, value)| format!("{}=\"{}\"", name, escape_label_value(value)))
+        .collect();
+    if let Some(le) = le {
+        pairs.push(format!("le=\"{}\"", le));
+    }
+    if pairs.is_empty() {
+        String::new()
+    } else {
+        format!("{{{}}}", pairs.join(","))
+    }
+}
+
+fn escape_label_value(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
@@ -0,0 +1,183 @@
+use std::{sync::Arc, time::Duration};
+
+use crate::{
+    bson::doc,
+    error::{Error, ErrorKind},
+    event::{
+        cmap::{
+            CmapEventHandler,
+            ConnectionCheckedInEvent,
+            ConnectionCheckedOutEvent,
+            ConnectionCheckoutStartedEvent,
+            ConnectionCreatedEvent,
+        },
+        command::{CommandEventHandler, CommandFailedEvent, CommandSucceededEvent, ConnectionInfo},
+        metrics::{
+            ClientMetrics,
+            MetricsRegistry,
+            ADDRESS_LABEL,
+            COMMAND_DURATION,
+            COMMAND_FAILURES,
+            COMMAND_LABEL,
+            HEARTBEAT_DURATION,
+            POOL_CHECKED_OUT_CONNECTIONS,
+            POOL_CHECKOUT_DURATION,
+            POOL_CONNECTIONS,
+            POOL_WAIT_QUEUE_SIZE,
+        },
+        sdam::{SdamEventHandler, ServerHeartbeatSucceededEvent},
+    },
+    options::ServerAddress,
+};
+
+fn address() -> ServerAddress {
+    ServerAddress::Tcp {
+        host: "localhost".to_string(),
+        port: Some(27017),
+    }
+}
+
+fn connection_info() -> ConnectionInfo {
+    ConnectionInfo {
+        id: 1,
+        server_id: None,
+        server_id_i64: None,
+        address: address(),
+    }
+}
+
+#[test]
+fn pool_metrics() {
+    let registry = Arc::new(MetricsRegistry::new());
+    let metrics = ClientMetrics::new(registry.clone());
+    let labels = [(ADDRESS_LABEL, "localhost:27017")];
+
+    for connection_id in 1..=2 {
+        metrics.handle_connection_created_event(ConnectionCreatedEvent {
+            address: address(),
+            connection_id,
+        });
+    }
+    for _ in 0..2 {
+        metrics.handle_connection_checkout_started_event(ConnectionCheckoutStartedEvent {
+            address: address(),
+        });
+    }
+    assert_eq!(registry.gauge(POOL_WAIT_QUEUE_SIZE, &labels), Some(2.0));
+
+    metrics.handle_connection_checked_out_event(ConnectionCheckedOutEvent {
+        address: address(),
+        connection_id: 1,
+        duration: Duration::from_millis(3),
+    });
+    assert_eq!(registry.gauge(POOL_CONNECTIONS, &labels), Some(2.0));
+    assert_eq!(registry.gauge(POOL_WAIT_QUEUE_SIZE, &labels), Some(1.0));
+    assert_eq!(
+        registry.gauge(POOL_CHECKED_OUT_CONNECTIONS, &labels),
+        Some(1.0)
+    );
+
+    metrics.handle_connection_checked_in_event(ConnectionCheckedInEvent {
+        address: address(),
+        connection_id: 1,
+    });
+    assert_eq!(
+        registry.gauge(POOL_CHECKED_OUT_CONNECTIONS, &labels),
+        Some(0.0)
+    );
+
+    let checkout = registry.histogram(POOL_CHECKOUT_DURATION, &labels).unwrap();
+    assert_eq!(checkout.count, 1);
+    assert_eq!(checkout.bucket_counts[1], 1);
+}
+
+#[test]
+fn command_and_heartbeat_metrics() {
+    let registry = Arc::new(MetricsRegistry::new());
+    let metrics = ClientMetrics::new(registry.clone());
+
+    metrics.handle_command_succeeded_event(CommandSucceededEvent {
+        duration: Duration::from_millis(20),
+        reply: doc! { "ok": 1 },
+        command_name: "find".to_string(),
+        request_id: 1,
+        connection: connection_info(),
+        service_id: None,
+    });
+    metrics.handle_command_failed_event(CommandFailedEvent {
+        duration: Duration::from_secs(20),
+        command_name: "find".to_string(),
+        failure: Error::new(
+            ErrorKind::Internal {
+                message: "failed".to_string(),
+            },
+            Option::<Vec<String>>::None,
+        ),
+        request_id: 2,
+        connection: connection_info(),
+        service_id: None,
+    });
+
+    let labels = [(COMMAND_LABEL, "find")];
+    assert_eq!(registry.counter(COMMAND_FAILURES, &labels), Some(1));
+    let durations = registry.histogram(COMMAND_DURATION, &labels).unwrap();
+    assert_eq!(durations.count, 2);
+    assert_eq!(durations.bucket_counts.iter().sum::<u64>(), 1);
+    assert!((durations.sum - 20.02).abs() < 1e-9);
+
+    for awaited in [false, true] {
+        metrics.handle_server_heartbeat_succeeded_event(ServerHeartbeatSucceededEvent {
+            duration: Duration::from_millis(2),
+            reply: doc! { "ok": 1 },
+            server_address: address(),
+            awaited,
+            driver_connection_id: 1,
+            server_connection_id: None,
+        });
+    }
+    let heartbeats = registry
+        .histogram(HEARTBEAT_DURATION, &[(ADDRESS_LABEL, "localhost:27017")])
+        .unwrap();
+    assert_eq!(heartbeats.count, 1);
+}
+
+#[test]
+fn render_prometheus() {
+    let registry = Arc::new(MetricsRegistry::new());
+    let metrics = ClientMetrics::new(registry.clone());
+
+    metrics.handle_connection_created_event(ConnectionCreatedEvent {
+        address: address(),
+        connection_id: 1,
+    });
+    metrics.handle_command_failed_event(CommandFailedEvent {
+        duration: Duration::from_millis(4),
+        command_name: "insert".to_string(),
+        failure: Error::new(
+            ErrorKind::Internal {
+                message: "failed".to_string(),
+            },
+            Option::<Vec<String>>::None,
+        ),
+        request_id: 1,
+        connection: connection_info(),
+        service_id: None,
+    });
+
+    let rendered = registry.render_prometheus();
+    let lines: Vec<_> = rendered.lines().collect();
+    for expected in [
+        "# TYPE mongodb_pool_connections gauge",
+        "mongodb_pool_connections{address=\"localhost:27017\"} 1",
+        "# TYPE mongodb_command_failures_total counter",
+        "mongodb_command_failures_total{command=\"insert\"} 1",
+        "# TYPE mongodb_command_duration_seconds histogram",
+        "mongodb_command_duration_seconds_bucket{command=\"insert\",le=\"0.001\"} 0",
+        "mongodb_command_duration_seconds_bucket{command=\"insert\",le=\"0.005\"} 1",
+        "mongodb_command_duration_seconds_bucket{command=\"insert\",le=\"10\"} 1",
+        "mongodb_command_duration_seconds_bucket{command=\"insert\",le=\"+Inf\"} 1",
+        "mongodb_command_duration_seconds_count{command=\"insert\"} 1",
+    ] {
+        assert!(lines.contains(&expected), "missing {:?}", expected);
+    }
+}
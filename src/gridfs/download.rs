@@ -1,4 +1,5 @@
 use std::{
+    io::SeekFrom,
     marker::Unpin,
     ops::Range,
     pin::Pin,
@@ -7,13 +8,13 @@ use std::{
 
 use futures_util::{
     future::{BoxFuture, FutureExt},
-    io::{AsyncRead, AsyncWrite, AsyncWriteExt},
+    io::{AsyncRead, AsyncSeek, AsyncWrite, AsyncWriteExt},
 };
 
 use super::{options::GridFsDownloadByNameOptions, Chunk, FilesCollectionDocument, GridFsBucket};
 use crate::{
     bson::{doc, Bson},
-    error::{ErrorKind, GridFsErrorKind, GridFsFileIdentifier, Result},
+    error::{Error, ErrorKind, GridFsErrorKind, GridFsFileIdentifier, Result},
     options::{FindOneOptions, FindOptions},
     Collection,
     Cursor,
@@ -199,6 +200,59 @@ impl GridFsBucket {
 
         Ok(())
     }
+
+    /// Downloads the bytes in `range` of the stored file specified by `id`. Only the chunks that
+    /// overlap the range are fetched from the chunks collection.
+    ///
+    /// If the end of the range exceeds the length of the file, the returned bytes will end at the
+    /// end of the file. An error is returned if the start of the range exceeds its end.
+    ///
+    /// ```rust
+    /// # use mongodb::{bson::Bson, error::Result, gridfs::GridFsBucket};
+    /// # async fn range_example(bucket: GridFsBucket, id: Bson) -> Result<()> {
+    /// // Download the second kilobyte of the file.
+    /// let bytes = bucket.download_range(id, 1024..2048).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn download_range(&self, id: Bson, range: Range<u64>) -> Result<Vec<u8>> {
+        if range.start > range.end {
+            return Err(Error::invalid_argument(format!(
+                "invalid GridFS download range: start ({}) exceeds end ({})",
+                range.start, range.end
+            )));
+        }
+
+        let file = self.find_file_by_id(&id).await?;
+        let end = std::cmp::min(range.end, file.length);
+        if range.start >= end {
+            return Ok(Vec::new());
+        }
+
+        let chunk_size_bytes = file.chunk_size_bytes as u64;
+        let start_n = (range.start / chunk_size_bytes) as u32;
+        let end_n = FilesCollectionDocument::n_from_vals(end, file.chunk_size_bytes);
+        let cursor = self
+            .chunks()
+            .find(
+                doc! { "files_id": &file.id, "n": { "$gte": start_n, "$lt": end_n } },
+                FindOptions::builder().sort(doc! { "n": 1 }).build(),
+            )
+            .await?;
+        let (mut buffer, _) = get_bytes(
+            Box::new(cursor),
+            Vec::new(),
+            start_n..end_n,
+            file.chunk_size_bytes,
+            file.length,
+        )
+        .await?;
+
+        let offset = (range.start - start_n as u64 * chunk_size_bytes) as usize;
+        buffer.truncate(offset + (end - range.start) as usize);
+        buffer.drain(..offset);
+        Ok(buffer)
+    }
 }
 
 /// A stream from which a file stored in a GridFS bucket can be downloaded.
@@ -221,22 +275,48 @@ impl GridFsBucket {
 /// ```
 ///
 /// # Using [`tokio::io::AsyncRead`]
-/// Users who prefer to use tokio's `AsyncRead` trait can use the [`tokio_util::compat`] module.
+/// The `GridFsDownloadStream` type also implements tokio's `AsyncRead` trait, so the utility
+/// methods in [`tokio::io::AsyncReadExt`] can be used as well.
 ///
 /// ```rust
-/// # use mongodb::{bson::Bson, error::Result, gridfs::{GridFsBucket, GridFsUploadStream}};
-/// # async fn compat_example(bucket: GridFsBucket, id: Bson) -> Result<()> {
-/// use tokio_util::compat::FuturesAsyncReadCompatExt;
+/// # use mongodb::{bson::Bson, error::Result, gridfs::{GridFsBucket, GridFsDownloadStream}};
+/// # async fn tokio_example(bucket: GridFsBucket, id: Bson) -> Result<()> {
+/// use tokio::io::AsyncReadExt;
 ///
-/// let futures_upload_stream = bucket.open_download_stream(id).await?;
-/// let tokio_upload_stream = futures_upload_stream.compat();
+/// let mut buf = Vec::new();
+/// let mut download_stream = bucket.open_download_stream(id).await?;
+/// download_stream.read_to_end(&mut buf).await?;
+/// # Ok(())
+/// # }
+/// ```
+///
+/// # Seeking
+/// The stream implements both [`futures_io::AsyncSeek`] and [`tokio::io::AsyncSeek`]. Seeking
+/// discards any buffered bytes and resumes reading from the chunk containing the new position, so
+/// only the chunks from that point onwards are fetched from the chunks collection. The chunks are
+/// not fetched until the next read, so any errors encountered while doing so are returned from
+/// that read. Seeking beyond the end of the file is allowed; subsequent reads will return no
+/// bytes.
+///
+/// ```rust
+/// # use mongodb::{bson::Bson, error::Result, gridfs::{GridFsBucket, GridFsDownloadStream}};
+/// # async fn seek_example(bucket: GridFsBucket, id: Bson) -> Result<()> {
+/// use std::io::SeekFrom;
+/// use tokio::io::{AsyncReadExt, AsyncSeekExt};
+///
+/// let mut buf = vec![0u8; 1024];
+/// let mut download_stream = bucket.open_download_stream(id).await?;
+/// download_stream.seek(SeekFrom::Start(4096)).await?;
+/// download_stream.read_exact(&mut buf).await?;
 /// # Ok(())
 /// # }
 /// ```
 pub struct GridFsDownloadStream {
     state: State,
     current_n: u32,
+    position: u64,
     file: FilesCollectionDocument,
+    chunks: Collection<Chunk<'static>>,
 }
 
 type GetBytesFuture = BoxFuture<'static, Result<(Vec<u8>, Box<Cursor<Chunk<'static>>>)>>;
@@ -282,9 +362,51 @@ impl GridFsDownloadStream {
         Ok(Self {
             state: initial_state,
             current_n: 0,
+            position: 0,
             file,
+            chunks: chunks.clone(),
         })
     }
+
+    /// Moves the stream to `position`. Any buffered bytes are discarded, and the chunk containing
+    /// `position` will be fetched on the next read.
+    fn start_seek(&mut self, position: SeekFrom) -> std::io::Result<u64> {
+        let position = match position {
+            SeekFrom::Start(offset) => Some(offset),
+            SeekFrom::End(offset) => offset_position(self.file.length, offset),
+            SeekFrom::Current(offset) => offset_position(self.position, offset),
+        }
+        .ok_or_else(|| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                "invalid seek to a negative or overflowing position",
+            )
+        })?;
+
+        if position >= self.file.length {
+            self.current_n = self.file.n();
+            self.state = State::Done;
+        } else {
+            let chunk_size_bytes = self.file.chunk_size_bytes as u64;
+            let n = (position / chunk_size_bytes) as u32;
+            let skip = (position % chunk_size_bytes) as usize;
+            self.current_n = n + 1;
+            self.state = State::Busy(
+                seek_to_chunk(
+                    self.chunks.clone(),
+                    self.file.id.clone(),
+                    n,
+                    skip,
+                    self.file.chunk_size_bytes,
+                    self.file.length,
+                )
+                .boxed(),
+            );
+        }
+        self.position = position;
+
+        Ok(position)
+    }
 }
 
 impl AsyncRead for GridFsDownloadStream {
@@ -348,6 +470,7 @@ impl AsyncRead for GridFsDownloadStream {
                 } else {
                     State::Done
                 };
+                stream.position += bytes_to_write as u64;
 
                 Poll::Ready(Ok(bytes_to_write))
             }
@@ -359,6 +482,78 @@ impl AsyncRead for GridFsDownloadStream {
     }
 }
 
+impl tokio::io::AsyncRead for GridFsDownloadStream {
+    fn poll_read(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut tokio::io::ReadBuf<'_>,
+    ) -> Poll<std::io::Result<()>> {
+        let bytes_read = match AsyncRead::poll_read(self, cx, buf.initialize_unfilled()) {
+            Poll::Ready(Ok(bytes_read)) => bytes_read,
+            Poll::Ready(Err(error)) => return Poll::Ready(Err(error)),
+            Poll::Pending => return Poll::Pending,
+        };
+        buf.advance(bytes_read);
+        Poll::Ready(Ok(()))
+    }
+}
+
+impl AsyncSeek for GridFsDownloadStream {
+    fn poll_seek(
+        self: Pin<&mut Self>,
+        _cx: &mut Context<'_>,
+        position: SeekFrom,
+    ) -> Poll<std::io::Result<u64>> {
+        Poll::Ready(self.get_mut().start_seek(position))
+    }
+}
+
+impl tokio::io::AsyncSeek for GridFsDownloadStream {
+    fn start_seek(self: Pin<&mut Self>, position: SeekFrom) -> std::io::Result<()> {
+        self.get_mut().start_seek(position).map(|_| ())
+    }
+
+    fn poll_complete(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<std::io::Result<u64>> {
+        // Seeking only resets the state of the stream; the chunks at the new position are fetched
+        // by the next read.
+        Poll::Ready(Ok(self.position))
+    }
+}
+
+fn offset_position(base: u64, offset: i64) -> Option<u64> {
+    if offset >= 0 {
+        base.checked_add(offset as u64)
+    } else {
+        base.checked_sub(offset.unsigned_abs())
+    }
+}
+
+// Opens a cursor over the chunks of a file starting at chunk `n` and buffers the contents of that
+// chunk, skipping its first `skip` bytes.
+async fn seek_to_chunk(
+    chunks: Collection<Chunk<'static>>,
+    files_id: Bson,
+    n: u32,
+    skip: usize,
+    chunk_size_bytes: u32,
+    file_len: u64,
+) -> Result<(Vec<u8>, Box<Cursor<Chunk<'static>>>)> {
+    let options = FindOptions::builder().sort(doc! { "n": 1 }).build();
+    let cursor = chunks
+        .find(doc! { "files_id": files_id, "n": { "$gte": n } }, options)
+        .await?;
+    let (mut buffer, cursor) = get_bytes(
+        Box::new(cursor),
+        Vec::new(),
+        n..n + 1,
+        chunk_size_bytes,
+        file_len,
+    )
+    .await?;
+    buffer.drain(..skip);
+    Ok((buffer, cursor))
+}
+
 async fn get_bytes(
     mut cursor: Box<Cursor<Chunk<'static>>>,
     mut buffer: Vec<u8>,
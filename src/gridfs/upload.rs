@@ -299,15 +299,21 @@ where
 /// call to `close`.
 ///
 /// # Using [`tokio::io::AsyncWrite`]
-/// Users who prefer to use tokio's `AsyncWrite` trait can use the [`tokio_util::compat`] module.
+/// The `GridFsUploadStream` type also implements tokio's `AsyncWrite` trait, so the utility methods
+/// in [`tokio::io::AsyncWriteExt`] can be used as well. Calling
+/// [`shutdown`](tokio::io::AsyncWriteExt::shutdown) on the stream is equivalent to calling
+/// `close`.
 ///
 /// ```rust
-/// # use mongodb::gridfs::{GridFsBucket, GridFsUploadStream};
-/// # fn compat_example(bucket: GridFsBucket) {
-/// use tokio_util::compat::FuturesAsyncWriteCompatExt;
+/// # use mongodb::{error::Result, gridfs::{GridFsBucket, GridFsUploadStream}};
+/// # async fn tokio_example(bucket: GridFsBucket) -> Result<()> {
+/// use tokio::io::AsyncWriteExt;
 ///
-/// let futures_upload_stream = bucket.open_upload_stream("example_file", None);
-/// let tokio_upload_stream = futures_upload_stream.compat_write();
+/// let bytes = vec![0u8; 100];
+/// let mut upload_stream = bucket.open_upload_stream("example_file", None);
+/// upload_stream.write_all(&bytes[..]).await?;
+/// upload_stream.shutdown().await?;
+/// # Ok(())
 /// # }
 /// ```
 pub struct GridFsUploadStream {
@@ -492,6 +498,24 @@ impl AsyncWrite for GridFsUploadStream {
     }
 }
 
+impl tokio::io::AsyncWrite for GridFsUploadStream {
+    fn poll_write(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<tokio::io::Result<usize>> {
+        AsyncWrite::poll_write(self, cx, buf)
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<tokio::io::Result<()>> {
+        AsyncWrite::poll_flush(self, cx)
+    }
+
+    fn poll_shutdown(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<tokio::io::Result<()>> {
+        AsyncWrite::poll_close(self, cx)
+    }
+}
+
 // Writes the data in the buffer to the database and returns the number of chunks written and any
 // leftover bytes that didn't fill an entire chunk.
 async fn write_bytes(
@@ -56,6 +56,9 @@
 //! | `sync`                       | Expose the synchronous API (`mongodb::sync`), using an async-std backend. Cannot be used with the `tokio-runtime` feature flag.                                         | no      |
 //! | `tokio-sync`                 | Expose the synchronous API (`mongodb::sync`), using a tokio backend. Cannot be used with the `async-std-runtime` feature flag.                                          | no      |
 //! | `aws-auth`                   | Enable support for the MONGODB-AWS authentication mechanism.                                                                                                            | no      |
+//! | `gssapi-auth`                | Enable support for the GSSAPI (Kerberos) authentication mechanism. This requires the system GSSAPI library (e.g. MIT Kerberos) on Unix.                                 | no      |
+//! | `mock-server`                | Enable the in-process mock server (`mongodb::mock_server`) for testing applications without a MongoDB deployment.                                                       | no      |
+//! | `metrics`                    | Enable `mongodb::event::metrics`, which records connection pool, command and heartbeat metrics from the driver's events.                                                | no      |
 //! | `bson-uuid-0_8`              | Enable support for v0.8 of the [`uuid`](docs.rs/uuid/0.8) crate in the public API of the re-exported `bson` crate.                                                      | no      |
 //! | `bson-uuid-1`                | Enable support for v1.x of the [`uuid`](docs.rs/uuid/1.0) crate in the public API of the re-exported `bson` crate.                                                      | no      |
 //! | `bson-chrono-0_4`            | Enable support for v0.4 of the [`chrono`](docs.rs/chrono/0.4) crate in the public API of the re-exported `bson` crate.                                                  | no      |
@@ -64,6 +67,7 @@
 //! | `zstd-compression`           | Enable support for compressing messages with [`zstd`](http://facebook.github.io/zstd/).                                                                                 | no      |
 //! | `snappy-compression`         | Enable support for compressing messages with [`snappy`](http://google.github.io/snappy/).                                                                               | no      |
 //! | `openssl-tls`                | Switch TLS connection handling to use [`openssl`](https://docs.rs/openssl/0.10.38/).                                                                                    | no      |
+//! | `rustls-tls`                 | Enable support for encrypted private keys when TLS connections are handled with `rustls`, i.e. when `openssl-tls` is not enabled.                                       | yes     |
 //! | `in-use-encryption-unstable` | Enable support for client-side field level encryption and queryable encryption. This API is unstable and may be subject to breaking changes in minor releases.          | no      |
 //! | `tracing-unstable`           | Enable support for emitting [`tracing`](https://docs.rs/tracing/latest/tracing/) events. This API is unstable and may be subject to breaking changes in minor releases. | no      |
 //!
@@ -311,6 +315,9 @@
 #[cfg(all(feature = "aws-auth", feature = "async-std-runtime"))]
 compile_error!("The `aws-auth` feature flag is only supported on the tokio runtime.");
 
+#[cfg(all(feature = "mock-server", feature = "async-std-runtime"))]
+compile_error!("The `mock-server` feature flag is only supported on the tokio runtime.");
+
 #[macro_use]
 pub mod options;
 
@@ -334,10 +341,14 @@ pub mod gridfs;
 mod hello;
 pub(crate) mod id_set;
 mod index;
+#[cfg(feature = "mock-server")]
+pub mod mock_server;
 mod operation;
+pub mod pipeline;
 pub mod results;
 pub(crate) mod runtime;
 mod sdam;
+mod search_index;
 mod selection_criteria;
 mod serde_util;
 mod srv;
@@ -355,6 +366,7 @@ pub use crate::{
     client::{session::ClientSession, Client},
     coll::Collection,
     cursor::{
+        resumable::ResumableCursor,
         session::{SessionCursor, SessionCursorStream},
         Cursor,
     },
@@ -362,7 +374,13 @@ pub use crate::{
     gridfs::{GridFsBucket, GridFsDownloadStream, GridFsUploadStream},
 };
 
-pub use {client::session::ClusterTime, coll::Namespace, index::IndexModel, sdam::public::*};
+pub use {
+    client::session::ClusterTime,
+    coll::Namespace,
+    index::IndexModel,
+    sdam::public::*,
+    search_index::{SearchIndexModel, SearchIndexType},
+};
 
 #[cfg(all(feature = "tokio-runtime", feature = "sync",))]
 compile_error!(
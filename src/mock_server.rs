@@ -0,0 +1,455 @@
+//! Contains an in-process mock server for testing applications without a running MongoDB
+//! deployment.
+//!
+//! A [`MockServer`] listens on a local port and speaks the `OP_MSG` wire protocol. It answers the
+//! `hello` handshake and heartbeats with a configurable topology and responds to all other
+//! commands according to the [`MockResponse`]s that have been added to it, so a [`Client`] created
+//! with [`Client::with_uri_str`] using [`MockServer::uri`] can be exercised end to end.
+//!
+//! ```rust
+//! # use mongodb::{bson::doc, error::Result, Client};
+//! # async fn mock_example() -> Result<()> {
+//! use mongodb::mock_server::{MockReply, MockResponse, MockServer};
+//!
+//! let server = MockServer::start(None).await?;
+//! server.add_response(
+//!     MockResponse::builder()
+//!         .command_name("find")
+//!         .reply(MockReply::Document(doc! {
+//!             "cursor": { "id": 0_i64, "ns": "db.coll", "firstBatch": [{ "x": 1 }] },
+//!         }))
+//!         .build(),
+//! );
+//! // Fail the next insert as a `failCommand` fail point would.
+//! server.add_response(
+//!     MockResponse::builder()
+//!         .command_name("insert")
+//!         .reply(MockReply::error(11600, "interrupted at shutdown"))
+//!         .times(1)
+//!         .build(),
+//! );
+//!
+//! let client = Client::with_uri_str(server.uri()).await?;
+//! let coll = client.database("db").collection::<mongodb::bson::Document>("coll");
+//! let doc = coll.find_one(None, None).await?;
+//! assert_eq!(doc, Some(doc! { "x": 1 }));
+//! assert!(coll.insert_one(doc! {}, None).await.is_err());
+//! # Ok(())
+//! # }
+//! ```
+
+use std::{
+    net::{Ipv4Addr, SocketAddr},
+    sync::{
+        atomic::{AtomicI64, Ordering},
+        Arc,
+        Mutex,
+    },
+    time::Duration,
+};
+
+use tokio::{net::TcpStream, sync::watch};
+use typed_builder::TypedBuilder;
+
+#[cfg(doc)]
+use crate::Client;
+use crate::{
+    bson::{doc, oid::ObjectId, Bson, DateTime, Document},
+    cmap::conn::wire::{Message, MessageFlags, MessageSection},
+    error::{Error, Result},
+    hello::{LEGACY_HELLO_COMMAND_NAME, LEGACY_HELLO_COMMAND_NAME_LOWERCASE},
+    options::ServerAddress,
+    runtime,
+};
+
+/// The maximum wire version reported by a [`MockServer`] by default, corresponding to MongoDB 6.0.
+const DEFAULT_MAX_WIRE_VERSION: i32 = 17;
+
+/// The topology a [`MockServer`] reports itself as being part of in its `hello` responses.
+#[derive(Clone, Debug, PartialEq)]
+#[non_exhaustive]
+pub enum MockTopology {
+    /// A standalone server.
+    Standalone,
+
+    /// The primary of a single-member replica set with the given name.
+    ReplicaSetPrimary {
+        /// The name of the replica set.
+        set_name: String,
+    },
+
+    /// A mongos router of a sharded cluster.
+    Mongos,
+}
+
+impl Default for MockTopology {
+    fn default() -> Self {
+        Self::Standalone
+    }
+}
+
+/// Specifies the options to a [`MockServer`].
+#[derive(Clone, Debug, Default, TypedBuilder)]
+#[builder(field_defaults(default, setter(into)))]
+#[non_exhaustive]
+pub struct MockServerOptions {
+    /// The topology the server reports in its `hello` responses.
+    ///
+    /// Defaults to [`MockTopology::Standalone`].
+    pub topology: Option<MockTopology>,
+
+    /// The maximum wire version the server reports in its `hello` responses.
+    ///
+    /// Defaults to 17 (MongoDB 6.0).
+    pub max_wire_version: Option<i32>,
+
+    /// Additional fields to include in the server's `hello` responses. These take precedence over
+    /// the fields generated from the topology.
+    pub hello_fields: Option<Document>,
+}
+
+/// The reply sent by a [`MockServer`] for a command matching a [`MockResponse`].
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub enum MockReply {
+    /// Reply with the given document. An `ok: 1` field is added if the document does not contain
+    /// an `ok` field.
+    Document(Document),
+
+    /// Reply with a command error.
+    Error {
+        /// The error code.
+        code: i32,
+
+        /// The error message.
+        message: String,
+
+        /// The error labels to attach to the error.
+        labels: Vec<String>,
+    },
+
+    /// Close the connection without replying, simulating a network error.
+    CloseConnection,
+}
+
+impl MockReply {
+    /// Creates a [`MockReply::Error`] with the given code and message and no error labels.
+    pub fn error(code: i32, message: impl Into<String>) -> Self {
+        Self::Error {
+            code,
+            message: message.into(),
+            labels: Vec::new(),
+        }
+    }
+
+    fn into_document(self) -> Option<Document> {
+        match self {
+            Self::Document(mut document) => {
+                if !document.contains_key("ok") {
+                    document.insert("ok", 1);
+                }
+                Some(document)
+            }
+            Self::Error {
+                code,
+                message,
+                labels,
+            } => {
+                let mut document = doc! {
+                    "ok": 0,
+                    "code": code,
+                    "errmsg": message,
+                };
+                if !labels.is_empty() {
+                    document.insert("errorLabels", labels);
+                }
+                Some(document)
+            }
+            Self::CloseConnection => None,
+        }
+    }
+}
+
+/// A scripted response to commands received by a [`MockServer`].
+///
+/// When a command is received, the responses added to the server are checked in the order they
+/// were added, and the first one whose `command_name` matches and that has not been exhausted is
+/// used.
+#[derive(Clone, Debug, TypedBuilder)]
+#[builder(field_defaults(setter(into)))]
+#[non_exhaustive]
+pub struct MockResponse {
+    /// The name of the command to respond to, e.g. "find".
+    pub command_name: String,
+
+    /// The reply to send.
+    pub reply: MockReply,
+
+    /// The number of times this response should be used. If unset, it will be used for every
+    /// matching command.
+    #[builder(default)]
+    pub times: Option<u32>,
+
+    /// An amount of time to wait before replying, similar to the `blockConnection` option of the
+    /// `failCommand` fail point.
+    #[builder(default)]
+    pub delay: Option<Duration>,
+}
+
+/// An in-process server that speaks the MongoDB wire protocol. See the [module-level
+/// documentation](crate::mock_server) for an example.
+///
+/// The server stops listening and closes all of its connections when it is dropped.
+#[derive(Debug)]
+pub struct MockServer {
+    address: ServerAddress,
+    state: Arc<State>,
+    _shutdown: watch::Sender<()>,
+}
+
+#[derive(Debug)]
+struct State {
+    address: ServerAddress,
+    options: MockServerOptions,
+    next_connection_id: AtomicI64,
+    responses: Mutex<Vec<MockResponse>>,
+    commands: Mutex<Vec<Document>>,
+}
+
+impl MockServer {
+    /// Starts a new server listening on an unused port on localhost.
+    pub async fn start(options: impl Into<Option<MockServerOptions>>) -> Result<Self> {
+        let listener = std::net::TcpListener::bind(SocketAddr::from((Ipv4Addr::LOCALHOST, 0)))?;
+        listener.set_nonblocking(true)?;
+        let address = ServerAddress::Tcp {
+            host: Ipv4Addr::LOCALHOST.to_string(),
+            port: Some(listener.local_addr()?.port()),
+        };
+
+        let state = Arc::new(State {
+            address: address.clone(),
+            options: options.into().unwrap_or_default(),
+            next_connection_id: AtomicI64::new(1),
+            responses: Mutex::new(Vec::new()),
+            commands: Mutex::new(Vec::new()),
+        });
+        let (shutdown_sender, shutdown_receiver) = watch::channel(());
+        runtime::execute(accept_loop(listener, state.clone(), shutdown_receiver));
+
+        Ok(Self {
+            address,
+            state,
+            _shutdown: shutdown_sender,
+        })
+    }
+
+    /// The address the server is listening on.
+    pub fn address(&self) -> &ServerAddress {
+        &self.address
+    }
+
+    /// A connection string that can be used to connect a [`Client`] to the server.
+    pub fn uri(&self) -> String {
+        format!("mongodb://{}/", self.address)
+    }
+
+    /// Adds a scripted response for commands received by the server.
+    pub fn add_response(&self, response: MockResponse) {
+        self.state.responses.lock().unwrap().push(response);
+    }
+
+    /// Removes all of the scripted responses that have been added to the server.
+    pub fn clear_responses(&self) {
+        self.state.responses.lock().unwrap().clear();
+    }
+
+    /// Returns the commands received by the server so far in the order they were received,
+    /// excluding `hello` commands used for connection handshakes and monitoring. Commands sent
+    /// with document sequences have the sequences included as array fields of the command.
+    pub fn received_commands(&self) -> Vec<Document> {
+        self.state.commands.lock().unwrap().clone()
+    }
+}
+
+async fn accept_loop(
+    listener: std::net::TcpListener,
+    state: Arc<State>,
+    mut shutdown: watch::Receiver<()>,
+) {
+    let listener = match tokio::net::TcpListener::from_std(listener) {
+        Ok(listener) => listener,
+        Err(_) => return,
+    };
+    loop {
+        tokio::select! {
+            result = listener.accept() => {
+                if let Ok((stream, _)) = result {
+                    runtime::execute(handle_connection(stream, state.clone(), shutdown.clone()));
+                }
+            }
+            _ = shutdown.changed() => return,
+        }
+    }
+}
+
+async fn handle_connection(
+    mut stream: TcpStream,
+    state: Arc<State>,
+    mut shutdown: watch::Receiver<()>,
+) {
+    let connection_id = state.next_connection_id.fetch_add(1, Ordering::SeqCst);
+    loop {
+        let message = tokio::select! {
+            result = Message::read_from(&mut stream, None) => match result {
+                Ok(message) => message,
+                Err(_) => return,
+            },
+            _ = shutdown.changed() => return,
+        };
+        let request_id = message.request_id;
+        let more_to_come = message.flags.contains(MessageFlags::MORE_TO_COME);
+
+        let (reply, delay) = match command_document(message) {
+            Ok(command) => state.reply(command, connection_id),
+            Err(error) => (MockReply::error(2, error.to_string()), None),
+        };
+        if let Some(delay) = delay {
+            tokio::time::sleep(delay).await;
+        }
+        let document = match reply.into_document() {
+            Some(document) => document,
+            None => return,
+        };
+        if more_to_come {
+            continue;
+        }
+
+        let response = match bson::to_vec(&document) {
+            Ok(bytes) => Message {
+                response_to: request_id.unwrap_or(0),
+                flags: MessageFlags::empty(),
+                sections: vec![MessageSection::Document(bytes)],
+                checksum: None,
+                request_id: None,
+            },
+            Err(_) => return,
+        };
+        if response.write_to(&mut stream).await.is_err() {
+            return;
+        }
+    }
+}
+
+/// Reconstructs the command document from the sections of a message.
+fn command_document(message: Message) -> Result<Document> {
+    let mut command = None;
+    let mut sequences = Vec::new();
+    for section in message.sections {
+        match section {
+            MessageSection::Document(bytes) => {
+                command = Some(Document::from_reader(bytes.as_slice())?);
+            }
+            MessageSection::Sequence {
+                identifier,
+                documents,
+                ..
+            } => {
+                let documents = documents
+                    .iter()
+                    .map(|bytes| Document::from_reader(bytes.as_slice()).map(Bson::Document))
+                    .collect::<std::result::Result<Vec<_>, _>>()?;
+                sequences.push((identifier, documents));
+            }
+        }
+    }
+
+    let mut command = command
+        .ok_or_else(|| Error::invalid_argument("message does not contain a command document"))?;
+    for (identifier, documents) in sequences {
+        command.insert(identifier, documents);
+    }
+    Ok(command)
+}
+
+impl State {
+    fn reply(&self, command: Document, connection_id: i64) -> (MockReply, Option<Duration>) {
+        let name = command.keys().next().cloned().unwrap_or_default();
+
+        let mut responses = self.responses.lock().unwrap();
+        if let Some(index) = responses
+            .iter()
+            .position(|response| response.command_name == name && response.times != Some(0))
+        {
+            let response = &mut responses[index];
+            if let Some(ref mut times) = response.times {
+                *times -= 1;
+            }
+            let reply = (response.reply.clone(), response.delay);
+            if response.times == Some(0) {
+                responses.remove(index);
+            }
+            drop(responses);
+            self.record(name.as_str(), command);
+            return reply;
+        }
+        drop(responses);
+
+        let reply = match name.as_str() {
+            "hello" | LEGACY_HELLO_COMMAND_NAME | LEGACY_HELLO_COMMAND_NAME_LOWERCASE => {
+                MockReply::Document(self.hello_response(connection_id))
+            }
+            "ping" | "endSessions" | "killCursors" => MockReply::Document(doc! {}),
+            "buildInfo" | "buildinfo" => MockReply::Document(doc! { "version": "6.0.0" }),
+            other => MockReply::error(59, format!("no such command: '{}'", other)),
+        };
+        self.record(name.as_str(), command);
+        (reply, None)
+    }
+
+    fn record(&self, name: &str, command: Document) {
+        if !matches!(
+            name,
+            "hello" | LEGACY_HELLO_COMMAND_NAME | LEGACY_HELLO_COMMAND_NAME_LOWERCASE
+        ) {
+            self.commands.lock().unwrap().push(command);
+        }
+    }
+
+    fn hello_response(&self, connection_id: i64) -> Document {
+        let mut response = doc! {
+            "helloOk": true,
+            "isWritablePrimary": true,
+            "ismaster": true,
+            "maxBsonObjectSize": 16 * 1024 * 1024,
+            "maxMessageSizeBytes": 48_000_000,
+            "maxWriteBatchSize": 100_000,
+            "localTime": DateTime::now(),
+            "logicalSessionTimeoutMinutes": 30,
+            "connectionId": connection_id,
+            "minWireVersion": 0,
+            "maxWireVersion": self.options.max_wire_version.unwrap_or(DEFAULT_MAX_WIRE_VERSION),
+        };
+
+        let address = self.address.to_string();
+        match self.options.topology.clone().unwrap_or_default() {
+            MockTopology::Standalone => {}
+            MockTopology::ReplicaSetPrimary { set_name } => {
+                response.insert("setName", set_name);
+                response.insert("setVersion", 1);
+                response.insert("secondary", false);
+                response.insert("hosts", vec![address.clone()]);
+                response.insert("primary", address.clone());
+                response.insert("me", address);
+                response.insert("electionId", ObjectId::from_bytes([0x7f; 12]));
+            }
+            MockTopology::Mongos => {
+                response.insert("msg", "isdbgrid");
+            }
+        }
+
+        if let Some(ref fields) = self.options.hello_fields {
+            response.extend(fields.clone());
+        }
+        response
+    }
+}
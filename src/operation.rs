@@ -1,15 +1,19 @@
 mod abort_transaction;
 mod aggregate;
+mod bulk_write;
+mod coll_mod;
 mod commit_transaction;
 mod count;
 mod count_documents;
 mod create;
 mod create_indexes;
+mod db_stats;
 mod delete;
 mod distinct;
 mod drop_collection;
 mod drop_database;
 mod drop_indexes;
+mod explain;
 mod find;
 mod find_and_modify;
 mod get_more;
@@ -18,14 +22,23 @@ mod list_collections;
 mod list_databases;
 mod list_indexes;
 mod raw_output;
+mod rename_collection;
 mod run_command;
 mod run_cursor_command;
+mod search_index;
+mod server_status;
 mod update;
+mod user_management;
 
 #[cfg(test)]
 mod test;
 
-use std::{collections::VecDeque, fmt::Debug, ops::Deref};
+use std::{
+    collections::VecDeque,
+    fmt::Debug,
+    ops::Deref,
+    time::{Duration, Instant},
+};
 
 use bson::{RawBsonRef, RawDocument, RawDocumentBuf, Timestamp};
 use serde::{de::DeserializeOwned, Deserialize, Serialize};
@@ -46,22 +59,28 @@ use crate::{
         WriteFailure,
     },
     options::WriteConcern,
+    results::BulkWriteResult,
+    sdam::TopologyDescription,
     selection_criteria::SelectionCriteria,
     Namespace,
 };
 
 pub(crate) use abort_transaction::AbortTransaction;
-pub(crate) use aggregate::{Aggregate, AggregateTarget, ChangeStreamAggregate};
+pub(crate) use aggregate::{is_out_or_merge, Aggregate, AggregateTarget, ChangeStreamAggregate};
+pub(crate) use bulk_write::{BulkWrite, SingleWriteResult};
+pub(crate) use coll_mod::CollMod;
 pub(crate) use commit_transaction::CommitTransaction;
 pub(crate) use count::Count;
 pub(crate) use count_documents::CountDocuments;
 pub(crate) use create::Create;
 pub(crate) use create_indexes::CreateIndexes;
-pub(crate) use delete::Delete;
+pub(crate) use db_stats::DbStats;
+pub(crate) use delete::{BulkDelete, Delete, DeleteStatement};
 pub(crate) use distinct::Distinct;
 pub(crate) use drop_collection::DropCollection;
 pub(crate) use drop_database::DropDatabase;
 pub(crate) use drop_indexes::DropIndexes;
+pub(crate) use explain::Explain;
 pub(crate) use find::Find;
 pub(crate) use find_and_modify::FindAndModify;
 pub(crate) use get_more::GetMore;
@@ -71,12 +90,31 @@ pub(crate) use list_databases::ListDatabases;
 pub(crate) use list_indexes::ListIndexes;
 #[cfg(feature = "in-use-encryption-unstable")]
 pub(crate) use raw_output::RawOutput;
+pub(crate) use rename_collection::RenameCollection;
 pub(crate) use run_command::RunCommand;
 pub(crate) use run_cursor_command::RunCursorCommand;
-pub(crate) use update::{Update, UpdateOrReplace};
+pub(crate) use search_index::{CreateSearchIndexes, DropSearchIndex, UpdateSearchIndex};
+pub(crate) use server_status::ServerStatus;
+pub(crate) use update::{BulkUpdate, Update, UpdateOrReplace, UpdateStatement};
+pub(crate) use user_management::{
+    CreateRole,
+    CreateUser,
+    DropRole,
+    DropUser,
+    GrantRolesToUser,
+    RolesInfo,
+    UpdateUser,
+    UsersInfo,
+};
 
 const SERVER_4_2_0_WIRE_VERSION: i32 = 8;
 const SERVER_4_4_0_WIRE_VERSION: i32 = 9;
+const SERVER_5_0_0_WIRE_VERSION: i32 = 13;
+
+/// A function that can replace an operation's selection criteria based on the state of the
+/// topology at the time of server selection. Returning `None` keeps the original criteria.
+pub(crate) type OverrideCriteriaFn =
+    fn(&SelectionCriteria, &TopologyDescription) -> Option<SelectionCriteria>;
 
 /// A trait modeling the behavior of a server side operation.
 ///
@@ -118,6 +156,9 @@ pub(crate) trait Operation {
     /// Criteria to use for selecting the server that this operation will be executed on.
     fn selection_criteria(&self) -> Option<&SelectionCriteria>;
 
+    /// Allows the operation to adjust its selection criteria based on the topology.
+    fn override_criteria(&self) -> OverrideCriteriaFn;
+
     /// Whether or not this operation will request acknowledgment from the server.
     fn is_acknowledged(&self) -> bool;
 
@@ -133,11 +174,25 @@ pub(crate) trait Operation {
     /// The level of retryability the operation supports.
     fn retryability(&self) -> Retryability;
 
+    /// Whether the command built for a connection with the given description can be retried as a
+    /// write. This lets operations that split their writes into batches only consider the writes
+    /// that are actually sent.
+    fn can_retry_write(&self, description: &StreamDescription) -> bool;
+
     /// Updates this operation as needed for a retry.
     fn update_for_retry(&mut self);
 
     fn pinned_connection(&self) -> Option<&PinnedConnectionHandle>;
 
+    /// The maximum amount of time this operation may take, including server selection,
+    /// connection checkout and any retries.
+    fn timeout(&self) -> Option<Duration>;
+
+    /// The point in time by which this operation must complete. This takes precedence over
+    /// [`Operation::timeout`] and is used by operations that continue the work of an earlier one,
+    /// such as `getMore`.
+    fn deadline(&self) -> Option<Instant>;
+
     fn name(&self) -> &str;
 }
 
@@ -237,6 +292,21 @@ pub(crate) fn append_options<T: Serialize + Debug>(
     Ok(())
 }
 
+/// Removes the fields the server includes in command responses regardless of the command, so that
+/// the rest of the response can be deserialized into a type with a catch-all field.
+pub(crate) fn remove_response_metadata(response: &mut Document) {
+    for key in [
+        "ok",
+        "$clusterTime",
+        "operationTime",
+        "$gleStats",
+        "lastCommittedOpTime",
+        "$configServerState",
+    ] {
+        response.remove(key);
+    }
+}
+
 pub(crate) fn append_options_to_raw_document<T: Serialize>(
     doc: &mut RawDocumentBuf,
     options: Option<&T>,
@@ -300,6 +370,7 @@ impl<T> WriteResponseBody<T> {
             write_errors: self.write_errors.clone(),
             write_concern_error: self.write_concern_error.clone(),
             inserted_ids: Default::default(),
+            partial_result: None,
         };
 
         Err(Error::new(
@@ -307,6 +378,17 @@ impl<T> WriteResponseBody<T> {
             self.labels.clone(),
         ))
     }
+
+    /// Like [`validate`](Self::validate), but attaches `partial_result`, which describes the
+    /// writes that were performed, to the returned error.
+    fn validate_with_partial_result(&self, partial_result: BulkWriteResult) -> Result<()> {
+        self.validate().map_err(|mut error| {
+            if let ErrorKind::BulkWrite(ref mut failure) = *error.kind {
+                failure.partial_result = Some(partial_result);
+            }
+            error
+        })
+    }
 }
 
 impl<T> Deref for WriteResponseBody<T> {
@@ -441,6 +523,11 @@ pub(crate) trait OperationWithDefaults {
         None
     }
 
+    /// Allows the operation to adjust its selection criteria based on the topology.
+    fn override_criteria(&self) -> OverrideCriteriaFn {
+        |_, _| None
+    }
+
     /// Whether or not this operation will request acknowledgment from the server.
     fn is_acknowledged(&self) -> bool {
         self.write_concern()
@@ -468,6 +555,13 @@ pub(crate) trait OperationWithDefaults {
         Retryability::None
     }
 
+    /// Whether the command built for a connection with the given description can be retried as a
+    /// write. This lets operations that split their writes into batches only consider the writes
+    /// that are actually sent.
+    fn can_retry_write(&self, _description: &StreamDescription) -> bool {
+        true
+    }
+
     /// Updates this operation as needed for a retry.
     fn update_for_retry(&mut self) {}
 
@@ -475,6 +569,18 @@ pub(crate) trait OperationWithDefaults {
         None
     }
 
+    /// The maximum amount of time this operation may take, including server selection,
+    /// connection checkout and any retries.
+    fn timeout(&self) -> Option<Duration> {
+        None
+    }
+
+    /// The point in time by which this operation must complete. This takes precedence over
+    /// [`OperationWithDefaults::timeout`].
+    fn deadline(&self) -> Option<Instant> {
+        None
+    }
+
     fn name(&self) -> &str {
         Self::NAME
     }
@@ -506,6 +612,9 @@ impl<T: OperationWithDefaults> Operation for T {
     fn selection_criteria(&self) -> Option<&SelectionCriteria> {
         self.selection_criteria()
     }
+    fn override_criteria(&self) -> OverrideCriteriaFn {
+        self.override_criteria()
+    }
     fn is_acknowledged(&self) -> bool {
         self.is_acknowledged()
     }
@@ -521,12 +630,21 @@ impl<T: OperationWithDefaults> Operation for T {
     fn retryability(&self) -> Retryability {
         self.retryability()
     }
+    fn can_retry_write(&self, description: &StreamDescription) -> bool {
+        self.can_retry_write(description)
+    }
     fn update_for_retry(&mut self) {
         self.update_for_retry()
     }
     fn pinned_connection(&self) -> Option<&PinnedConnectionHandle> {
         self.pinned_connection()
     }
+    fn timeout(&self) -> Option<Duration> {
+        self.timeout()
+    }
+    fn deadline(&self) -> Option<Instant> {
+        self.deadline()
+    }
     fn name(&self) -> &str {
         self.name()
     }
@@ -3,6 +3,8 @@ mod change_stream;
 #[cfg(test)]
 mod test;
 
+use std::time::Duration;
+
 use crate::{
     bson::{doc, Bson, Document},
     bson_util,
@@ -10,16 +12,19 @@ use crate::{
     cursor::CursorSpecification,
     error::Result,
     operation::{append_options, remove_empty_write_concern, Retryability},
-    options::{AggregateOptions, SelectionCriteria, WriteConcern},
+    options::{AggregateOptions, ReadPreference, SelectionCriteria, WriteConcern},
+    sdam::{TopologyDescription, TopologyType},
     Namespace,
 };
 
 use super::{
     CursorBody,
     OperationWithDefaults,
+    OverrideCriteriaFn,
     WriteConcernOnlyBody,
     SERVER_4_2_0_WIRE_VERSION,
     SERVER_4_4_0_WIRE_VERSION,
+    SERVER_5_0_0_WIRE_VERSION,
 };
 
 pub(crate) use change_stream::ChangeStreamAggregate;
@@ -133,6 +138,14 @@ impl OperationWithDefaults for Aggregate {
             .and_then(|opts| opts.selection_criteria.as_ref())
     }
 
+    fn override_criteria(&self) -> OverrideCriteriaFn {
+        if self.is_out_or_merge() {
+            out_or_merge_criteria
+        } else {
+            |_, _| None
+        }
+    }
+
     fn supports_read_concern(&self, description: &StreamDescription) -> bool {
         // for aggregates that write, read concern is only supported in MongoDB 4.2+.
         !self.is_out_or_merge()
@@ -145,6 +158,10 @@ impl OperationWithDefaults for Aggregate {
             .and_then(|opts| opts.write_concern.as_ref())
     }
 
+    fn timeout(&self) -> Option<Duration> {
+        self.options.as_ref().and_then(|opts| opts.timeout)
+    }
+
     fn retryability(&self) -> Retryability {
         if self.is_out_or_merge() {
             Retryability::None
@@ -157,13 +174,42 @@ impl OperationWithDefaults for Aggregate {
 impl Aggregate {
     /// Returns whether this is a $out or $merge aggregation operation.
     fn is_out_or_merge(&self) -> bool {
-        self.pipeline
-            .last()
-            .map(|stage| {
-                let stage = bson_util::first_key(stage);
-                stage == Some("$out") || stage == Some("$merge")
-            })
-            .unwrap_or(false)
+        is_out_or_merge(&self.pipeline)
+    }
+}
+
+/// Returns whether the final stage of the given pipeline is $out or $merge.
+pub(crate) fn is_out_or_merge(pipeline: &[Document]) -> bool {
+    pipeline
+        .last()
+        .map(|stage| {
+            let stage = bson_util::first_key(stage);
+            stage == Some("$out") || stage == Some("$merge")
+        })
+        .unwrap_or(false)
+}
+
+/// Aggregations that write can only be routed to secondaries by 5.0+ servers, so fall back to the
+/// primary if any available server in the topology is older.
+fn out_or_merge_criteria(
+    criteria: &SelectionCriteria,
+    topology: &TopologyDescription,
+) -> Option<SelectionCriteria> {
+    if matches!(
+        criteria,
+        SelectionCriteria::ReadPreference(ReadPreference::Primary)
+    ) || topology.topology_type() == TopologyType::LoadBalanced
+    {
+        return None;
+    }
+    let any_pre_5_0 = topology.servers.values().any(|server| {
+        server.is_available()
+            && server.max_wire_version().ok().flatten().unwrap_or(0) < SERVER_5_0_0_WIRE_VERSION
+    });
+    if any_pre_5_0 {
+        Some(SelectionCriteria::ReadPreference(ReadPreference::Primary))
+    } else {
+        None
     }
 }
 
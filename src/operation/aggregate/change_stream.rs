@@ -4,7 +4,7 @@ use crate::{
     cmap::{Command, RawCommandResponse, StreamDescription},
     cursor::CursorSpecification,
     error::Result,
-    operation::{append_options, OperationWithDefaults, Retryability},
+    operation::{append_options, OperationWithDefaults, OverrideCriteriaFn, Retryability},
     options::{ChangeStreamOptions, SelectionCriteria, WriteConcern},
 };
 
@@ -117,6 +117,10 @@ impl OperationWithDefaults for ChangeStreamAggregate {
         self.inner.selection_criteria()
     }
 
+    fn override_criteria(&self) -> OverrideCriteriaFn {
+        self.inner.override_criteria()
+    }
+
     fn supports_read_concern(&self, description: &StreamDescription) -> bool {
         self.inner.supports_read_concern(description)
     }
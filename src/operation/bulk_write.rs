@@ -0,0 +1,484 @@
+#[cfg(test)]
+mod test;
+
+use std::{collections::HashMap, convert::TryFrom, time::Duration};
+
+use serde::Deserialize;
+
+use crate::{
+    bson::{rawdoc, Bson, Document, RawArrayBuf, RawBson, RawDocumentBuf},
+    bson_util,
+    cmap::{Command, DocumentSequence, RawCommandResponse, StreamDescription},
+    cursor::CursorSpecification,
+    error::{ClientBulkWriteFailure, ErrorKind, Result, WriteConcernError, WriteError},
+    operation::{CursorInfo, OperationWithDefaults, Retryability},
+    options::{BulkWriteOptions, UpdateModifications, WriteConcern, WriteModel},
+    results::{BulkWriteResult, DeleteResult, InsertOneResult, UpdateResult},
+    serde_util,
+    Namespace,
+};
+
+/// The number of bytes reserved in each message for the fields added to the command document
+/// after it has been built (e.g. `lsid`, `txnNumber` and `$clusterTime`).
+const COMMAND_OVERHEAD_SIZE_BYTES: usize = 1_000;
+
+/// Performs as many of the writes in `models`, starting at `offset`, as fit into a single
+/// `bulkWrite` command.
+#[derive(Debug)]
+pub(crate) struct BulkWrite<'a> {
+    models: &'a [WriteModel],
+    offset: usize,
+    options: Option<&'a BulkWriteOptions>,
+    /// The number of models included in the most recently built command.
+    n_attempted: usize,
+}
+
+impl<'a> BulkWrite<'a> {
+    pub(crate) fn new(
+        models: &'a [WriteModel],
+        offset: usize,
+        options: Option<&'a BulkWriteOptions>,
+    ) -> Self {
+        Self {
+            models,
+            offset,
+            options,
+            n_attempted: 0,
+        }
+    }
+
+    /// The number of models that were sent to the server by this operation.
+    pub(crate) fn n_attempted(&self) -> usize {
+        self.n_attempted
+    }
+
+    fn is_verbose(&self) -> bool {
+        self.options
+            .and_then(|o| o.verbose_results)
+            .unwrap_or(false)
+    }
+
+    fn is_ordered(&self) -> bool {
+        self.options.and_then(|o| o.ordered).unwrap_or(true)
+    }
+
+    fn command_body(&self) -> Result<RawDocumentBuf> {
+        let mut body = rawdoc! {
+            Self::NAME: 1,
+            "errorsOnly": !self.is_verbose(),
+            "ordered": self.is_ordered(),
+        };
+
+        if let Some(options) = self.options {
+            if let Some(bypass_document_validation) = options.bypass_document_validation {
+                body.append("bypassDocumentValidation", bypass_document_validation);
+            }
+            if let Some(ref comment) = options.comment {
+                body.append("comment", RawBson::try_from(comment.clone())?);
+            }
+            if let Some(ref let_vars) = options.let_vars {
+                body.append("let", RawDocumentBuf::from_document(let_vars)?);
+            }
+            if let Some(write_concern) = options.write_concern.as_ref() {
+                if !write_concern.is_empty() {
+                    body.append("writeConcern", bson::to_raw_document_buf(write_concern)?);
+                }
+            }
+        }
+
+        Ok(body)
+    }
+
+    /// Returns the `ops` and `nsInfo` documents for the models, starting at `offset`, that fit
+    /// into a single command with the given body on a connection with the given description.
+    fn batch(
+        &self,
+        body: &RawDocumentBuf,
+        description: &StreamDescription,
+    ) -> Result<(Vec<RawDocumentBuf>, Vec<RawDocumentBuf>)> {
+        let max_operations = description.max_write_batch_size as usize;
+        let max_document_size = description.max_bson_object_size as usize;
+        let max_sequences_size = (description.max_message_size_bytes as usize)
+            .saturating_sub(body.as_bytes().len() + COMMAND_OVERHEAD_SIZE_BYTES);
+
+        let mut ops = Vec::new();
+        let mut ns_info = Vec::new();
+        let mut ns_indexes: HashMap<&Namespace, usize> = HashMap::new();
+        let mut size = 0;
+
+        for model in self.models.iter().skip(self.offset).take(max_operations) {
+            let (ns_index, ns_doc) = match ns_indexes.get(model.namespace()) {
+                Some(index) => (*index, None),
+                None => {
+                    let ns_doc = rawdoc! { "ns": model.namespace().to_string() };
+                    (ns_indexes.len(), Some(ns_doc))
+                }
+            };
+
+            let op = operation_document(model, ns_index)?;
+            if op.as_bytes().len() > max_document_size {
+                return Err(ErrorKind::InvalidArgument {
+                    message: format!(
+                        "write model exceeds maxBsonObjectSize ({} bytes)",
+                        max_document_size
+                    ),
+                }
+                .into());
+            }
+
+            let op_size = op.as_bytes().len() + ns_doc.as_ref().map_or(0, |d| d.as_bytes().len());
+            if size + op_size > max_sequences_size {
+                break;
+            }
+            size += op_size;
+
+            if let Some(ns_doc) = ns_doc {
+                ns_indexes.insert(model.namespace(), ns_index);
+                ns_info.push(ns_doc);
+            }
+            ops.push(op);
+        }
+
+        if ops.is_empty() {
+            return Err(ErrorKind::InvalidArgument {
+                message: "write model exceeds maxMessageSizeBytes".to_string(),
+            }
+            .into());
+        }
+        Ok((ops, ns_info))
+    }
+}
+
+impl<'a> OperationWithDefaults for BulkWrite<'a> {
+    type O = BulkWriteBatchResponse;
+    type Command = RawDocumentBuf;
+
+    const NAME: &'static str = "bulkWrite";
+
+    fn build(&mut self, description: &StreamDescription) -> Result<Command<Self::Command>> {
+        let body = self.command_body()?;
+        let (ops, ns_info) = self.batch(&body, description)?;
+        self.n_attempted = ops.len();
+
+        let mut command = Command::new(Self::NAME.to_string(), "admin".to_string(), body);
+        command.document_sequences = vec![
+            DocumentSequence {
+                identifier: "ops".to_string(),
+                documents: ops,
+            },
+            DocumentSequence {
+                identifier: "nsInfo".to_string(),
+                documents: ns_info,
+            },
+        ];
+        Ok(command)
+    }
+
+    fn serialize_command(&mut self, cmd: Command<Self::Command>) -> Result<Vec<u8>> {
+        cmd.into_bson_bytes()
+    }
+
+    fn handle_response(
+        &self,
+        response: RawCommandResponse,
+        description: &StreamDescription,
+    ) -> Result<Self::O> {
+        let body: BulkWriteResponseBody = response.body_utf8_lossy()?;
+
+        Ok(BulkWriteBatchResponse {
+            summary: body.summary,
+            write_concern_error: body.write_concern_error,
+            cursor: CursorSpecification::new(
+                body.cursor,
+                description.server_address.clone(),
+                None,
+                None,
+                self.options.and_then(|o| o.comment.clone()),
+            ),
+        })
+    }
+
+    fn write_concern(&self) -> Option<&WriteConcern> {
+        self.options.and_then(|o| o.write_concern.as_ref())
+    }
+
+    fn timeout(&self) -> Option<Duration> {
+        self.options.and_then(|o| o.timeout)
+    }
+
+    fn retryability(&self) -> Retryability {
+        Retryability::Write
+    }
+
+    fn can_retry_write(&self, description: &StreamDescription) -> bool {
+        let max_operations = description.max_write_batch_size as usize;
+        if !self
+            .models
+            .iter()
+            .skip(self.offset)
+            .take(max_operations)
+            .any(WriteModel::is_multi)
+        {
+            return true;
+        }
+
+        // A multi-document write is among the models that could be sent, so work out whether it
+        // actually fits into this batch. If the batch can't be built, the error is reported when
+        // building the command.
+        let batch_len = match self
+            .command_body()
+            .and_then(|body| self.batch(&body, description))
+        {
+            Ok((ops, _)) => ops.len(),
+            Err(_) => return true,
+        };
+        !self.models[self.offset..self.offset + batch_len]
+            .iter()
+            .any(WriteModel::is_multi)
+    }
+}
+
+/// Converts a write model into its entry in the `ops` document sequence.
+fn operation_document(model: &WriteModel, ns_index: usize) -> Result<RawDocumentBuf> {
+    let ns_index = ns_index as i32;
+    let (mut op, multi) = match model {
+        WriteModel::InsertOne(model) => {
+            return Ok(rawdoc! {
+                "insert": ns_index,
+                "document": RawDocumentBuf::from_document(&model.document)?,
+            });
+        }
+        WriteModel::UpdateOne(model) => (
+            update_document(
+                ns_index,
+                &model.filter,
+                update_modifications(&model.update)?,
+                model.array_filters.as_ref(),
+                model.upsert,
+            )?,
+            false,
+        ),
+        WriteModel::UpdateMany(model) => (
+            update_document(
+                ns_index,
+                &model.filter,
+                update_modifications(&model.update)?,
+                model.array_filters.as_ref(),
+                model.upsert,
+            )?,
+            true,
+        ),
+        WriteModel::ReplaceOne(model) => (
+            update_document(
+                ns_index,
+                &model.filter,
+                RawDocumentBuf::from_document(&model.replacement)?.into(),
+                None,
+                model.upsert,
+            )?,
+            false,
+        ),
+        WriteModel::DeleteOne(model) => (
+            rawdoc! {
+                "delete": ns_index,
+                "filter": RawDocumentBuf::from_document(&model.filter)?,
+            },
+            false,
+        ),
+        WriteModel::DeleteMany(model) => (
+            rawdoc! {
+                "delete": ns_index,
+                "filter": RawDocumentBuf::from_document(&model.filter)?,
+            },
+            true,
+        ),
+    };
+    op.append("multi", multi);
+
+    let (collation, hint) = match model {
+        WriteModel::InsertOne(_) => (None, None),
+        WriteModel::UpdateOne(model) => (model.collation.as_ref(), model.hint.as_ref()),
+        WriteModel::UpdateMany(model) => (model.collation.as_ref(), model.hint.as_ref()),
+        WriteModel::ReplaceOne(model) => (model.collation.as_ref(), model.hint.as_ref()),
+        WriteModel::DeleteOne(model) => (model.collation.as_ref(), model.hint.as_ref()),
+        WriteModel::DeleteMany(model) => (model.collation.as_ref(), model.hint.as_ref()),
+    };
+    if let Some(collation) = collation {
+        op.append("collation", bson::to_raw_document_buf(collation)?);
+    }
+    if let Some(hint) = hint {
+        op.append("hint", hint.to_raw_bson()?);
+    }
+
+    Ok(op)
+}
+
+fn update_document(
+    ns_index: i32,
+    filter: &Document,
+    update_mods: RawBson,
+    array_filters: Option<&Vec<Document>>,
+    upsert: Option<bool>,
+) -> Result<RawDocumentBuf> {
+    let mut op = rawdoc! {
+        "update": ns_index,
+        "filter": RawDocumentBuf::from_document(filter)?,
+        "updateMods": update_mods,
+    };
+    if let Some(array_filters) = array_filters {
+        let mut array = RawArrayBuf::new();
+        for filter in array_filters {
+            array.push(RawDocumentBuf::from_document(filter)?);
+        }
+        op.append("arrayFilters", array);
+    }
+    if let Some(upsert) = upsert {
+        op.append("upsert", upsert);
+    }
+    Ok(op)
+}
+
+fn update_modifications(update: &UpdateModifications) -> Result<RawBson> {
+    match update {
+        UpdateModifications::Document(document) => {
+            Ok(RawDocumentBuf::from_document(document)?.into())
+        }
+        UpdateModifications::Pipeline(pipeline) => bson_util::to_raw_bson_array(pipeline),
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct BulkWriteResponseBody {
+    cursor: CursorInfo,
+
+    #[serde(flatten)]
+    summary: BulkWriteSummary,
+
+    write_concern_error: Option<WriteConcernError>,
+}
+
+/// The counts reported by the server for a single `bulkWrite` command.
+#[derive(Clone, Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct BulkWriteSummary {
+    #[serde(deserialize_with = "serde_util::deserialize_u64_from_bson_number")]
+    pub(crate) n_inserted: u64,
+
+    #[serde(deserialize_with = "serde_util::deserialize_u64_from_bson_number")]
+    pub(crate) n_upserted: u64,
+
+    #[serde(deserialize_with = "serde_util::deserialize_u64_from_bson_number")]
+    pub(crate) n_matched: u64,
+
+    #[serde(deserialize_with = "serde_util::deserialize_u64_from_bson_number")]
+    pub(crate) n_modified: u64,
+
+    #[serde(deserialize_with = "serde_util::deserialize_u64_from_bson_number")]
+    pub(crate) n_deleted: u64,
+}
+
+impl BulkWriteSummary {
+    /// Whether any of the writes in the batch were successfully performed.
+    pub(crate) fn any_successful(&self) -> bool {
+        self.n_inserted + self.n_upserted + self.n_matched + self.n_deleted > 0
+    }
+}
+
+/// The response to a single `bulkWrite` command. The results of the individual writes are
+/// returned via a cursor.
+#[derive(Debug)]
+pub(crate) struct BulkWriteBatchResponse {
+    pub(crate) summary: BulkWriteSummary,
+    pub(crate) write_concern_error: Option<WriteConcernError>,
+    pub(crate) cursor: CursorSpecification,
+}
+
+/// The result of a single write as returned in the `bulkWrite` results cursor.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct SingleWriteResult {
+    ok: Bson,
+
+    #[serde(rename = "idx")]
+    index: usize,
+
+    #[serde(default)]
+    n: u64,
+
+    #[serde(default)]
+    n_modified: u64,
+
+    upserted: Option<UpsertedId>,
+
+    #[serde(flatten)]
+    error: Option<WriteError>,
+}
+
+#[derive(Debug, Deserialize)]
+struct UpsertedId {
+    #[serde(rename = "_id")]
+    id: Bson,
+}
+
+impl SingleWriteResult {
+    /// Records this result, which belongs to a batch starting at `offset`, in either the verbose
+    /// results or the failure.
+    pub(crate) fn record(
+        self,
+        models: &[WriteModel],
+        offset: usize,
+        result: &mut BulkWriteResult,
+        failure: &mut ClientBulkWriteFailure,
+    ) -> Result<()> {
+        let index = offset + self.index;
+        let model = models
+            .get(index)
+            .ok_or_else(|| ErrorKind::InvalidResponse {
+                message: format!("bulkWrite result index {} is out of range", index),
+            })?;
+
+        if bson_util::get_int(&self.ok) != Some(1) {
+            let error = self.error.ok_or_else(|| ErrorKind::InvalidResponse {
+                message: "missing error for failed write in bulkWrite response".to_string(),
+            })?;
+            failure.write_errors.insert(index, error);
+            return Ok(());
+        }
+
+        match model {
+            WriteModel::InsertOne(model) => {
+                if let Some(ref mut insert_results) = result.insert_results {
+                    let inserted_id = model.document.get("_id").cloned().unwrap_or(Bson::Null);
+                    insert_results.insert(index, InsertOneResult { inserted_id });
+                }
+            }
+            WriteModel::UpdateOne(_) | WriteModel::UpdateMany(_) | WriteModel::ReplaceOne(_) => {
+                if let Some(ref mut update_results) = result.update_results {
+                    let upserted_id = self.upserted.map(|u| u.id);
+                    let matched_count = if upserted_id.is_some() { 0 } else { self.n };
+                    update_results.insert(
+                        index,
+                        UpdateResult {
+                            matched_count,
+                            modified_count: self.n_modified,
+                            upserted_id,
+                        },
+                    );
+                }
+            }
+            WriteModel::DeleteOne(_) | WriteModel::DeleteMany(_) => {
+                if let Some(ref mut delete_results) = result.delete_results {
+                    delete_results.insert(
+                        index,
+                        DeleteResult {
+                            deleted_count: self.n,
+                        },
+                    );
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,200 @@
+use crate::{
+    bson::{doc, Document},
+    cmap::StreamDescription,
+    operation::{test::handle_response_test, BulkWrite, Operation, Retryability},
+    options::{BulkWriteOptions, DeleteManyModel, InsertOneModel, UpdateOneModel, WriteModel},
+    Namespace,
+};
+
+fn description() -> StreamDescription {
+    let mut description = StreamDescription::new_testing();
+    description.max_message_size_bytes = 48_000_000;
+    description
+}
+
+fn insert_models(count: usize, namespaces: &[Namespace]) -> Vec<WriteModel> {
+    (0..count)
+        .map(|i| {
+            InsertOneModel::builder()
+                .namespace(namespaces[i % namespaces.len()].clone())
+                .document(doc! { "_id": i as i32 })
+                .build()
+                .into()
+        })
+        .collect()
+}
+
+#[test]
+fn build() {
+    let models = vec![
+        InsertOneModel::builder()
+            .namespace(Namespace::new("db", "a"))
+            .document(doc! { "_id": 1 })
+            .build()
+            .into(),
+        UpdateOneModel::builder()
+            .namespace(Namespace::new("db", "b"))
+            .filter(doc! { "x": 1 })
+            .update(doc! { "$set": { "x": 2 } })
+            .upsert(true)
+            .build()
+            .into(),
+        DeleteManyModel::builder()
+            .namespace(Namespace::new("db", "a"))
+            .filter(doc! {})
+            .build()
+            .into(),
+    ];
+    let options = BulkWriteOptions::builder()
+        .ordered(false)
+        .verbose_results(true)
+        .build();
+    let mut op = BulkWrite::new(&models, 0, Some(&options));
+
+    let cmd = op.build(&description()).unwrap();
+    assert_eq!(cmd.name, "bulkWrite");
+    assert_eq!(cmd.target_db, "admin");
+    assert_eq!(op.n_attempted(), 3);
+
+    let sequences: Vec<(&str, Vec<Document>)> = cmd
+        .document_sequences
+        .iter()
+        .map(|sequence| {
+            (
+                sequence.identifier.as_str(),
+                sequence
+                    .documents
+                    .iter()
+                    .map(|d| d.to_document().unwrap())
+                    .collect(),
+            )
+        })
+        .collect();
+    assert_eq!(
+        sequences,
+        vec![
+            (
+                "ops",
+                vec![
+                    doc! { "insert": 0, "document": { "_id": 1 } },
+                    doc! {
+                        "update": 1,
+                        "filter": { "x": 1 },
+                        "updateMods": { "$set": { "x": 2 } },
+                        "upsert": true,
+                        "multi": false,
+                    },
+                    doc! { "delete": 0, "filter": {}, "multi": true },
+                ]
+            ),
+            ("nsInfo", vec![doc! { "ns": "db.a" }, doc! { "ns": "db.b" }]),
+        ]
+    );
+
+    let serialized = op.serialize_command(cmd).unwrap();
+    let cmd_doc = Document::from_reader(serialized.as_slice()).unwrap();
+    assert_eq!(cmd_doc.get_i32("bulkWrite"), Ok(1));
+    assert_eq!(cmd_doc.get_bool("errorsOnly"), Ok(false));
+    assert_eq!(cmd_doc.get_bool("ordered"), Ok(false));
+    assert!(!cmd_doc.contains_key("ops"));
+
+    assert_eq!(op.retryability(), Retryability::Write);
+    assert!(!op.can_retry_write(&description()));
+}
+
+#[test]
+fn retryability_only_considers_current_batch() {
+    let namespace = Namespace::new("db", "a");
+    let mut models = insert_models(2, &[namespace.clone()]);
+    models.push(
+        DeleteManyModel::builder()
+            .namespace(namespace)
+            .filter(doc! {})
+            .build()
+            .into(),
+    );
+    let mut description = description();
+    description.max_write_batch_size = 2;
+
+    // The first batch only contains the two inserts, so it can be retried even though the
+    // multi-document delete follows in the next batch.
+    let op = BulkWrite::new(&models, 0, None);
+    assert!(op.can_retry_write(&description));
+
+    let op = BulkWrite::new(&models, 1, None);
+    assert!(!op.can_retry_write(&description));
+}
+
+#[test]
+fn split_by_max_write_batch_size() {
+    let namespaces = [Namespace::new("db", "a"), Namespace::new("db", "b")];
+    let models = insert_models(10, &namespaces);
+    let mut description = description();
+    description.max_write_batch_size = 4;
+
+    let mut offset = 0;
+    let mut batch_sizes = Vec::new();
+    while offset < models.len() {
+        let mut op = BulkWrite::new(&models, offset, None);
+        let cmd = op.build(&description).unwrap();
+        assert_eq!(cmd.document_sequences[0].documents.len(), op.n_attempted());
+        assert_eq!(cmd.document_sequences[1].documents.len(), 2);
+        batch_sizes.push(op.n_attempted());
+        offset += op.n_attempted();
+    }
+    assert_eq!(batch_sizes, vec![4, 4, 2]);
+}
+
+#[test]
+fn split_by_max_message_size_bytes() {
+    let large_string = "a".repeat(1_000);
+    let models: Vec<WriteModel> = (0..10)
+        .map(|i| {
+            InsertOneModel::builder()
+                .namespace(Namespace::new("db", "coll"))
+                .document(doc! { "_id": i, "s": large_string.clone() })
+                .build()
+                .into()
+        })
+        .collect();
+    let mut description = description();
+    description.max_message_size_bytes = 5_500;
+
+    let mut op = BulkWrite::new(&models, 0, None);
+    op.build(&description).unwrap();
+    assert_eq!(op.n_attempted(), 4);
+
+    let mut op = BulkWrite::new(&models, 8, None);
+    op.build(&description).unwrap();
+    assert_eq!(op.n_attempted(), 2);
+
+    description.max_message_size_bytes = 1_500;
+    let mut op = BulkWrite::new(&models, 0, None);
+    assert!(op.build(&description).is_err());
+}
+
+#[test]
+fn handle_response() {
+    let models = insert_models(1, &[Namespace::new("db", "coll")]);
+    let op = BulkWrite::new(&models, 0, None);
+
+    let response = doc! {
+        "ok": 1,
+        "cursor": {
+            "id": 0_i64,
+            "firstBatch": [],
+            "ns": "admin.$cmd.bulkWrite",
+        },
+        "nErrors": 0,
+        "nInserted": 1,
+        "nMatched": 0,
+        "nModified": 0,
+        "nUpserted": 0,
+        "nDeleted": 0,
+    };
+    let response = handle_response_test(&op, response).unwrap();
+    assert_eq!(response.summary.n_inserted, 1);
+    assert!(response.summary.any_successful());
+    assert!(response.write_concern_error.is_none());
+    assert_eq!(response.cursor.id(), 0);
+}
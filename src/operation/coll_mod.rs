@@ -0,0 +1,75 @@
+#[cfg(test)]
+mod test;
+
+use bson::Document;
+
+use crate::{
+    bson::doc,
+    cmap::{Command, RawCommandResponse, StreamDescription},
+    error::{Error, Result},
+    operation::{
+        append_options,
+        remove_empty_write_concern,
+        OperationWithDefaults,
+        WriteConcernOnlyBody,
+    },
+    options::{CollModOptions, WriteConcern},
+    Namespace,
+};
+
+#[derive(Debug)]
+pub(crate) struct CollMod {
+    ns: Namespace,
+    options: Option<CollModOptions>,
+}
+
+impl CollMod {
+    pub(crate) fn new(ns: Namespace, options: Option<CollModOptions>) -> Self {
+        Self { ns, options }
+    }
+}
+
+impl OperationWithDefaults for CollMod {
+    type O = ();
+    type Command = Document;
+
+    const NAME: &'static str = "collMod";
+
+    fn build(&mut self, _description: &StreamDescription) -> Result<Command> {
+        if let Some(index) = self.options.as_ref().and_then(|o| o.index.as_ref()) {
+            if index.key_pattern.is_some() == index.name.is_some() {
+                return Err(Error::invalid_argument(
+                    "exactly one of key_pattern and name must be specified for the index to modify",
+                ));
+            }
+        }
+
+        let mut body = doc! {
+            Self::NAME: self.ns.coll.clone(),
+        };
+
+        remove_empty_write_concern!(self.options);
+        append_options(&mut body, self.options.as_ref())?;
+
+        Ok(Command::new(
+            Self::NAME.to_string(),
+            self.ns.db.clone(),
+            body,
+        ))
+    }
+
+    fn handle_response(
+        &self,
+        response: RawCommandResponse,
+        _description: &StreamDescription,
+    ) -> Result<Self::O> {
+        let response: WriteConcernOnlyBody = response.body()?;
+        response.validate()
+    }
+
+    fn write_concern(&self) -> Option<&WriteConcern> {
+        self.options
+            .as_ref()
+            .and_then(|opts| opts.write_concern.as_ref())
+    }
+}
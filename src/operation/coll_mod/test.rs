@@ -0,0 +1,117 @@
+use std::time::Duration;
+
+use crate::{
+    bson::doc,
+    cmap::StreamDescription,
+    concern::WriteConcern,
+    error::ErrorKind,
+    operation::{test::handle_response_test, CollMod, Operation},
+    options::{
+        ChangeStreamPreAndPostImages,
+        CollModIndex,
+        CollModOptions,
+        ValidationAction,
+        ValidationLevel,
+    },
+    Namespace,
+};
+
+fn namespace() -> Namespace {
+    Namespace {
+        db: "test_db".to_string(),
+        coll: "test_coll".to_string(),
+    }
+}
+
+#[test]
+fn build() {
+    let mut op = CollMod::new(
+        namespace(),
+        Some(
+            CollModOptions::builder()
+                .validator(doc! { "x": { "$gt": 1 } })
+                .validation_level(ValidationLevel::Strict)
+                .validation_action(ValidationAction::Error)
+                .expire_after_seconds(Duration::from_secs(60))
+                .change_stream_pre_and_post_images(
+                    ChangeStreamPreAndPostImages::builder()
+                        .enabled(true)
+                        .build(),
+                )
+                .write_concern(WriteConcern {
+                    journal: Some(true),
+                    ..Default::default()
+                })
+                .build(),
+        ),
+    );
+
+    let description = StreamDescription::new_testing();
+    let cmd = op.build(&description).unwrap();
+
+    assert_eq!(cmd.name.as_str(), "collMod");
+    assert_eq!(cmd.target_db.as_str(), "test_db");
+    assert_eq!(
+        cmd.body,
+        doc! {
+            "collMod": "test_coll",
+            "validator": { "x": { "$gt": 1 } },
+            "validationLevel": "strict",
+            "validationAction": "error",
+            "expireAfterSeconds": 60,
+            "changeStreamPreAndPostImages": { "enabled": true },
+            "writeConcern": { "j": true },
+        }
+    );
+}
+
+#[test]
+fn build_index() {
+    let mut op = CollMod::new(
+        namespace(),
+        Some(
+            CollModOptions::builder()
+                .index(
+                    CollModIndex::builder()
+                        .name("a_1".to_string())
+                        .hidden(true)
+                        .expire_after_seconds(Duration::from_secs(3600))
+                        .build(),
+                )
+                .build(),
+        ),
+    );
+
+    let description = StreamDescription::new_testing();
+    let cmd = op.build(&description).unwrap();
+    assert_eq!(
+        cmd.body,
+        doc! {
+            "collMod": "test_coll",
+            "index": { "name": "a_1", "hidden": true, "expireAfterSeconds": 3600 },
+        }
+    );
+
+    let mut op = CollMod::new(
+        namespace(),
+        Some(
+            CollModOptions::builder()
+                .index(
+                    CollModIndex::builder()
+                        .key_pattern(doc! { "a": 1 })
+                        .name("a_1".to_string())
+                        .build(),
+                )
+                .build(),
+        ),
+    );
+    let err = op.build(&description).unwrap_err();
+    assert!(matches!(*err.kind, ErrorKind::InvalidArgument { .. }));
+}
+
+#[test]
+fn handle_success() {
+    let op = CollMod::new(namespace(), None);
+    let response = doc! { "ok": 1.0, "expireAfterSeconds_old": 60, "expireAfterSeconds_new": 120 };
+    handle_response_test(&op, response).unwrap();
+}
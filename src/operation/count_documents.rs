@@ -1,7 +1,7 @@
 #[cfg(test)]
 mod test;
 
-use std::convert::TryInto;
+use std::{convert::TryInto, time::Duration};
 
 use serde::Deserialize;
 
@@ -67,6 +67,7 @@ impl CountDocuments {
                 .selection_criteria(opts.selection_criteria)
                 .read_concern(opts.read_concern)
                 .comment_bson(opts.comment)
+                .timeout(opts.timeout)
                 .build()
         });
 
@@ -103,6 +104,10 @@ impl OperationWithDefaults for CountDocuments {
         self.aggregate.selection_criteria()
     }
 
+    fn timeout(&self) -> Option<Duration> {
+        self.aggregate.timeout()
+    }
+
     fn retryability(&self) -> Retryability {
         Retryability::Read
     }
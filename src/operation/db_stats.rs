@@ -0,0 +1,61 @@
+#[cfg(test)]
+mod test;
+
+use crate::{
+    bson::{doc, Document},
+    cmap::{Command, RawCommandResponse, StreamDescription},
+    error::Result,
+    operation::{append_options, remove_response_metadata, OperationWithDefaults, Retryability},
+    options::DbStatsOptions,
+    results::DatabaseStats,
+    selection_criteria::SelectionCriteria,
+};
+
+#[derive(Debug)]
+pub(crate) struct DbStats {
+    db: String,
+    options: Option<DbStatsOptions>,
+}
+
+impl DbStats {
+    pub(crate) fn new(db: String, options: Option<DbStatsOptions>) -> Self {
+        Self { db, options }
+    }
+}
+
+impl OperationWithDefaults for DbStats {
+    type O = DatabaseStats;
+    type Command = Document;
+
+    const NAME: &'static str = "dbStats";
+
+    fn build(&mut self, _description: &StreamDescription) -> Result<Command> {
+        let mut body = doc! {
+            Self::NAME: 1,
+        };
+
+        append_options(&mut body, self.options.as_ref())?;
+
+        Ok(Command::new(Self::NAME.to_string(), self.db.clone(), body))
+    }
+
+    fn handle_response(
+        &self,
+        response: RawCommandResponse,
+        _description: &StreamDescription,
+    ) -> Result<Self::O> {
+        let mut response: Document = response.body()?;
+        remove_response_metadata(&mut response);
+        Ok(bson::from_document(response)?)
+    }
+
+    fn selection_criteria(&self) -> Option<&SelectionCriteria> {
+        self.options
+            .as_ref()
+            .and_then(|opts| opts.selection_criteria.as_ref())
+    }
+
+    fn retryability(&self) -> Retryability {
+        Retryability::Read
+    }
+}
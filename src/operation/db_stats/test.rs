@@ -0,0 +1,70 @@
+use crate::{
+    bson::{doc, Timestamp},
+    cmap::StreamDescription,
+    operation::{test::handle_response_test, DbStats, Operation},
+    options::{DbStatsOptions, ReadPreference, SelectionCriteria},
+};
+
+#[test]
+fn build() {
+    let mut op = DbStats::new(
+        "test_db".to_string(),
+        Some(
+            DbStatsOptions::builder()
+                .scale(1024)
+                .free_storage(true)
+                .selection_criteria(SelectionCriteria::ReadPreference(
+                    ReadPreference::Secondary {
+                        options: Default::default(),
+                    },
+                ))
+                .build(),
+        ),
+    );
+
+    let cmd = op.build(&StreamDescription::new_testing()).unwrap();
+    assert_eq!(cmd.name.as_str(), "dbStats");
+    assert_eq!(cmd.target_db.as_str(), "test_db");
+    assert_eq!(
+        cmd.body,
+        doc! { "dbStats": 1, "scale": 1024, "freeStorage": true }
+    );
+    assert!(matches!(
+        op.selection_criteria(),
+        Some(SelectionCriteria::ReadPreference(
+            ReadPreference::Secondary { .. }
+        ))
+    ));
+}
+
+#[test]
+fn handle_success() {
+    let op = DbStats::new("test_db".to_string(), None);
+    let response = doc! {
+        "ok": 1.0,
+        "db": "test_db",
+        "collections": 2,
+        "views": 1,
+        "objects": 10_i64,
+        "avgObjSize": 33.5,
+        "dataSize": 335.0,
+        "storageSize": 8192,
+        "indexes": 2,
+        "indexSize": 8192,
+        "totalSize": 16384,
+        "scaleFactor": 1,
+        "fsUsedSize": 1000.0,
+        "$clusterTime": { "clusterTime": Timestamp { time: 1, increment: 1 }, "signature": {} },
+        "operationTime": Timestamp { time: 1, increment: 1 },
+    };
+
+    let stats = handle_response_test(&op, response).unwrap();
+    assert_eq!(stats.db, "test_db");
+    assert_eq!(stats.collections, 2);
+    assert_eq!(stats.views, Some(1));
+    assert_eq!(stats.objects, 10);
+    assert_eq!(stats.avg_obj_size, 33.5);
+    assert_eq!(stats.storage_size, 8192.0);
+    assert_eq!(stats.total_size, Some(16384.0));
+    assert_eq!(stats.extra, doc! { "fsUsedSize": 1000.0 });
+}
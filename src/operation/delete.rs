@@ -1,8 +1,11 @@
 #[cfg(test)]
 mod test;
 
+use std::time::Duration;
+
 use crate::{
-    bson::{doc, Document},
+    bson::{doc, Bson, Document},
+    bson_util,
     cmap::{Command, RawCommandResponse, StreamDescription},
     coll::Namespace,
     collation::Collation,
@@ -15,17 +18,42 @@ use crate::{
         WriteResponseBody,
     },
     options::{DeleteOptions, Hint, WriteConcern},
-    results::DeleteResult,
+    results::{BulkWriteResult, DeleteResult},
 };
 
+/// A single delete sent as one of the statements of a [`Delete`] or a [`BulkDelete`].
+#[derive(Debug)]
+pub(crate) struct DeleteStatement {
+    pub(crate) filter: Document,
+    pub(crate) limit: u32,
+    pub(crate) collation: Option<Collation>,
+    pub(crate) hint: Option<Hint>,
+}
+
+impl DeleteStatement {
+    fn to_document(&self) -> Result<Document> {
+        let mut statement = doc! {
+            "q": self.filter.clone(),
+            "limit": self.limit,
+        };
+
+        if let Some(ref collation) = self.collation {
+            statement.insert("collation", bson::to_bson(&collation)?);
+        }
+
+        if let Some(ref hint) = self.hint {
+            statement.insert("hint", bson::to_bson(&hint)?);
+        }
+
+        Ok(statement)
+    }
+}
+
 #[derive(Debug)]
 pub(crate) struct Delete {
     ns: Namespace,
-    filter: Document,
-    limit: u32,
+    statement: DeleteStatement,
     options: Option<DeleteOptions>,
-    collation: Option<Collation>,
-    hint: Option<Hint>,
 }
 
 impl Delete {
@@ -50,10 +78,12 @@ impl Delete {
     ) -> Self {
         Self {
             ns,
-            filter,
-            limit: limit.unwrap_or(0), // 0 = no limit
-            collation: options.as_mut().and_then(|opts| opts.collation.take()),
-            hint: options.as_mut().and_then(|opts| opts.hint.take()),
+            statement: DeleteStatement {
+                filter,
+                limit: limit.unwrap_or(0), // 0 = no limit
+                collation: options.as_mut().and_then(|opts| opts.collation.take()),
+                hint: options.as_mut().and_then(|opts| opts.hint.take()),
+            },
             options,
         }
     }
@@ -66,23 +96,129 @@ impl OperationWithDefaults for Delete {
     const NAME: &'static str = "delete";
 
     fn build(&mut self, _description: &StreamDescription) -> Result<Command> {
-        let mut delete = doc! {
-            "q": self.filter.clone(),
-            "limit": self.limit,
+        let mut body = doc! {
+            Self::NAME: self.ns.coll.clone(),
+            "deletes": [self.statement.to_document()?],
+            "ordered": true, // command monitoring tests expect this (SPEC-1130)
         };
 
-        if let Some(ref collation) = self.collation {
-            delete.insert("collation", bson::to_bson(&collation)?);
+        remove_empty_write_concern!(self.options);
+        append_options(&mut body, self.options.as_ref())?;
+
+        Ok(Command::new(
+            Self::NAME.to_string(),
+            self.ns.db.clone(),
+            body,
+        ))
+    }
+
+    fn handle_response(
+        &self,
+        response: RawCommandResponse,
+        _description: &StreamDescription,
+    ) -> Result<Self::O> {
+        let response: WriteResponseBody = response.body()?;
+        response.validate().map_err(convert_bulk_errors)?;
+
+        Ok(DeleteResult {
+            deleted_count: response.n,
+        })
+    }
+
+    fn write_concern(&self) -> Option<&WriteConcern> {
+        self.options
+            .as_ref()
+            .and_then(|opts| opts.write_concern.as_ref())
+    }
+
+    fn timeout(&self) -> Option<Duration> {
+        self.options.as_ref().and_then(|opts| opts.timeout)
+    }
+
+    fn retryability(&self) -> Retryability {
+        if self.statement.limit == 1 {
+            Retryability::Write
+        } else {
+            Retryability::None
         }
+    }
+}
 
-        if let Some(ref hint) = self.hint {
-            delete.insert("hint", bson::to_bson(&hint)?);
+/// A `delete` command made up of multiple statements, as used by
+/// [`Collection::bulk_write`](crate::Collection::bulk_write). Only as many statements as fit into
+/// a single command are sent; `n_attempted` reports how many that was. If `verbose` is set, only
+/// one statement is sent at a time so that its result can be reported in
+/// [`BulkWriteResult::delete_results`].
+#[derive(Debug)]
+pub(crate) struct BulkDelete {
+    ns: Namespace,
+    statements: Vec<DeleteStatement>,
+    ordered: bool,
+    options: Option<DeleteOptions>,
+    verbose: bool,
+    n_attempted: usize,
+}
+
+impl BulkDelete {
+    /// Creates a delete made up of `statements`. Only the command-level options in `options` are
+    /// used; the collation and hint for each statement are taken from the statement itself.
+    pub(crate) fn new(
+        ns: Namespace,
+        statements: Vec<DeleteStatement>,
+        ordered: bool,
+        options: Option<DeleteOptions>,
+        verbose: bool,
+    ) -> Self {
+        Self {
+            ns,
+            statements,
+            ordered,
+            options,
+            verbose,
+            n_attempted: 0,
         }
+    }
+
+    /// The number of statements that were included in the most recently built command.
+    pub(crate) fn n_attempted(&self) -> usize {
+        self.n_attempted
+    }
+}
+
+impl OperationWithDefaults for BulkDelete {
+    type O = BulkWriteResult;
+    type Command = Document;
+
+    const NAME: &'static str = "delete";
+
+    fn build(&mut self, description: &StreamDescription) -> Result<Command> {
+        let max_statements = if self.verbose {
+            1
+        } else {
+            description.max_write_batch_size as usize
+        };
+        let mut deletes = Vec::new();
+        let mut size = 0;
+        for (i, statement) in self.statements.iter().take(max_statements).enumerate() {
+            let statement = statement.to_document()?;
+            let statement_size =
+                bson_util::array_entry_size_bytes(i, bson::to_vec(&statement)?.len());
+            // The first statement is always sent so that the server can report it if it is too
+            // large.
+            if !deletes.is_empty()
+                && size + statement_size > description.max_bson_object_size as u64
+            {
+                break;
+            }
+            deletes.push(Bson::Document(statement));
+            size += statement_size;
+        }
+        self.n_attempted = deletes.len();
 
         let mut body = doc! {
             Self::NAME: self.ns.coll.clone(),
-            "deletes": [delete],
-            "ordered": true, // command monitoring tests expect this (SPEC-1130)
+            "deletes": deletes,
+            "ordered": self.ordered,
         };
 
         remove_empty_write_concern!(self.options);
@@ -101,11 +237,23 @@ impl OperationWithDefaults for Delete {
         _description: &StreamDescription,
     ) -> Result<Self::O> {
         let response: WriteResponseBody = response.body()?;
-        response.validate().map_err(convert_bulk_errors)?;
+        let mut result = BulkWriteResult::new(self.verbose);
+        result.deleted_count = response.n;
+        // Verbose commands are made up of a single statement, which succeeded unless there is a
+        // write error.
+        if let Some(ref mut delete_results) = result.delete_results {
+            if response.write_errors.is_none() {
+                delete_results.insert(
+                    0,
+                    DeleteResult {
+                        deleted_count: response.n,
+                    },
+                );
+            }
+        }
+        response.validate_with_partial_result(result.clone())?;
 
-        Ok(DeleteResult {
-            deleted_count: response.n,
-        })
+        Ok(result)
     }
 
     fn write_concern(&self) -> Option<&WriteConcern> {
@@ -114,8 +262,12 @@ impl OperationWithDefaults for Delete {
             .and_then(|opts| opts.write_concern.as_ref())
     }
 
+    fn timeout(&self) -> Option<Duration> {
+        self.options.as_ref().and_then(|opts| opts.timeout)
+    }
+
     fn retryability(&self) -> Retryability {
-        if self.limit == 1 {
+        if self.statements.iter().all(|statement| statement.limit == 1) {
             Retryability::Write
         } else {
             Retryability::None
@@ -1,12 +1,19 @@
 use pretty_assertions::assert_eq;
 
 use crate::{
-    bson::doc,
+    bson::{doc, Bson},
     bson_util,
     cmap::StreamDescription,
     concern::{Acknowledgment, WriteConcern},
     error::{ErrorKind, WriteConcernError, WriteError, WriteFailure},
-    operation::{test::handle_response_test, Delete, Operation},
+    operation::{
+        test::handle_response_test,
+        BulkDelete,
+        Delete,
+        DeleteStatement,
+        Operation,
+        Retryability,
+    },
     options::DeleteOptions,
     Namespace,
 };
@@ -194,3 +201,79 @@ fn handle_write_concern_failure() {
         ref e => panic!("expected write concern error, got {:?}", e),
     }
 }
+
+#[test]
+fn build_bulk() {
+    let filter = doc! { "x": { "$gt": 1 } };
+    let statements = vec![
+        DeleteStatement {
+            filter: filter.clone(),
+            limit: 1,
+            collation: None,
+            hint: None,
+        },
+        DeleteStatement {
+            filter: filter.clone(),
+            limit: 0,
+            collation: None,
+            hint: None,
+        },
+    ];
+    let options = DeleteOptions::builder()
+        .comment(Bson::from("hello"))
+        .build();
+    let mut op = BulkDelete::new(
+        Namespace::new("test_db", "test_coll"),
+        statements,
+        false,
+        Some(options),
+        false,
+    );
+
+    let cmd = op.build(&StreamDescription::new_testing()).unwrap();
+    assert_eq!(cmd.name.as_str(), "delete");
+    assert_eq!(cmd.target_db.as_str(), "test_db");
+    assert_eq!(op.n_attempted(), 2);
+    assert_eq!(
+        cmd.body,
+        doc! {
+            "delete": "test_coll",
+            "deletes": [
+                { "q": filter.clone(), "limit": 1 },
+                { "q": filter.clone(), "limit": 0 },
+            ],
+            "ordered": false,
+            "comment": "hello",
+        }
+    );
+    assert_eq!(op.retryability(), Retryability::None);
+
+    let response = doc! { "ok": 1.0, "n": 4 };
+    let result = handle_response_test(&op, response).unwrap();
+    assert_eq!(result.deleted_count, 4);
+}
+
+#[test]
+fn handle_write_failure_partial_result() {
+    let op = BulkDelete::new(Namespace::new("db", "coll"), Vec::new(), true, None, false);
+
+    let write_error_response = doc! {
+        "ok": 1.0,
+        "n": 2,
+        "writeErrors": [
+            {
+                "index": 2,
+                "code": 1234,
+                "errmsg": "my error string"
+            }
+        ]
+    };
+    let error = handle_response_test(&op, write_error_response).unwrap_err();
+    match *error.kind {
+        ErrorKind::BulkWrite(ref failure) => {
+            let partial_result = failure.partial_result.as_ref().unwrap();
+            assert_eq!(partial_result.deleted_count, 2);
+        }
+        ref e => panic!("expected bulk write error, got {:?}", e),
+    }
+}
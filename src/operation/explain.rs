@@ -0,0 +1,138 @@
+#[cfg(test)]
+mod test;
+
+use std::time::{Duration, Instant};
+
+use crate::{
+    bson::{doc, Document},
+    cmap::{conn::PinnedConnectionHandle, Command, RawCommandResponse, StreamDescription},
+    coll::options::ExplainVerbosity,
+    error::{Error, Result},
+    operation::{Operation, OverrideCriteriaFn, Retryability},
+    options::WriteConcern,
+    selection_criteria::SelectionCriteria,
+};
+
+/// Wraps the command built by another `Operation` in an `explain` command and returns the raw
+/// explain output.
+///
+/// The wrapped operation's read concern is moved onto the outer command; any write concern is
+/// dropped, as `explain` does not accept one.
+pub(crate) struct Explain<Op> {
+    inner: Op,
+    verbosity: ExplainVerbosity,
+}
+
+impl<Op: Operation> Explain<Op> {
+    pub(crate) fn new(inner: Op, verbosity: ExplainVerbosity) -> Self {
+        Self { inner, verbosity }
+    }
+}
+
+impl<Op: Operation> Operation for Explain<Op> {
+    type O = Document;
+    type Command = Document;
+    const NAME: &'static str = "explain";
+
+    fn build(&mut self, description: &StreamDescription) -> Result<Command> {
+        let mut inner = self.inner.build(description)?;
+        let target_db = inner.target_db.clone();
+        let read_concern = inner.read_concern.take();
+
+        let serialized = self.inner.serialize_command(inner)?;
+        let mut explained = Document::from_reader(serialized.as_slice())?;
+        explained.remove("$db");
+        explained.remove("writeConcern");
+
+        let mut command = Command::new(
+            Self::NAME.to_string(),
+            target_db,
+            doc! {
+                Self::NAME: explained,
+                "verbosity": bson::to_bson(&self.verbosity)?,
+            },
+        );
+        command.read_concern = read_concern;
+        Ok(command)
+    }
+
+    fn serialize_command(&mut self, cmd: Command<Self::Command>) -> Result<Vec<u8>> {
+        Ok(bson::to_vec(&cmd)?)
+    }
+
+    fn extract_at_cluster_time(
+        &self,
+        _response: &bson::RawDocument,
+    ) -> Result<Option<bson::Timestamp>> {
+        Ok(None)
+    }
+
+    fn handle_response(
+        &self,
+        response: RawCommandResponse,
+        _description: &StreamDescription,
+    ) -> Result<Self::O> {
+        response.body()
+    }
+
+    fn handle_error(&self, error: Error) -> Result<Self::O> {
+        Err(error)
+    }
+
+    fn selection_criteria(&self) -> Option<&SelectionCriteria> {
+        self.inner.selection_criteria()
+    }
+
+    fn override_criteria(&self) -> OverrideCriteriaFn {
+        self.inner.override_criteria()
+    }
+
+    fn is_acknowledged(&self) -> bool {
+        true
+    }
+
+    fn write_concern(&self) -> Option<&WriteConcern> {
+        None
+    }
+
+    fn supports_read_concern(&self, description: &StreamDescription) -> bool {
+        self.inner.supports_read_concern(description)
+    }
+
+    fn supports_sessions(&self) -> bool {
+        self.inner.supports_sessions()
+    }
+
+    fn retryability(&self) -> Retryability {
+        // Explaining a write does not perform it, so it must not be retried as a write (which
+        // would attach a transaction number).
+        match self.inner.retryability() {
+            Retryability::Read => Retryability::Read,
+            _ => Retryability::None,
+        }
+    }
+
+    fn can_retry_write(&self, description: &StreamDescription) -> bool {
+        self.inner.can_retry_write(description)
+    }
+
+    fn update_for_retry(&mut self) {
+        self.inner.update_for_retry()
+    }
+
+    fn pinned_connection(&self) -> Option<&PinnedConnectionHandle> {
+        self.inner.pinned_connection()
+    }
+
+    fn timeout(&self) -> Option<Duration> {
+        self.inner.timeout()
+    }
+
+    fn deadline(&self) -> Option<Instant> {
+        self.inner.deadline()
+    }
+
+    fn name(&self) -> &str {
+        Self::NAME
+    }
+}
@@ -0,0 +1,123 @@
+use crate::{
+    bson::{doc, Document},
+    cmap::StreamDescription,
+    coll::{
+        options::{DeleteOptions, DistinctOptions, ExplainVerbosity, Hint, UpdateOptions},
+        Namespace,
+    },
+    concern::{Acknowledgment, ReadConcern, WriteConcern},
+    operation::{
+        test::handle_response_test,
+        Delete,
+        Distinct,
+        Explain,
+        Operation,
+        Retryability,
+        Update,
+    },
+};
+
+fn ns() -> Namespace {
+    Namespace {
+        db: "test_db".to_string(),
+        coll: "test_coll".to_string(),
+    }
+}
+
+fn serialize<Op: Operation>(op: &mut Explain<Op>) -> Document {
+    let cmd = op
+        .build(&StreamDescription::new_testing())
+        .expect("error on build");
+    let serialized = op.serialize_command(cmd).expect("error on serialize");
+    Document::from_reader(serialized.as_slice()).unwrap()
+}
+
+#[test]
+fn build_read() {
+    let options = DistinctOptions::builder()
+        .read_concern(ReadConcern::majority())
+        .build();
+    let distinct = Distinct::new(ns(), "x".to_string(), Some(doc! { "y": 1 }), Some(options));
+    let mut op = Explain::new(distinct, ExplainVerbosity::QueryPlanner);
+
+    assert_eq!(
+        serialize(&mut op),
+        doc! {
+            "explain": {
+                "distinct": "test_coll",
+                "key": "x",
+                "query": { "y": 1 },
+            },
+            "verbosity": "queryPlanner",
+            "$db": "test_db",
+            "readConcern": { "level": "majority" },
+        }
+    );
+    assert_eq!(op.retryability(), Retryability::Read);
+}
+
+#[test]
+fn build_update() {
+    let options = UpdateOptions::builder()
+        .hint(Hint::Name("x_1".to_string()))
+        .write_concern(WriteConcern::builder().w(Acknowledgment::Majority).build())
+        .build();
+    let update = Update::with_update(
+        ns(),
+        doc! { "x": 1 },
+        doc! { "$set": { "x": 2 } }.into(),
+        false,
+        Some(options),
+        false,
+    );
+    let mut op = Explain::new(update, ExplainVerbosity::ExecutionStats);
+
+    let cmd = serialize(&mut op);
+    let explained = cmd.get_document("explain").unwrap();
+    assert_eq!(explained.get_str("update"), Ok("test_coll"));
+    assert_eq!(
+        explained.get_array("updates").unwrap()[0]
+            .as_document()
+            .unwrap()
+            .get_str("hint"),
+        Ok("x_1")
+    );
+    assert!(!explained.contains_key("writeConcern"));
+    assert!(!explained.contains_key("$db"));
+    assert_eq!(cmd.get_str("verbosity"), Ok("executionStats"));
+    assert!(op.write_concern().is_none());
+    assert_eq!(op.retryability(), Retryability::None);
+}
+
+#[test]
+fn build_delete() {
+    let options = DeleteOptions::builder()
+        .hint(Hint::Keys(doc! { "x": 1 }))
+        .build();
+    let delete = Delete::new(ns(), doc! { "x": 1 }, Some(1), Some(options));
+    let mut op = Explain::new(delete, ExplainVerbosity::AllPlansExecution);
+
+    let cmd = serialize(&mut op);
+    assert_eq!(
+        cmd.get_document("explain").unwrap(),
+        &doc! {
+            "delete": "test_coll",
+            "deletes": [{ "q": { "x": 1 }, "limit": 1, "hint": { "x": 1 } }],
+            "ordered": true,
+        }
+    );
+    assert_eq!(cmd.get_str("verbosity"), Ok("allPlansExecution"));
+}
+
+#[test]
+fn handle_success() {
+    let op = Explain::new(
+        Distinct::new(ns(), "x".to_string(), None, None),
+        ExplainVerbosity::QueryPlanner,
+    );
+    let response = doc! { "ok": 1, "queryPlanner": { "winningPlan": { "stage": "COLLSCAN" } } };
+    assert_eq!(
+        handle_response_test(&op, response.clone()).unwrap(),
+        response
+    );
+}
@@ -1,6 +1,6 @@
 mod options;
 
-use std::fmt::Debug;
+use std::{fmt::Debug, time::Duration};
 
 use bson::{from_slice, RawBson};
 use serde::{de::DeserializeOwned, Deserialize, Serialize};
@@ -171,6 +171,10 @@ impl<'a, R: Serialize, T: DeserializeOwned> OperationWithDefaults for FindAndMod
         self.options.as_ref().and_then(|o| o.write_concern.as_ref())
     }
 
+    fn timeout(&self) -> Option<Duration> {
+        self.options.as_ref().and_then(|opts| opts.timeout)
+    }
+
     fn retryability(&self) -> Retryability {
         Retryability::Write
     }
@@ -70,6 +70,10 @@ pub(super) struct FindAndModifyOptions {
 
     #[builder(default)]
     pub(crate) comment: Option<Bson>,
+
+    #[serde(skip)]
+    #[builder(default)]
+    pub(crate) timeout: Option<Duration>,
 }
 
 impl From<FindOneAndDeleteOptions> for FindAndModifyOptions {
@@ -87,6 +91,7 @@ impl From<FindOneAndDeleteOptions> for FindAndModifyOptions {
             hint: options.hint,
             let_vars: options.let_vars,
             comment: options.comment,
+            timeout: options.timeout,
         }
     }
 }
@@ -106,6 +111,7 @@ impl From<FindOneAndUpdateOptions> for FindAndModifyOptions {
             hint: options.hint,
             let_vars: options.let_vars,
             comment: options.comment,
+            timeout: options.timeout,
         }
     }
 }
@@ -125,6 +131,7 @@ impl From<FindOneAndReplaceOptions> for FindAndModifyOptions {
             hint: options.hint,
             let_vars: options.let_vars,
             comment: options.comment,
+            timeout: options.timeout,
         }
     }
 }
@@ -1,7 +1,10 @@
 #[cfg(test)]
 mod test;
 
-use std::{collections::VecDeque, time::Duration};
+use std::{
+    collections::VecDeque,
+    time::{Duration, Instant},
+};
 
 use bson::{Document, RawDocumentBuf};
 use serde::Deserialize;
@@ -27,6 +30,7 @@ pub(crate) struct GetMore<'conn> {
     max_time: Option<Duration>,
     pinned_connection: Option<&'conn PinnedConnectionHandle>,
     comment: Option<Bson>,
+    deadline: Option<Instant>,
 }
 
 impl<'conn> GetMore<'conn> {
@@ -42,6 +46,7 @@ impl<'conn> GetMore<'conn> {
             max_time: info.max_time,
             pinned_connection: pinned,
             comment: info.comment,
+            deadline: info.deadline,
         }
     }
 }
@@ -107,6 +112,10 @@ impl<'conn> OperationWithDefaults for GetMore<'conn> {
     fn pinned_connection(&self) -> Option<&PinnedConnectionHandle> {
         self.pinned_connection
     }
+
+    fn deadline(&self) -> Option<Instant> {
+        self.deadline
+    }
 }
 
 #[derive(Debug, Deserialize)]
@@ -1,7 +1,7 @@
 #[cfg(test)]
 mod test;
 
-use std::{collections::HashMap, convert::TryInto};
+use std::{collections::HashMap, convert::TryInto, time::Duration};
 
 use bson::{oid::ObjectId, Bson, RawArrayBuf, RawDocumentBuf};
 use serde::Serialize;
@@ -62,6 +62,11 @@ impl<'a, T> Insert<'a, T> {
         }
     }
 
+    /// The number of documents that were included in the most recently built command.
+    pub(crate) fn n_attempted(&self) -> usize {
+        self.inserted_ids.len()
+    }
+
     fn is_ordered(&self) -> bool {
         self.options
             .as_ref()
@@ -194,6 +199,7 @@ impl<'a, T: Serialize> OperationWithDefaults for Insert<'a, T> {
                     write_errors: response.write_errors,
                     write_concern_error: response.write_concern_error,
                     inserted_ids: map,
+                    partial_result: None,
                 }),
                 response.labels,
             ));
@@ -206,6 +212,10 @@ impl<'a, T: Serialize> OperationWithDefaults for Insert<'a, T> {
         self.options.as_ref().and_then(|o| o.write_concern.as_ref())
     }
 
+    fn timeout(&self) -> Option<Duration> {
+        self.options.as_ref().and_then(|opts| opts.timeout)
+    }
+
     fn retryability(&self) -> Retryability {
         Retryability::Write
     }
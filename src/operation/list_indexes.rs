@@ -1,3 +1,5 @@
+use std::time::Duration;
+
 use crate::{
     bson::{doc, Document},
     cmap::{Command, RawCommandResponse, StreamDescription},
@@ -80,4 +82,8 @@ impl OperationWithDefaults for ListIndexes {
     fn retryability(&self) -> Retryability {
         Retryability::Read
     }
+
+    fn timeout(&self) -> Option<Duration> {
+        self.options.as_ref().and_then(|o| o.timeout)
+    }
 }
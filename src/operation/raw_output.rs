@@ -3,7 +3,7 @@ use crate::{
     error::Result,
 };
 
-use super::Operation;
+use super::{Operation, OverrideCriteriaFn};
 
 /// Forwards all implementation to the wrapped `Operation`, but returns the response unparsed and
 /// unvalidated as a `RawCommandResponse`.
@@ -46,6 +46,10 @@ impl<Op: Operation> Operation for RawOutput<Op> {
         self.0.selection_criteria()
     }
 
+    fn override_criteria(&self) -> OverrideCriteriaFn {
+        self.0.override_criteria()
+    }
+
     fn is_acknowledged(&self) -> bool {
         self.0.is_acknowledged()
     }
@@ -66,6 +70,10 @@ impl<Op: Operation> Operation for RawOutput<Op> {
         self.0.retryability()
     }
 
+    fn can_retry_write(&self, description: &StreamDescription) -> bool {
+        self.0.can_retry_write(description)
+    }
+
     fn update_for_retry(&mut self) {
         self.0.update_for_retry()
     }
@@ -74,6 +82,14 @@ impl<Op: Operation> Operation for RawOutput<Op> {
         self.0.pinned_connection()
     }
 
+    fn timeout(&self) -> Option<std::time::Duration> {
+        self.0.timeout()
+    }
+
+    fn deadline(&self) -> Option<std::time::Instant> {
+        self.0.deadline()
+    }
+
     fn name(&self) -> &str {
         self.0.name()
     }
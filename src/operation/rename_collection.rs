@@ -0,0 +1,76 @@
+#[cfg(test)]
+mod test;
+
+use bson::Document;
+
+use crate::{
+    bson::doc,
+    cmap::{Command, RawCommandResponse, StreamDescription},
+    error::Result,
+    operation::{OperationWithDefaults, WriteConcernOnlyBody},
+    options::WriteConcern,
+    Namespace,
+};
+
+#[derive(Debug)]
+pub(crate) struct RenameCollection {
+    from: Namespace,
+    to: Namespace,
+    drop_target: bool,
+    write_concern: Option<WriteConcern>,
+}
+
+impl RenameCollection {
+    pub(crate) fn new(
+        from: Namespace,
+        to: Namespace,
+        drop_target: bool,
+        write_concern: Option<WriteConcern>,
+    ) -> Self {
+        Self {
+            from,
+            to,
+            drop_target,
+            write_concern,
+        }
+    }
+}
+
+impl OperationWithDefaults for RenameCollection {
+    type O = ();
+    type Command = Document;
+
+    const NAME: &'static str = "renameCollection";
+
+    fn build(&mut self, _description: &StreamDescription) -> Result<Command> {
+        let mut body = doc! {
+            Self::NAME: self.from.to_string(),
+            "to": self.to.to_string(),
+            "dropTarget": self.drop_target,
+        };
+        if let Some(ref write_concern) = self.write_concern {
+            if !write_concern.is_empty() {
+                body.insert("writeConcern", bson::to_bson(write_concern)?);
+            }
+        }
+
+        Ok(Command::new(
+            Self::NAME.to_string(),
+            "admin".to_string(),
+            body,
+        ))
+    }
+
+    fn handle_response(
+        &self,
+        response: RawCommandResponse,
+        _description: &StreamDescription,
+    ) -> Result<Self::O> {
+        let response: WriteConcernOnlyBody = response.body()?;
+        response.validate()
+    }
+
+    fn write_concern(&self) -> Option<&WriteConcern> {
+        self.write_concern.as_ref()
+    }
+}
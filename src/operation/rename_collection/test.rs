@@ -0,0 +1,52 @@
+use crate::{
+    bson::doc,
+    cmap::StreamDescription,
+    concern::WriteConcern,
+    operation::{test::handle_response_test, Operation, RenameCollection},
+    Namespace,
+};
+
+#[test]
+fn build() {
+    let mut op = RenameCollection::new(
+        Namespace {
+            db: "test_db".to_string(),
+            coll: "from".to_string(),
+        },
+        Namespace {
+            db: "other_db".to_string(),
+            coll: "to".to_string(),
+        },
+        true,
+        Some(WriteConcern {
+            journal: Some(true),
+            ..Default::default()
+        }),
+    );
+
+    let description = StreamDescription::new_testing();
+    let cmd = op.build(&description).unwrap();
+
+    assert_eq!(cmd.name.as_str(), "renameCollection");
+    assert_eq!(cmd.target_db.as_str(), "admin");
+    assert_eq!(
+        cmd.body,
+        doc! {
+            "renameCollection": "test_db.from",
+            "to": "other_db.to",
+            "dropTarget": true,
+            "writeConcern": { "j": true },
+        }
+    );
+}
+
+#[test]
+fn handle_success() {
+    let op = RenameCollection::new(
+        Namespace::new("test_db", "from"),
+        Namespace::new("test_db", "to"),
+        false,
+        None,
+    );
+    handle_response_test(&op, doc! { "ok": 1.0 }).unwrap();
+}
@@ -1,7 +1,7 @@
 #[cfg(test)]
 mod test;
 
-use std::convert::TryInto;
+use std::{convert::TryInto, time::Duration};
 
 use bson::{RawBsonRef, RawDocumentBuf};
 
@@ -20,6 +20,7 @@ pub(crate) struct RunCommand<'conn> {
     command: RawDocumentBuf,
     selection_criteria: Option<SelectionCriteria>,
     pinned_connection: Option<&'conn PinnedConnectionHandle>,
+    timeout: Option<Duration>,
 }
 
 impl<'conn> RunCommand<'conn> {
@@ -28,12 +29,14 @@ impl<'conn> RunCommand<'conn> {
         command: Document,
         selection_criteria: Option<SelectionCriteria>,
         pinned_connection: Option<&'conn PinnedConnectionHandle>,
+        timeout: Option<Duration>,
     ) -> Result<Self> {
         Ok(Self {
             db,
             command: RawDocumentBuf::from_document(&command)?,
             selection_criteria,
             pinned_connection,
+            timeout,
         })
     }
 
@@ -43,12 +46,14 @@ impl<'conn> RunCommand<'conn> {
         command: RawDocumentBuf,
         selection_criteria: Option<SelectionCriteria>,
         pinned_connection: Option<&'conn PinnedConnectionHandle>,
+        timeout: Option<Duration>,
     ) -> Result<Self> {
         Ok(Self {
             db,
             command,
             selection_criteria,
             pinned_connection,
+            timeout,
         })
     }
 
@@ -117,4 +122,8 @@ impl<'conn> OperationWithDefaults for RunCommand<'conn> {
     fn pinned_connection(&self) -> Option<&PinnedConnectionHandle> {
         self.pinned_connection
     }
+
+    fn timeout(&self) -> Option<Duration> {
+        self.timeout
+    }
 }
@@ -5,7 +5,7 @@ use crate::{bson::doc, operation::test::handle_response_test};
 
 #[test]
 fn handle_success() {
-    let op = RunCommand::new("foo".into(), doc! { "hello": 1 }, None, None).unwrap();
+    let op = RunCommand::new("foo".into(), doc! { "hello": 1 }, None, None, None).unwrap();
 
     let doc = doc! {
         "ok": 1,
@@ -5,7 +5,7 @@ use crate::{
     concern::WriteConcern,
     cursor::CursorSpecification,
     error::{Error, Result},
-    operation::{CursorBody, Operation, RunCommand},
+    operation::{CursorBody, Operation, OverrideCriteriaFn, RunCommand},
     options::RunCursorCommandOptions,
     selection_criteria::SelectionCriteria,
 };
@@ -57,6 +57,10 @@ impl<'conn> Operation for RunCursorCommand<'conn> {
         self.run_command.selection_criteria()
     }
 
+    fn override_criteria(&self) -> OverrideCriteriaFn {
+        self.run_command.override_criteria()
+    }
+
     fn is_acknowledged(&self) -> bool {
         self.run_command.is_acknowledged()
     }
@@ -77,6 +81,10 @@ impl<'conn> Operation for RunCursorCommand<'conn> {
         self.run_command.retryability()
     }
 
+    fn can_retry_write(&self, description: &StreamDescription) -> bool {
+        self.run_command.can_retry_write(description)
+    }
+
     fn update_for_retry(&mut self) {
         self.run_command.update_for_retry()
     }
@@ -85,6 +93,17 @@ impl<'conn> Operation for RunCursorCommand<'conn> {
         self.run_command.pinned_connection()
     }
 
+    fn timeout(&self) -> Option<std::time::Duration> {
+        self.options
+            .as_ref()
+            .and_then(|opts| opts.timeout)
+            .or_else(|| self.run_command.timeout())
+    }
+
+    fn deadline(&self) -> Option<std::time::Instant> {
+        self.run_command.deadline()
+    }
+
     fn name(&self) -> &str {
         self.run_command.name()
     }
@@ -0,0 +1,187 @@
+#[cfg(test)]
+mod test;
+
+use serde::Deserialize;
+
+use crate::{
+    bson::{doc, Document},
+    cmap::{Command, RawCommandResponse, StreamDescription},
+    error::{Error, Result},
+    operation::{append_options, OperationWithDefaults},
+    search_index::{
+        options::{CreateSearchIndexOptions, DropSearchIndexOptions, UpdateSearchIndexOptions},
+        SearchIndexModel,
+    },
+    Namespace,
+};
+
+#[derive(Debug)]
+pub(crate) struct CreateSearchIndexes {
+    ns: Namespace,
+    indexes: Vec<SearchIndexModel>,
+    options: Option<CreateSearchIndexOptions>,
+}
+
+impl CreateSearchIndexes {
+    pub(crate) fn new(
+        ns: Namespace,
+        indexes: Vec<SearchIndexModel>,
+        options: Option<CreateSearchIndexOptions>,
+    ) -> Self {
+        Self {
+            ns,
+            indexes,
+            options,
+        }
+    }
+}
+
+impl OperationWithDefaults for CreateSearchIndexes {
+    type O = Vec<String>;
+    type Command = Document;
+    const NAME: &'static str = "createSearchIndexes";
+
+    fn build(&mut self, _description: &StreamDescription) -> Result<Command> {
+        let mut body = doc! {
+            Self::NAME: self.ns.coll.clone(),
+            "indexes": bson::to_bson(&self.indexes)?,
+        };
+        append_options(&mut body, self.options.as_ref())?;
+
+        Ok(Command::new(
+            Self::NAME.to_string(),
+            self.ns.db.clone(),
+            body,
+        ))
+    }
+
+    fn handle_response(
+        &self,
+        response: RawCommandResponse,
+        _description: &StreamDescription,
+    ) -> Result<Self::O> {
+        #[derive(Debug, Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct Response {
+            indexes_created: Vec<CreatedIndex>,
+        }
+
+        #[derive(Debug, Deserialize)]
+        struct CreatedIndex {
+            name: String,
+        }
+
+        let response: Response = response.body()?;
+        Ok(response
+            .indexes_created
+            .into_iter()
+            .map(|index| index.name)
+            .collect())
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct UpdateSearchIndex {
+    ns: Namespace,
+    name: String,
+    definition: Document,
+    options: Option<UpdateSearchIndexOptions>,
+}
+
+impl UpdateSearchIndex {
+    pub(crate) fn new(
+        ns: Namespace,
+        name: String,
+        definition: Document,
+        options: Option<UpdateSearchIndexOptions>,
+    ) -> Self {
+        Self {
+            ns,
+            name,
+            definition,
+            options,
+        }
+    }
+}
+
+impl OperationWithDefaults for UpdateSearchIndex {
+    type O = ();
+    type Command = Document;
+    const NAME: &'static str = "updateSearchIndex";
+
+    fn build(&mut self, _description: &StreamDescription) -> Result<Command> {
+        let mut body = doc! {
+            Self::NAME: self.ns.coll.clone(),
+            "name": self.name.clone(),
+            "definition": self.definition.clone(),
+        };
+        append_options(&mut body, self.options.as_ref())?;
+
+        Ok(Command::new(
+            Self::NAME.to_string(),
+            self.ns.db.clone(),
+            body,
+        ))
+    }
+
+    fn handle_response(
+        &self,
+        _response: RawCommandResponse,
+        _description: &StreamDescription,
+    ) -> Result<Self::O> {
+        Ok(())
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct DropSearchIndex {
+    ns: Namespace,
+    name: String,
+    options: Option<DropSearchIndexOptions>,
+}
+
+impl DropSearchIndex {
+    pub(crate) fn new(
+        ns: Namespace,
+        name: String,
+        options: Option<DropSearchIndexOptions>,
+    ) -> Self {
+        Self { ns, name, options }
+    }
+}
+
+impl OperationWithDefaults for DropSearchIndex {
+    type O = ();
+    type Command = Document;
+    const NAME: &'static str = "dropSearchIndex";
+
+    fn build(&mut self, _description: &StreamDescription) -> Result<Command> {
+        let mut body = doc! {
+            Self::NAME: self.ns.coll.clone(),
+            "name": self.name.clone(),
+        };
+        append_options(&mut body, self.options.as_ref())?;
+
+        Ok(Command::new(
+            Self::NAME.to_string(),
+            self.ns.db.clone(),
+            body,
+        ))
+    }
+
+    fn handle_response(
+        &self,
+        _response: RawCommandResponse,
+        _description: &StreamDescription,
+    ) -> Result<Self::O> {
+        Ok(())
+    }
+
+    fn handle_error(&self, error: Error) -> Result<Self::O> {
+        if error.is_ns_not_found() {
+            Ok(())
+        } else {
+            Err(error)
+        }
+    }
+}
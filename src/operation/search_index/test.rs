@@ -0,0 +1,108 @@
+use crate::{
+    bson::doc,
+    cmap::StreamDescription,
+    error::{CommandError, Error, ErrorKind},
+    operation::{
+        test::handle_response_test,
+        CreateSearchIndexes,
+        DropSearchIndex,
+        Operation,
+        UpdateSearchIndex,
+    },
+    search_index::{options::CreateSearchIndexOptions, SearchIndexModel, SearchIndexType},
+    Namespace,
+};
+
+#[test]
+fn build_create() {
+    let model = SearchIndexModel::builder()
+        .definition(doc! { "mappings": { "dynamic": true } })
+        .name("foo".to_string())
+        .build();
+    let vector_model = SearchIndexModel::builder()
+        .definition(doc! { "fields": [] })
+        .index_type(SearchIndexType::VectorSearch)
+        .build();
+    let options = CreateSearchIndexOptions::builder()
+        .comment(bson::Bson::from("hi"))
+        .build();
+    let mut op = CreateSearchIndexes::new(
+        Namespace::new("test_db", "test_coll"),
+        vec![model, vector_model],
+        Some(options),
+    );
+
+    let cmd = op.build(&StreamDescription::new_testing()).unwrap();
+    assert_eq!(cmd.target_db, "test_db");
+    assert_eq!(
+        cmd.body,
+        doc! {
+            "createSearchIndexes": "test_coll",
+            "indexes": [
+                { "definition": { "mappings": { "dynamic": true } }, "name": "foo" },
+                { "definition": { "fields": [] }, "type": "vectorSearch" },
+            ],
+            "comment": "hi",
+        }
+    );
+}
+
+#[test]
+fn handle_create_response() {
+    let op = CreateSearchIndexes::new(Namespace::new("db", "coll"), Vec::new(), None);
+    let response = doc! {
+        "ok": 1,
+        "indexesCreated": [
+            { "id": "6524096020da840844a4c4a7", "name": "foo" },
+            { "id": "6524096020da840844a4c4a8", "name": "default" },
+        ],
+    };
+    let names = handle_response_test(&op, response).unwrap();
+    assert_eq!(names, vec!["foo".to_string(), "default".to_string()]);
+}
+
+#[test]
+fn build_update_and_drop() {
+    let ns = Namespace::new("test_db", "test_coll");
+
+    let mut update = UpdateSearchIndex::new(
+        ns.clone(),
+        "foo".to_string(),
+        doc! { "mappings": { "dynamic": false } },
+        None,
+    );
+    let cmd = update.build(&StreamDescription::new_testing()).unwrap();
+    assert_eq!(
+        cmd.body,
+        doc! {
+            "updateSearchIndex": "test_coll",
+            "name": "foo",
+            "definition": { "mappings": { "dynamic": false } },
+        }
+    );
+
+    let mut drop = DropSearchIndex::new(ns, "foo".to_string(), None);
+    let cmd = drop.build(&StreamDescription::new_testing()).unwrap();
+    assert_eq!(
+        cmd.body,
+        doc! {
+            "dropSearchIndex": "test_coll",
+            "name": "foo",
+        }
+    );
+}
+
+#[test]
+fn drop_ignores_ns_not_found() {
+    let op = DropSearchIndex::new(Namespace::new("db", "coll"), "foo".to_string(), None);
+    let ns_not_found = Error::new(
+        ErrorKind::Command(CommandError {
+            code: 26,
+            code_name: "NamespaceNotFound".to_string(),
+            message: "ns not found".to_string(),
+            topology_version: None,
+        }),
+        None::<Vec<String>>,
+    );
+    assert!(op.handle_error(ns_not_found).is_ok());
+}
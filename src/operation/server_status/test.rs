@@ -0,0 +1,56 @@
+use crate::{
+    bson::{doc, DateTime},
+    cmap::StreamDescription,
+    operation::{test::handle_response_test, Operation, ServerStatus},
+    options::ServerStatusOptions,
+};
+
+#[test]
+fn build() {
+    let mut op = ServerStatus::new(Some(
+        ServerStatusOptions::builder()
+            .sections(doc! { "repl": 0, "latchAnalysis": 1 })
+            .build(),
+    ));
+
+    let cmd = op.build(&StreamDescription::new_testing()).unwrap();
+    assert_eq!(cmd.name.as_str(), "serverStatus");
+    assert_eq!(cmd.target_db.as_str(), "admin");
+    assert_eq!(
+        cmd.body,
+        doc! { "serverStatus": 1, "repl": 0, "latchAnalysis": 1 }
+    );
+}
+
+#[test]
+fn handle_success() {
+    let op = ServerStatus::new(None);
+    let local_time = DateTime::now();
+    let response = doc! {
+        "ok": 1.0,
+        "host": "localhost:27017",
+        "version": "7.0.2",
+        "process": "mongod",
+        "pid": 4242_i64,
+        "uptime": 120.0,
+        "uptimeMillis": 120005_i64,
+        "uptimeEstimate": 120_i64,
+        "localTime": local_time,
+        "connections": { "current": 5, "available": 100 },
+    };
+
+    let status = handle_response_test(&op, response).unwrap();
+    assert_eq!(status.host, "localhost:27017");
+    assert_eq!(status.version, "7.0.2");
+    assert_eq!(status.process, "mongod");
+    assert_eq!(status.pid, 4242);
+    assert_eq!(status.uptime_millis, Some(120005));
+    assert_eq!(status.local_time, local_time);
+    assert_eq!(
+        status.extra,
+        doc! {
+            "uptimeEstimate": 120_i64,
+            "connections": { "current": 5, "available": 100 },
+        }
+    );
+}
@@ -1,16 +1,19 @@
 #[cfg(test)]
 mod test;
 
+use std::time::Duration;
+
 use serde::{Deserialize, Serialize};
 
 use crate::{
     bson::{doc, rawdoc, Document, RawArrayBuf, RawBson, RawDocumentBuf},
     bson_util,
     cmap::{Command, RawCommandResponse, StreamDescription},
+    collation::Collation,
     error::{convert_bulk_errors, Result},
     operation::{OperationWithDefaults, Retryability, WriteResponseBody},
-    options::{UpdateModifications, UpdateOptions, WriteConcern},
-    results::UpdateResult,
+    options::{Hint, UpdateModifications, UpdateOptions, WriteConcern},
+    results::{BulkWriteResult, UpdateResult},
     serde_util::to_raw_document_buf_with_options,
     Namespace,
 };
@@ -52,12 +55,114 @@ impl<'a, T: Serialize> From<&'a T> for UpdateOrReplace<'a, T> {
     }
 }
 
+/// A single update or replacement sent as one of the statements of an [`Update`] or a
+/// [`BulkUpdate`].
+#[derive(Debug)]
+pub(crate) struct UpdateStatement<'a, T = ()> {
+    pub(crate) filter: Document,
+    pub(crate) update: UpdateOrReplace<'a, T>,
+    pub(crate) multi: bool,
+    pub(crate) upsert: Option<bool>,
+    pub(crate) array_filters: Option<Vec<Document>>,
+    pub(crate) collation: Option<Collation>,
+    pub(crate) hint: Option<Hint>,
+}
+
+impl<'a, T> UpdateStatement<'a, T> {
+    /// Creates a statement, taking the options that apply to individual statements out of
+    /// `options`.
+    fn new(
+        filter: Document,
+        update: UpdateOrReplace<'a, T>,
+        multi: bool,
+        options: Option<&mut UpdateOptions>,
+    ) -> Self {
+        match options {
+            Some(options) => Self {
+                filter,
+                update,
+                multi,
+                upsert: options.upsert,
+                array_filters: options.array_filters.take(),
+                collation: options.collation.take(),
+                hint: options.hint.take(),
+            },
+            None => Self {
+                filter,
+                update,
+                multi,
+                upsert: None,
+                array_filters: None,
+                collation: None,
+                hint: None,
+            },
+        }
+    }
+}
+
+impl<'a, T: Serialize> UpdateStatement<'a, T> {
+    fn to_raw_document(&self, human_readable_serialization: bool) -> Result<RawDocumentBuf> {
+        let mut statement = rawdoc! {
+            "q": RawDocumentBuf::from_document(&self.filter)?,
+            "u": self.update.to_raw_bson(human_readable_serialization)?,
+        };
+
+        if let Some(upsert) = self.upsert {
+            statement.append("upsert", upsert);
+        }
+
+        if let Some(ref array_filters) = self.array_filters {
+            statement.append("arrayFilters", bson_util::to_raw_bson_array(array_filters)?);
+        }
+
+        if let Some(ref hint) = self.hint {
+            statement.append("hint", hint.to_raw_bson()?);
+        }
+
+        if let Some(ref collation) = self.collation {
+            statement.append("collation", bson::to_raw_document_buf(collation)?);
+        }
+
+        if self.multi {
+            statement.append("multi", true);
+        }
+
+        Ok(statement)
+    }
+}
+
+/// Appends the options that apply to the `update` command as a whole to `body`.
+fn append_command_options(
+    body: &mut RawDocumentBuf,
+    options: Option<&UpdateOptions>,
+) -> Result<()> {
+    if let Some(options) = options {
+        if let Some(bypass_doc_validation) = options.bypass_document_validation {
+            body.append("bypassDocumentValidation", bypass_doc_validation);
+        }
+
+        if let Some(ref write_concern) = options.write_concern {
+            if !write_concern.is_empty() {
+                body.append("writeConcern", bson::to_raw_document_buf(write_concern)?);
+            }
+        }
+
+        if let Some(ref let_vars) = options.let_vars {
+            body.append("let", bson::to_raw_document_buf(&let_vars)?);
+        }
+
+        if let Some(ref comment) = options.comment {
+            body.append("comment", RawBson::try_from(comment.clone())?);
+        }
+    }
+
+    Ok(())
+}
+
 #[derive(Debug)]
 pub(crate) struct Update<'a, T = ()> {
     ns: Namespace,
-    filter: Document,
-    update: UpdateOrReplace<'a, T>,
-    multi: Option<bool>,
+    statement: UpdateStatement<'a, T>,
     options: Option<UpdateOptions>,
     human_readable_serialization: bool,
 }
@@ -80,14 +185,12 @@ impl Update<'_> {
         filter: Document,
         update: UpdateModifications,
         multi: bool,
-        options: Option<UpdateOptions>,
+        mut options: Option<UpdateOptions>,
         human_readable_serialization: bool,
     ) -> Self {
         Self {
             ns,
-            filter,
-            update: update.into(),
-            multi: multi.then(|| true),
+            statement: UpdateStatement::new(filter, update.into(), multi, options.as_mut()),
             options,
             human_readable_serialization,
         }
@@ -100,14 +203,12 @@ impl<'a, T: Serialize> Update<'a, T> {
         filter: Document,
         update: &'a T,
         multi: bool,
-        options: Option<UpdateOptions>,
+        mut options: Option<UpdateOptions>,
         human_readable_serialization: bool,
     ) -> Self {
         Self {
             ns,
-            filter,
-            update: update.into(),
-            multi: multi.then(|| true),
+            statement: UpdateStatement::new(filter, update.into(), multi, options.as_mut()),
             options,
             human_readable_serialization,
         }
@@ -124,54 +225,13 @@ impl<'a, T: Serialize> OperationWithDefaults for Update<'a, T> {
         let mut body = rawdoc! {
             Self::NAME: self.ns.coll.clone(),
         };
-
-        let mut update = rawdoc! {
-            "q": RawDocumentBuf::from_document(&self.filter)?,
-            "u": self.update.to_raw_bson(self.human_readable_serialization)?,
-        };
-
-        if let Some(ref options) = self.options {
-            if let Some(upsert) = options.upsert {
-                update.append("upsert", upsert);
-            }
-
-            if let Some(ref array_filters) = options.array_filters {
-                update.append("arrayFilters", bson_util::to_raw_bson_array(array_filters)?);
-            }
-
-            if let Some(ref hint) = options.hint {
-                update.append("hint", hint.to_raw_bson()?);
-            }
-
-            if let Some(ref collation) = options.collation {
-                update.append("collation", bson::to_raw_document_buf(&collation)?);
-            }
-
-            if let Some(bypass_doc_validation) = options.bypass_document_validation {
-                body.append("bypassDocumentValidation", bypass_doc_validation);
-            }
-
-            if let Some(ref write_concern) = options.write_concern {
-                if !write_concern.is_empty() {
-                    body.append("writeConcern", bson::to_raw_document_buf(write_concern)?);
-                }
-            }
-
-            if let Some(ref let_vars) = options.let_vars {
-                body.append("let", bson::to_raw_document_buf(&let_vars)?);
-            }
-
-            if let Some(ref comment) = options.comment {
-                body.append("comment", RawBson::try_from(comment.clone())?);
-            }
-        };
-
-        if let Some(multi) = self.multi {
-            update.append("multi", multi);
-        }
+        append_command_options(&mut body, self.options.as_ref())?;
 
         let mut updates = RawArrayBuf::new();
-        updates.push(update);
+        updates.push(
+            self.statement
+                .to_raw_document(self.human_readable_serialization)?,
+        );
         body.append("updates", updates);
         body.append("ordered", true); // command monitoring tests expect this (SPEC-1130)
 
@@ -217,8 +277,12 @@ impl<'a, T: Serialize> OperationWithDefaults for Update<'a, T> {
             .and_then(|opts| opts.write_concern.as_ref())
     }
 
+    fn timeout(&self) -> Option<Duration> {
+        self.options.as_ref().and_then(|opts| opts.timeout)
+    }
+
     fn retryability(&self) -> Retryability {
-        if self.multi != Some(true) {
+        if !self.statement.multi {
             Retryability::Write
         } else {
             Retryability::None
@@ -226,6 +290,155 @@ impl<'a, T: Serialize> OperationWithDefaults for Update<'a, T> {
     }
 }
 
+/// An `update` command made up of multiple statements, as used by
+/// [`Collection::bulk_write`](crate::Collection::bulk_write). Only as many statements as fit into
+/// a single command are sent; `n_attempted` reports how many that was. If `verbose` is set, only
+/// one statement is sent at a time so that its result can be reported in
+/// [`BulkWriteResult::update_results`].
+#[derive(Debug)]
+pub(crate) struct BulkUpdate<'a, T = ()> {
+    ns: Namespace,
+    statements: Vec<UpdateStatement<'a, T>>,
+    ordered: bool,
+    options: Option<UpdateOptions>,
+    verbose: bool,
+    n_attempted: usize,
+    human_readable_serialization: bool,
+}
+
+impl<'a, T> BulkUpdate<'a, T> {
+    /// Creates an update made up of `statements`. Only the command-level options in `options`
+    /// are used; the options for each statement are taken from the statement itself.
+    pub(crate) fn new(
+        ns: Namespace,
+        statements: Vec<UpdateStatement<'a, T>>,
+        ordered: bool,
+        options: Option<UpdateOptions>,
+        verbose: bool,
+        human_readable_serialization: bool,
+    ) -> Self {
+        Self {
+            ns,
+            statements,
+            ordered,
+            options,
+            verbose,
+            n_attempted: 0,
+            human_readable_serialization,
+        }
+    }
+
+    /// The number of statements that were included in the most recently built command.
+    pub(crate) fn n_attempted(&self) -> usize {
+        self.n_attempted
+    }
+}
+
+impl<'a, T: Serialize> OperationWithDefaults for BulkUpdate<'a, T> {
+    type O = BulkWriteResult;
+    type Command = RawDocumentBuf;
+
+    const NAME: &'static str = "update";
+
+    fn build(&mut self, description: &StreamDescription) -> Result<Command<Self::Command>> {
+        let mut body = rawdoc! {
+            Self::NAME: self.ns.coll.clone(),
+        };
+        append_command_options(&mut body, self.options.as_ref())?;
+
+        let max_statements = if self.verbose {
+            1
+        } else {
+            description.max_write_batch_size as usize
+        };
+        let mut updates = RawArrayBuf::new();
+        let mut size = 0;
+        let mut n_attempted = 0;
+        for (i, statement) in self.statements.iter().take(max_statements).enumerate() {
+            let statement = statement.to_raw_document(self.human_readable_serialization)?;
+            let statement_size = bson_util::array_entry_size_bytes(i, statement.as_bytes().len());
+            // The first statement is always sent so that the server can report it if it is too
+            // large.
+            if n_attempted > 0 && size + statement_size > description.max_bson_object_size as u64 {
+                break;
+            }
+            updates.push(statement);
+            size += statement_size;
+            n_attempted += 1;
+        }
+        self.n_attempted = n_attempted;
+
+        body.append("updates", updates);
+        body.append("ordered", self.ordered);
+
+        Ok(Command::new(
+            Self::NAME.to_string(),
+            self.ns.db.clone(),
+            body,
+        ))
+    }
+
+    fn serialize_command(&mut self, cmd: Command<Self::Command>) -> Result<Vec<u8>> {
+        cmd.into_bson_bytes()
+    }
+
+    fn handle_response(
+        &self,
+        raw_response: RawCommandResponse,
+        _description: &StreamDescription,
+    ) -> Result<Self::O> {
+        let response: WriteResponseBody<UpdateBody> = raw_response.body_utf8_lossy()?;
+
+        let upserted_count = response.upserted.as_ref().map_or(0, Vec::len) as u64;
+        let mut result = BulkWriteResult::new(self.verbose);
+        result.upserted_count = upserted_count;
+        result.matched_count = response.n.saturating_sub(upserted_count);
+        result.modified_count = response.n_modified;
+        // Verbose commands are made up of a single statement, which succeeded unless there is a
+        // write error.
+        if let Some(ref mut update_results) = result.update_results {
+            if response.write_errors.is_none() {
+                let upserted_id = response
+                    .upserted
+                    .as_ref()
+                    .and_then(|v| v.first())
+                    .and_then(|doc| doc.get("_id"))
+                    .cloned();
+                update_results.insert(
+                    0,
+                    UpdateResult {
+                        matched_count: result.matched_count,
+                        modified_count: result.modified_count,
+                        upserted_id,
+                    },
+                );
+            }
+        }
+
+        response.validate_with_partial_result(result.clone())?;
+
+        Ok(result)
+    }
+
+    fn write_concern(&self) -> Option<&WriteConcern> {
+        self.options
+            .as_ref()
+            .and_then(|opts| opts.write_concern.as_ref())
+    }
+
+    fn timeout(&self) -> Option<Duration> {
+        self.options.as_ref().and_then(|opts| opts.timeout)
+    }
+
+    fn retryability(&self) -> Retryability {
+        if self.statements.iter().any(|statement| statement.multi) {
+            Retryability::None
+        } else {
+            Retryability::Write
+        }
+    }
+}
+
 #[derive(Deserialize)]
 pub(crate) struct UpdateBody {
     #[serde(rename = "nModified")]
@@ -1,9 +1,20 @@
 use pretty_assertions::assert_eq;
 
 use crate::{
-    bson::{doc, Bson},
+    bson::{doc, Bson, Document},
+    cmap::StreamDescription,
     error::{ErrorKind, WriteConcernError, WriteError, WriteFailure},
-    operation::{test::handle_response_test, Update},
+    operation::{
+        test::handle_response_test,
+        BulkUpdate,
+        Operation,
+        Retryability,
+        Update,
+        UpdateOrReplace,
+        UpdateStatement,
+    },
+    options::{UpdateModifications, UpdateOptions},
+    Namespace,
 };
 
 #[test]
@@ -114,3 +125,213 @@ fn handle_write_concern_failure() {
         ref e => panic!("expected write concern error, got {:?}", e),
     }
 }
+
+#[test]
+fn build_bulk() {
+    let filter = doc! { "x": 1 };
+    let replacement = doc! { "x": 2 };
+    let statements = vec![
+        UpdateStatement {
+            filter: filter.clone(),
+            update: UpdateOrReplace::UpdateModifications(UpdateModifications::Document(
+                doc! { "$inc": { "y": 1 } },
+            )),
+            multi: true,
+            upsert: None,
+            array_filters: None,
+            collation: None,
+            hint: None,
+        },
+        UpdateStatement {
+            filter: filter.clone(),
+            update: UpdateOrReplace::Replacement(&replacement),
+            multi: false,
+            upsert: Some(true),
+            array_filters: None,
+            collation: None,
+            hint: None,
+        },
+    ];
+    let options = UpdateOptions::builder()
+        .bypass_document_validation(true)
+        .build();
+    let mut op = BulkUpdate::new(
+        Namespace::new("test_db", "test_coll"),
+        statements,
+        false,
+        Some(options),
+        false,
+        false,
+    );
+
+    let cmd = op.build(&StreamDescription::new_testing()).unwrap();
+    assert_eq!(cmd.name.as_str(), "update");
+    assert_eq!(cmd.target_db.as_str(), "test_db");
+    assert_eq!(op.n_attempted(), 2);
+    assert_eq!(
+        cmd.body.to_document().unwrap(),
+        doc! {
+            "update": "test_coll",
+            "bypassDocumentValidation": true,
+            "updates": [
+                { "q": { "x": 1 }, "u": { "$inc": { "y": 1 } }, "multi": true },
+                { "q": { "x": 1 }, "u": { "x": 2 }, "upsert": true },
+            ],
+            "ordered": false,
+        }
+    );
+    assert_eq!(op.retryability(), Retryability::None);
+}
+
+#[test]
+fn build_bulk_split_by_max_write_batch_size() {
+    let filter = doc! {};
+    let statements = (0..5)
+        .map(|_| UpdateStatement::<Document> {
+            filter: filter.clone(),
+            update: UpdateOrReplace::UpdateModifications(UpdateModifications::Document(
+                doc! { "$set": { "x": 1 } },
+            )),
+            multi: false,
+            upsert: None,
+            array_filters: None,
+            collation: None,
+            hint: None,
+        })
+        .collect();
+    let mut op = BulkUpdate::new(
+        Namespace::new("db", "coll"),
+        statements,
+        true,
+        None,
+        false,
+        false,
+    );
+
+    let mut description = StreamDescription::new_testing();
+    description.max_write_batch_size = 3;
+    let cmd = op.build(&description).unwrap();
+    assert_eq!(op.n_attempted(), 3);
+    assert_eq!(
+        cmd.body
+            .to_document()
+            .unwrap()
+            .get_array("updates")
+            .unwrap()
+            .len(),
+        3
+    );
+    assert_eq!(op.retryability(), Retryability::Write);
+}
+
+#[test]
+fn handle_bulk_success() {
+    let op = BulkUpdate::<Document>::new(
+        Namespace::new("db", "coll"),
+        Vec::new(),
+        true,
+        None,
+        false,
+        false,
+    );
+
+    let ok_response = doc! {
+        "ok": 1.0,
+        "n": 5,
+        "nModified": 2,
+        "upserted": [
+            { "index": 1, "_id": 1 },
+            { "index": 3, "_id": 2 },
+        ]
+    };
+
+    let result = handle_response_test(&op, ok_response).unwrap();
+    assert_eq!(result.matched_count, 3);
+    assert_eq!(result.modified_count, 2);
+    assert_eq!(result.upserted_count, 2);
+    assert!(result.update_results.is_none());
+}
+
+#[test]
+fn build_bulk_verbose() {
+    let statements = (0..2)
+        .map(|i| UpdateStatement::<Document> {
+            filter: doc! { "_id": i },
+            update: UpdateOrReplace::UpdateModifications(UpdateModifications::Document(
+                doc! { "$set": { "x": 1 } },
+            )),
+            multi: false,
+            upsert: Some(true),
+            array_filters: None,
+            collation: None,
+            hint: None,
+        })
+        .collect();
+    let mut op = BulkUpdate::new(
+        Namespace::new("db", "coll"),
+        statements,
+        true,
+        None,
+        true,
+        false,
+    );
+
+    // Each statement is sent on its own so that its result is known.
+    op.build(&StreamDescription::new_testing()).unwrap();
+    assert_eq!(op.n_attempted(), 1);
+
+    let ok_response = doc! {
+        "ok": 1.0,
+        "n": 1,
+        "nModified": 0,
+        "upserted": [
+            { "index": 0, "_id": 0 },
+        ]
+    };
+    let result = handle_response_test(&op, ok_response).unwrap();
+    assert_eq!(result.upserted_count, 1);
+    let update_result = &result.update_results.unwrap()[&0];
+    assert_eq!(update_result.matched_count, 0);
+    assert_eq!(update_result.modified_count, 0);
+    assert_eq!(update_result.upserted_id, Some(Bson::Int32(0)));
+}
+
+#[test]
+fn handle_write_failure_partial_result() {
+    let op = BulkUpdate::<Document>::new(
+        Namespace::new("db", "coll"),
+        Vec::new(),
+        true,
+        None,
+        false,
+        false,
+    );
+
+    let write_error_response = doc! {
+        "ok": 1.0,
+        "n": 2,
+        "nModified": 1,
+        "upserted": [
+            { "index": 1, "_id": 1 },
+        ],
+        "writeErrors": [
+            {
+                "index": 2,
+                "code": 11000,
+                "errmsg": "duplicate key"
+            }
+        ]
+    };
+
+    let error = handle_response_test(&op, write_error_response).unwrap_err();
+    match *error.kind {
+        ErrorKind::BulkWrite(ref failure) => {
+            let partial_result = failure.partial_result.as_ref().unwrap();
+            assert_eq!(partial_result.matched_count, 1);
+            assert_eq!(partial_result.modified_count, 1);
+            assert_eq!(partial_result.upserted_count, 1);
+            assert_eq!(failure.write_errors.as_ref().unwrap()[0].index, 2);
+        }
+        ref e => panic!("expected bulk write error, got {:?}", e),
+    }
+}
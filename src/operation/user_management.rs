@@ -0,0 +1,439 @@
+#[cfg(test)]
+mod test;
+
+use serde::Deserialize;
+
+use crate::{
+    bson::{doc, Bson, Document},
+    cmap::{Command, RawCommandResponse, StreamDescription},
+    error::Result,
+    operation::{
+        append_options,
+        remove_empty_write_concern,
+        OperationWithDefaults,
+        WriteConcernOnlyBody,
+    },
+    options::{
+        CreateRoleOptions,
+        CreateUserOptions,
+        DropRoleOptions,
+        DropUserOptions,
+        GrantRolesToUserOptions,
+        Role,
+        RolesInfoOptions,
+        UpdateUserOptions,
+        UsersInfoOptions,
+        WriteConcern,
+    },
+    results::{RoleInfo, UserInfo},
+};
+
+#[derive(Debug)]
+pub(crate) struct CreateUser {
+    db: String,
+    user: String,
+    options: Option<CreateUserOptions>,
+}
+
+impl CreateUser {
+    pub(crate) fn new(db: String, user: String, options: Option<CreateUserOptions>) -> Self {
+        Self { db, user, options }
+    }
+}
+
+impl OperationWithDefaults for CreateUser {
+    type O = ();
+    type Command = Document;
+
+    const NAME: &'static str = "createUser";
+
+    fn build(&mut self, _description: &StreamDescription) -> Result<Command> {
+        // The server requires the roles field to be present, even if no roles are granted.
+        let mut body = doc! {
+            Self::NAME: self.user.clone(),
+            "roles": [],
+        };
+
+        remove_empty_write_concern!(self.options);
+        append_options(&mut body, self.options.as_ref())?;
+
+        Ok(Command::new(Self::NAME.to_string(), self.db.clone(), body))
+    }
+
+    fn handle_response(
+        &self,
+        response: RawCommandResponse,
+        _description: &StreamDescription,
+    ) -> Result<Self::O> {
+        let response: WriteConcernOnlyBody = response.body()?;
+        response.validate()
+    }
+
+    fn write_concern(&self) -> Option<&WriteConcern> {
+        self.options
+            .as_ref()
+            .and_then(|opts| opts.write_concern.as_ref())
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct UpdateUser {
+    db: String,
+    user: String,
+    options: Option<UpdateUserOptions>,
+}
+
+impl UpdateUser {
+    pub(crate) fn new(db: String, user: String, options: Option<UpdateUserOptions>) -> Self {
+        Self { db, user, options }
+    }
+}
+
+impl OperationWithDefaults for UpdateUser {
+    type O = ();
+    type Command = Document;
+
+    const NAME: &'static str = "updateUser";
+
+    fn build(&mut self, _description: &StreamDescription) -> Result<Command> {
+        let mut body = doc! {
+            Self::NAME: self.user.clone(),
+        };
+
+        remove_empty_write_concern!(self.options);
+        append_options(&mut body, self.options.as_ref())?;
+
+        Ok(Command::new(Self::NAME.to_string(), self.db.clone(), body))
+    }
+
+    fn handle_response(
+        &self,
+        response: RawCommandResponse,
+        _description: &StreamDescription,
+    ) -> Result<Self::O> {
+        let response: WriteConcernOnlyBody = response.body()?;
+        response.validate()
+    }
+
+    fn write_concern(&self) -> Option<&WriteConcern> {
+        self.options
+            .as_ref()
+            .and_then(|opts| opts.write_concern.as_ref())
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct DropUser {
+    db: String,
+    user: String,
+    options: Option<DropUserOptions>,
+}
+
+impl DropUser {
+    pub(crate) fn new(db: String, user: String, options: Option<DropUserOptions>) -> Self {
+        Self { db, user, options }
+    }
+}
+
+impl OperationWithDefaults for DropUser {
+    type O = ();
+    type Command = Document;
+
+    const NAME: &'static str = "dropUser";
+
+    fn build(&mut self, _description: &StreamDescription) -> Result<Command> {
+        let mut body = doc! {
+            Self::NAME: self.user.clone(),
+        };
+
+        remove_empty_write_concern!(self.options);
+        append_options(&mut body, self.options.as_ref())?;
+
+        Ok(Command::new(Self::NAME.to_string(), self.db.clone(), body))
+    }
+
+    fn handle_response(
+        &self,
+        response: RawCommandResponse,
+        _description: &StreamDescription,
+    ) -> Result<Self::O> {
+        let response: WriteConcernOnlyBody = response.body()?;
+        response.validate()
+    }
+
+    fn write_concern(&self) -> Option<&WriteConcern> {
+        self.options
+            .as_ref()
+            .and_then(|opts| opts.write_concern.as_ref())
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct UsersInfo {
+    db: String,
+    users: Option<Vec<String>>,
+    options: Option<UsersInfoOptions>,
+}
+
+impl UsersInfo {
+    pub(crate) fn new(
+        db: String,
+        users: Option<Vec<String>>,
+        options: Option<UsersInfoOptions>,
+    ) -> Self {
+        Self { db, users, options }
+    }
+}
+
+impl OperationWithDefaults for UsersInfo {
+    type O = Vec<UserInfo>;
+    type Command = Document;
+
+    const NAME: &'static str = "usersInfo";
+
+    fn build(&mut self, _description: &StreamDescription) -> Result<Command> {
+        // Requesting `1` returns all of the users defined in the target database.
+        let users = match self.users {
+            Some(ref users) => Bson::Array(
+                users
+                    .iter()
+                    .map(|user| Bson::Document(doc! { "user": user, "db": self.db.clone() }))
+                    .collect(),
+            ),
+            None => Bson::Int32(1),
+        };
+        let mut body = doc! {
+            Self::NAME: users,
+        };
+
+        append_options(&mut body, self.options.as_ref())?;
+
+        Ok(Command::new(Self::NAME.to_string(), self.db.clone(), body))
+    }
+
+    fn handle_response(
+        &self,
+        response: RawCommandResponse,
+        _description: &StreamDescription,
+    ) -> Result<Self::O> {
+        #[derive(Debug, Deserialize)]
+        struct Response {
+            users: Vec<UserInfo>,
+        }
+
+        let response: Response = response.body()?;
+        Ok(response.users)
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct GrantRolesToUser {
+    db: String,
+    user: String,
+    roles: Vec<Role>,
+    options: Option<GrantRolesToUserOptions>,
+}
+
+impl GrantRolesToUser {
+    pub(crate) fn new(
+        db: String,
+        user: String,
+        roles: Vec<Role>,
+        options: Option<GrantRolesToUserOptions>,
+    ) -> Self {
+        Self {
+            db,
+            user,
+            roles,
+            options,
+        }
+    }
+}
+
+impl OperationWithDefaults for GrantRolesToUser {
+    type O = ();
+    type Command = Document;
+
+    const NAME: &'static str = "grantRolesToUser";
+
+    fn build(&mut self, _description: &StreamDescription) -> Result<Command> {
+        let mut body = doc! {
+            Self::NAME: self.user.clone(),
+            "roles": bson::to_bson(&self.roles)?,
+        };
+
+        remove_empty_write_concern!(self.options);
+        append_options(&mut body, self.options.as_ref())?;
+
+        Ok(Command::new(Self::NAME.to_string(), self.db.clone(), body))
+    }
+
+    fn handle_response(
+        &self,
+        response: RawCommandResponse,
+        _description: &StreamDescription,
+    ) -> Result<Self::O> {
+        let response: WriteConcernOnlyBody = response.body()?;
+        response.validate()
+    }
+
+    fn write_concern(&self) -> Option<&WriteConcern> {
+        self.options
+            .as_ref()
+            .and_then(|opts| opts.write_concern.as_ref())
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct CreateRole {
+    db: String,
+    role: String,
+    options: Option<CreateRoleOptions>,
+}
+
+impl CreateRole {
+    pub(crate) fn new(db: String, role: String, options: Option<CreateRoleOptions>) -> Self {
+        Self { db, role, options }
+    }
+}
+
+impl OperationWithDefaults for CreateRole {
+    type O = ();
+    type Command = Document;
+
+    const NAME: &'static str = "createRole";
+
+    fn build(&mut self, _description: &StreamDescription) -> Result<Command> {
+        // The server requires the privileges and roles fields to be present, even if they are
+        // empty.
+        let mut body = doc! {
+            Self::NAME: self.role.clone(),
+            "privileges": [],
+            "roles": [],
+        };
+
+        remove_empty_write_concern!(self.options);
+        append_options(&mut body, self.options.as_ref())?;
+
+        Ok(Command::new(Self::NAME.to_string(), self.db.clone(), body))
+    }
+
+    fn handle_response(
+        &self,
+        response: RawCommandResponse,
+        _description: &StreamDescription,
+    ) -> Result<Self::O> {
+        let response: WriteConcernOnlyBody = response.body()?;
+        response.validate()
+    }
+
+    fn write_concern(&self) -> Option<&WriteConcern> {
+        self.options
+            .as_ref()
+            .and_then(|opts| opts.write_concern.as_ref())
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct DropRole {
+    db: String,
+    role: String,
+    options: Option<DropRoleOptions>,
+}
+
+impl DropRole {
+    pub(crate) fn new(db: String, role: String, options: Option<DropRoleOptions>) -> Self {
+        Self { db, role, options }
+    }
+}
+
+impl OperationWithDefaults for DropRole {
+    type O = ();
+    type Command = Document;
+
+    const NAME: &'static str = "dropRole";
+
+    fn build(&mut self, _description: &StreamDescription) -> Result<Command> {
+        let mut body = doc! {
+            Self::NAME: self.role.clone(),
+        };
+
+        remove_empty_write_concern!(self.options);
+        append_options(&mut body, self.options.as_ref())?;
+
+        Ok(Command::new(Self::NAME.to_string(), self.db.clone(), body))
+    }
+
+    fn handle_response(
+        &self,
+        response: RawCommandResponse,
+        _description: &StreamDescription,
+    ) -> Result<Self::O> {
+        let response: WriteConcernOnlyBody = response.body()?;
+        response.validate()
+    }
+
+    fn write_concern(&self) -> Option<&WriteConcern> {
+        self.options
+            .as_ref()
+            .and_then(|opts| opts.write_concern.as_ref())
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct RolesInfo {
+    db: String,
+    roles: Option<Vec<String>>,
+    options: Option<RolesInfoOptions>,
+}
+
+impl RolesInfo {
+    pub(crate) fn new(
+        db: String,
+        roles: Option<Vec<String>>,
+        options: Option<RolesInfoOptions>,
+    ) -> Self {
+        Self { db, roles, options }
+    }
+}
+
+impl OperationWithDefaults for RolesInfo {
+    type O = Vec<RoleInfo>;
+    type Command = Document;
+
+    const NAME: &'static str = "rolesInfo";
+
+    fn build(&mut self, _description: &StreamDescription) -> Result<Command> {
+        // Requesting `1` returns all of the roles defined in the target database.
+        let roles = match self.roles {
+            Some(ref roles) => Bson::Array(
+                roles
+                    .iter()
+                    .map(|role| Bson::Document(doc! { "role": role, "db": self.db.clone() }))
+                    .collect(),
+            ),
+            None => Bson::Int32(1),
+        };
+        let mut body = doc! {
+            Self::NAME: roles,
+        };
+
+        append_options(&mut body, self.options.as_ref())?;
+
+        Ok(Command::new(Self::NAME.to_string(), self.db.clone(), body))
+    }
+
+    fn handle_response(
+        &self,
+        response: RawCommandResponse,
+        _description: &StreamDescription,
+    ) -> Result<Self::O> {
+        #[derive(Debug, Deserialize)]
+        struct Response {
+            roles: Vec<RoleInfo>,
+        }
+
+        let response: Response = response.body()?;
+        Ok(response.roles)
+    }
+}
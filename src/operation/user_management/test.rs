@@ -0,0 +1,255 @@
+use crate::{
+    bson::doc,
+    cmap::StreamDescription,
+    concern::{Acknowledgment, WriteConcern},
+    error::{ErrorKind, WriteFailure},
+    operation::{
+        test::handle_response_test,
+        CreateRole,
+        CreateUser,
+        GrantRolesToUser,
+        Operation,
+        RolesInfo,
+        UpdateUser,
+        UsersInfo,
+    },
+    options::{
+        AuthenticationRestriction,
+        CreateRoleOptions,
+        CreateUserOptions,
+        Privilege,
+        Role,
+        RolesInfoOptions,
+        UpdateUserOptions,
+        UsersInfoOptions,
+    },
+};
+
+#[test]
+fn build_create_user() {
+    let mut op = CreateUser::new(
+        "admin".to_string(),
+        "tenant".to_string(),
+        Some(
+            CreateUserOptions::builder()
+                .password("hunter2".to_string())
+                .roles(vec![Role::new("readWrite", "tenant_db")])
+                .custom_data(doc! { "tier": "free" })
+                .write_concern(WriteConcern::builder().w(Acknowledgment::Majority).build())
+                .build(),
+        ),
+    );
+
+    let cmd = op.build(&StreamDescription::new_testing()).unwrap();
+    assert_eq!(cmd.name.as_str(), "createUser");
+    assert_eq!(cmd.target_db.as_str(), "admin");
+    assert_eq!(
+        cmd.body,
+        doc! {
+            "createUser": "tenant",
+            "roles": [{ "role": "readWrite", "db": "tenant_db" }],
+            "pwd": "hunter2",
+            "customData": { "tier": "free" },
+            "writeConcern": { "w": "majority" },
+        }
+    );
+    assert!(cmd.should_redact());
+    assert!(!format!("{:?}", op).contains("hunter2"));
+}
+
+#[test]
+fn build_create_user_without_roles() {
+    let mut op = CreateUser::new("admin".to_string(), "tenant".to_string(), None);
+
+    let cmd = op.build(&StreamDescription::new_testing()).unwrap();
+    assert_eq!(cmd.body, doc! { "createUser": "tenant", "roles": [] });
+}
+
+#[test]
+fn build_update_user() {
+    let mut op = UpdateUser::new(
+        "admin".to_string(),
+        "tenant".to_string(),
+        Some(
+            UpdateUserOptions::builder()
+                .password("correct horse".to_string())
+                .build(),
+        ),
+    );
+
+    let cmd = op.build(&StreamDescription::new_testing()).unwrap();
+    assert_eq!(
+        cmd.body,
+        doc! { "updateUser": "tenant", "pwd": "correct horse" }
+    );
+    assert!(cmd.should_redact());
+    assert!(!format!("{:?}", op).contains("correct horse"));
+}
+
+#[test]
+fn build_users_info() {
+    let mut op = UsersInfo::new("admin".to_string(), None, None);
+    let cmd = op.build(&StreamDescription::new_testing()).unwrap();
+    assert_eq!(cmd.body, doc! { "usersInfo": 1 });
+
+    let mut op = UsersInfo::new(
+        "admin".to_string(),
+        Some(vec!["a".to_string(), "b".to_string()]),
+        Some(UsersInfoOptions::builder().show_privileges(true).build()),
+    );
+    let cmd = op.build(&StreamDescription::new_testing()).unwrap();
+    assert_eq!(
+        cmd.body,
+        doc! {
+            "usersInfo": [{ "user": "a", "db": "admin" }, { "user": "b", "db": "admin" }],
+            "showPrivileges": true,
+        }
+    );
+}
+
+#[test]
+fn handle_users_info_response() {
+    let op = UsersInfo::new("admin".to_string(), None, None);
+    let response = doc! {
+        "ok": 1,
+        "users": [{
+            "_id": "admin.tenant",
+            "user": "tenant",
+            "db": "admin",
+            "roles": [{ "role": "readWrite", "db": "tenant_db" }],
+            "mechanisms": ["SCRAM-SHA-1", "SCRAM-SHA-256"],
+            "authenticationRestrictions": [{ "clientSource": ["127.0.0.1"] }],
+        }],
+    };
+
+    let users = handle_response_test(&op, response).unwrap();
+    assert_eq!(users.len(), 1);
+    let user = &users[0];
+    assert_eq!(user.user, "tenant");
+    assert_eq!(user.db, "admin");
+    assert_eq!(user.roles, vec![Role::new("readWrite", "tenant_db")]);
+    assert_eq!(
+        user.mechanisms,
+        Some(vec!["SCRAM-SHA-1".to_string(), "SCRAM-SHA-256".to_string()])
+    );
+    assert_eq!(
+        user.authentication_restrictions,
+        Some(vec![AuthenticationRestriction::builder()
+            .client_source(vec!["127.0.0.1".to_string()])
+            .build()])
+    );
+}
+
+#[test]
+fn build_grant_roles_to_user() {
+    let mut op = GrantRolesToUser::new(
+        "admin".to_string(),
+        "tenant".to_string(),
+        vec![Role::new("read", "reporting")],
+        None,
+    );
+
+    let cmd = op.build(&StreamDescription::new_testing()).unwrap();
+    assert_eq!(
+        cmd.body,
+        doc! {
+            "grantRolesToUser": "tenant",
+            "roles": [{ "role": "read", "db": "reporting" }],
+        }
+    );
+}
+
+#[test]
+fn build_create_role() {
+    let mut op = CreateRole::new(
+        "tenant_db".to_string(),
+        "auditor".to_string(),
+        Some(
+            CreateRoleOptions::builder()
+                .privileges(vec![Privilege::builder()
+                    .resource(doc! { "db": "tenant_db", "collection": "" })
+                    .actions(vec!["find".to_string()])
+                    .build()])
+                .build(),
+        ),
+    );
+
+    let cmd = op.build(&StreamDescription::new_testing()).unwrap();
+    assert_eq!(
+        cmd.body,
+        doc! {
+            "createRole": "auditor",
+            "privileges": [{
+                "resource": { "db": "tenant_db", "collection": "" },
+                "actions": ["find"],
+            }],
+            "roles": [],
+        }
+    );
+}
+
+#[test]
+fn handle_create_role_write_concern_error() {
+    let op = CreateRole::new("tenant_db".to_string(), "auditor".to_string(), None);
+    let response = doc! {
+        "ok": 1,
+        "writeConcernError": {
+            "code": 100,
+            "codeName": "UnsatisfiableWriteConcern",
+            "errmsg": "Not enough data-bearing nodes",
+        },
+    };
+
+    let error = handle_response_test(&op, response).unwrap_err();
+    match *error.kind {
+        ErrorKind::Write(WriteFailure::WriteConcernError(ref wc_error)) => {
+            assert_eq!(wc_error.code, 100)
+        }
+        ref e => panic!("expected write concern error, got {:?}", e),
+    }
+}
+
+#[test]
+fn build_and_handle_roles_info() {
+    let mut op = RolesInfo::new(
+        "tenant_db".to_string(),
+        Some(vec!["auditor".to_string()]),
+        Some(RolesInfoOptions::builder().show_privileges(true).build()),
+    );
+    let cmd = op.build(&StreamDescription::new_testing()).unwrap();
+    assert_eq!(
+        cmd.body,
+        doc! {
+            "rolesInfo": [{ "role": "auditor", "db": "tenant_db" }],
+            "showPrivileges": true,
+        }
+    );
+
+    let response = doc! {
+        "ok": 1,
+        "roles": [{
+            "role": "auditor",
+            "db": "tenant_db",
+            "isBuiltin": false,
+            "roles": [],
+            "inheritedRoles": [],
+            "privileges": [{
+                "resource": { "db": "tenant_db", "collection": "" },
+                "actions": ["find"],
+            }],
+            "inheritedPrivileges": [{
+                "resource": { "db": "tenant_db", "collection": "" },
+                "actions": ["find"],
+            }],
+        }],
+    };
+    let roles = handle_response_test(&op, response).unwrap();
+    assert_eq!(roles.len(), 1);
+    let role = &roles[0];
+    assert_eq!(role.role, "auditor");
+    assert!(!role.is_builtin);
+    assert_eq!(
+        role.privileges.as_ref().unwrap()[0].actions,
+        vec!["find".to_string()]
+    );
+}
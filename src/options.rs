@@ -25,6 +25,7 @@ pub use crate::{
     db::options::*,
     gridfs::options::*,
     index::options::*,
+    search_index::options::*,
     selection_criteria::*,
 };
 
@@ -144,3 +145,23 @@ macro_rules! resolve_selection_criteria_with_session {
         result
     }};
 }
+
+/// Updates the timeout of an options struct. If the timeout is not configured directly on the
+/// operation, inherit the default timeout from the session, if any. Otherwise, inherit it from the
+/// collection/database.
+macro_rules! resolve_timeout_with_session {
+    ($obj:expr, $opts:expr, $session:expr) => {{
+        if $opts
+            .as_ref()
+            .map(|opts| opts.timeout.is_none())
+            .unwrap_or(true)
+        {
+            let timeout = $session
+                .and_then(|session| session.options().and_then(|opts| opts.default_timeout))
+                .or_else(|| $obj.timeout());
+            if let Some(timeout) = timeout {
+                $opts.get_or_insert_with(Default::default).timeout = Some(timeout);
+            }
+        }
+    }};
+}
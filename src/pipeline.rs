@@ -0,0 +1,431 @@
+//! Contains a typed builder for aggregation pipelines.
+#[cfg(test)]
+mod test;
+
+use typed_builder::TypedBuilder;
+
+use crate::{
+    bson::{doc, Bson, Document},
+    Namespace,
+};
+
+/// A builder for aggregation pipelines.
+///
+/// A `Pipeline` can be passed anywhere a pipeline of documents is accepted, such as
+/// [`Collection::aggregate`](crate::Collection::aggregate) or
+/// [`Collection::watch`](crate::Collection::watch), and can be converted into a `Vec<Document>`
+/// for options like
+/// [`CreateCollectionOptions::pipeline`](crate::options::CreateCollectionOptions::pipeline).
+///
+/// ```rust
+/// # use mongodb::{bson::{doc, Document}, Client, error::Result};
+/// # async fn func() -> Result<()> {
+/// # let client = Client::with_uri_str("mongodb://example.com").await?;
+/// # let coll = client.database("foo").collection::<Document>("orders");
+/// use mongodb::pipeline::{Lookup, Pipeline};
+///
+/// let pipeline = Pipeline::new()
+///     .match_(doc! { "status": "shipped" })
+///     .lookup(
+///         Lookup::builder()
+///             .from("customers")
+///             .local_field("customerId")
+///             .foreign_field("_id")
+///             .as_("customer")
+///             .build(),
+///     )
+///     .unwind("$customer")
+///     .group("$customer.region", doc! { "total": { "$sum": "$amount" } })
+///     .sort(doc! { "total": -1 });
+/// let mut cursor = coll.aggregate(pipeline, None).await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Pipeline {
+    stages: Vec<Document>,
+}
+
+impl Pipeline {
+    /// Creates a new, empty pipeline.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Appends an arbitrary stage to the pipeline.
+    pub fn stage(mut self, stage: Document) -> Self {
+        self.stages.push(stage);
+        self
+    }
+
+    /// Appends a [`$match`](https://www.mongodb.com/docs/manual/reference/operator/aggregation/match/)
+    /// stage that filters documents using the given query.
+    pub fn match_(self, filter: Document) -> Self {
+        self.stage(doc! { "$match": filter })
+    }
+
+    /// Appends a [`$group`](https://www.mongodb.com/docs/manual/reference/operator/aggregation/group/)
+    /// stage that groups documents by the `id` expression and computes the given accumulator
+    /// fields for each group.
+    pub fn group(self, id: impl Into<Bson>, accumulators: Document) -> Self {
+        let mut group = doc! { "_id": id.into() };
+        group.extend(accumulators);
+        self.stage(doc! { "$group": group })
+    }
+
+    /// Appends a [`$lookup`](https://www.mongodb.com/docs/manual/reference/operator/aggregation/lookup/)
+    /// stage.
+    pub fn lookup(self, lookup: Lookup) -> Self {
+        self.stage(doc! { "$lookup": lookup.into_document() })
+    }
+
+    /// Appends an [`$unwind`](https://www.mongodb.com/docs/manual/reference/operator/aggregation/unwind/)
+    /// stage. A field path can be provided directly, e.g. `pipeline.unwind("$items")`.
+    pub fn unwind(self, unwind: impl Into<Unwind>) -> Self {
+        self.stage(doc! { "$unwind": unwind.into().into_bson() })
+    }
+
+    /// Appends a [`$project`](https://www.mongodb.com/docs/manual/reference/operator/aggregation/project/)
+    /// stage.
+    pub fn project(self, projection: Document) -> Self {
+        self.stage(doc! { "$project": projection })
+    }
+
+    /// Appends a [`$sort`](https://www.mongodb.com/docs/manual/reference/operator/aggregation/sort/)
+    /// stage.
+    pub fn sort(self, sort: Document) -> Self {
+        self.stage(doc! { "$sort": sort })
+    }
+
+    /// Appends a [`$facet`](https://www.mongodb.com/docs/manual/reference/operator/aggregation/facet/)
+    /// stage that runs each of the given sub-pipelines, outputting their results in the field with
+    /// the corresponding name.
+    pub fn facet<S: Into<String>>(self, facets: impl IntoIterator<Item = (S, Pipeline)>) -> Self {
+        let facets: Document = facets
+            .into_iter()
+            .map(|(name, pipeline)| (name.into(), pipeline.into_bson()))
+            .collect();
+        self.stage(doc! { "$facet": facets })
+    }
+
+    /// Appends a [`$merge`](https://www.mongodb.com/docs/manual/reference/operator/aggregation/merge/)
+    /// stage. This must be the last stage in the pipeline.
+    pub fn merge(self, merge: Merge) -> Self {
+        self.stage(doc! { "$merge": merge.into_document() })
+    }
+
+    /// Appends an [`$out`](https://www.mongodb.com/docs/manual/reference/operator/aggregation/out/)
+    /// stage that writes the results to the given collection. This must be the last stage in the
+    /// pipeline.
+    pub fn out(self, target: impl Into<OutputCollection>) -> Self {
+        self.stage(doc! { "$out": target.into().into_bson() })
+    }
+
+    /// Appends a [`$setWindowFields`](https://www.mongodb.com/docs/manual/reference/operator/aggregation/setWindowFields/)
+    /// stage. Only available in MongoDB 5.0+.
+    pub fn set_window_fields(self, set_window_fields: SetWindowFields) -> Self {
+        self.stage(doc! { "$setWindowFields": set_window_fields.into_document() })
+    }
+
+    /// Appends an Atlas [`$search`](https://www.mongodb.com/docs/atlas/atlas-search/query-syntax/)
+    /// stage. This must be the first stage in the pipeline.
+    pub fn search(self, search: Document) -> Self {
+        self.stage(doc! { "$search": search })
+    }
+
+    /// The stages in this pipeline.
+    pub fn stages(&self) -> &[Document] {
+        &self.stages
+    }
+
+    fn into_bson(self) -> Bson {
+        Bson::Array(self.stages.into_iter().map(Bson::Document).collect())
+    }
+}
+
+impl IntoIterator for Pipeline {
+    type Item = Document;
+    type IntoIter = std::vec::IntoIter<Document>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.stages.into_iter()
+    }
+}
+
+impl From<Pipeline> for Vec<Document> {
+    fn from(pipeline: Pipeline) -> Self {
+        pipeline.stages
+    }
+}
+
+impl From<Vec<Document>> for Pipeline {
+    fn from(stages: Vec<Document>) -> Self {
+        Self { stages }
+    }
+}
+
+impl FromIterator<Document> for Pipeline {
+    fn from_iter<I: IntoIterator<Item = Document>>(iter: I) -> Self {
+        Self {
+            stages: iter.into_iter().collect(),
+        }
+    }
+}
+
+/// Specifies a `$lookup` stage, which joins documents from another collection.
+#[derive(Clone, Debug, PartialEq, TypedBuilder)]
+#[builder(field_defaults(setter(into)))]
+#[non_exhaustive]
+pub struct Lookup {
+    /// The collection in the same database to join with.
+    pub from: String,
+
+    /// The field from the input documents to match against `foreign_field`.
+    #[builder(default, setter(strip_option))]
+    pub local_field: Option<String>,
+
+    /// The field from the `from` collection's documents to match against `local_field`.
+    #[builder(default, setter(strip_option))]
+    pub foreign_field: Option<String>,
+
+    /// Variables to make available to `pipeline`.
+    #[builder(default)]
+    pub let_vars: Option<Document>,
+
+    /// A pipeline to run on the joined documents.
+    #[builder(default)]
+    pub pipeline: Option<Pipeline>,
+
+    /// The name of the array field to add to the input documents containing the joined documents.
+    pub as_: String,
+}
+
+impl Lookup {
+    fn into_document(self) -> Document {
+        let mut lookup = doc! { "from": self.from };
+        if let Some(local_field) = self.local_field {
+            lookup.insert("localField", local_field);
+        }
+        if let Some(foreign_field) = self.foreign_field {
+            lookup.insert("foreignField", foreign_field);
+        }
+        if let Some(let_vars) = self.let_vars {
+            lookup.insert("let", let_vars);
+        }
+        if let Some(pipeline) = self.pipeline {
+            lookup.insert("pipeline", pipeline.into_bson());
+        }
+        lookup.insert("as", self.as_);
+        lookup
+    }
+}
+
+/// Specifies an `$unwind` stage, which outputs a document for each element of an array field.
+#[derive(Clone, Debug, PartialEq, TypedBuilder)]
+#[builder(field_defaults(setter(into)))]
+#[non_exhaustive]
+pub struct Unwind {
+    /// The path of the array field to unwind. A leading `$` will be added if not present.
+    pub path: String,
+
+    /// The name of a field to add to each output document containing the array index of the
+    /// element.
+    #[builder(default)]
+    pub include_array_index: Option<String>,
+
+    /// Whether to output a document when the field is null, missing or an empty array.
+    #[builder(default)]
+    pub preserve_null_and_empty_arrays: Option<bool>,
+}
+
+impl Unwind {
+    fn into_bson(self) -> Bson {
+        let path = if self.path.starts_with('$') {
+            self.path
+        } else {
+            format!("${}", self.path)
+        };
+        if self.include_array_index.is_none() && self.preserve_null_and_empty_arrays.is_none() {
+            return Bson::String(path);
+        }
+        let mut unwind = doc! { "path": path };
+        if let Some(include_array_index) = self.include_array_index {
+            unwind.insert("includeArrayIndex", include_array_index);
+        }
+        if let Some(preserve) = self.preserve_null_and_empty_arrays {
+            unwind.insert("preserveNullAndEmptyArrays", preserve);
+        }
+        Bson::Document(unwind)
+    }
+}
+
+impl From<&str> for Unwind {
+    fn from(path: &str) -> Self {
+        Self::builder().path(path).build()
+    }
+}
+
+impl From<String> for Unwind {
+    fn from(path: String) -> Self {
+        Self::builder().path(path).build()
+    }
+}
+
+/// The collection written to by an `$out` or `$merge` stage.
+#[derive(Clone, Debug, PartialEq)]
+#[non_exhaustive]
+pub enum OutputCollection {
+    /// A collection in the database the aggregation is run against.
+    Collection(String),
+
+    /// A collection in the specified database.
+    Namespace(Namespace),
+}
+
+impl OutputCollection {
+    fn into_bson(self) -> Bson {
+        match self {
+            Self::Collection(coll) => Bson::String(coll),
+            Self::Namespace(ns) => Bson::Document(doc! { "db": ns.db, "coll": ns.coll }),
+        }
+    }
+}
+
+impl From<&str> for OutputCollection {
+    fn from(coll: &str) -> Self {
+        Self::Collection(coll.to_string())
+    }
+}
+
+impl From<String> for OutputCollection {
+    fn from(coll: String) -> Self {
+        Self::Collection(coll)
+    }
+}
+
+impl From<Namespace> for OutputCollection {
+    fn from(ns: Namespace) -> Self {
+        Self::Namespace(ns)
+    }
+}
+
+/// Specifies a `$merge` stage, which writes the results of the pipeline into a collection.
+#[derive(Clone, Debug, PartialEq, TypedBuilder)]
+#[builder(field_defaults(setter(into)))]
+#[non_exhaustive]
+pub struct Merge {
+    /// The collection to write to, i.e. the `into` field of the stage.
+    pub target: OutputCollection,
+
+    /// The fields that uniquely identify a document in the target collection. Defaults to `_id`.
+    #[builder(default)]
+    pub on: Option<Vec<String>>,
+
+    /// Variables to make available to a `WhenMatched::Pipeline`.
+    #[builder(default)]
+    pub let_vars: Option<Document>,
+
+    /// The behavior when a result document matches an existing document.
+    #[builder(default)]
+    pub when_matched: Option<WhenMatched>,
+
+    /// The behavior when a result document does not match an existing document.
+    #[builder(default)]
+    pub when_not_matched: Option<WhenNotMatched>,
+}
+
+impl Merge {
+    fn into_document(self) -> Document {
+        let mut merge = doc! { "into": self.target.into_bson() };
+        if let Some(on) = self.on {
+            merge.insert("on", on);
+        }
+        if let Some(let_vars) = self.let_vars {
+            merge.insert("let", let_vars);
+        }
+        if let Some(when_matched) = self.when_matched {
+            let when_matched = match when_matched {
+                WhenMatched::Replace => Bson::String("replace".to_string()),
+                WhenMatched::KeepExisting => Bson::String("keepExisting".to_string()),
+                WhenMatched::Merge => Bson::String("merge".to_string()),
+                WhenMatched::Fail => Bson::String("fail".to_string()),
+                WhenMatched::Pipeline(pipeline) => pipeline.into_bson(),
+            };
+            merge.insert("whenMatched", when_matched);
+        }
+        if let Some(when_not_matched) = self.when_not_matched {
+            let when_not_matched = match when_not_matched {
+                WhenNotMatched::Insert => "insert",
+                WhenNotMatched::Discard => "discard",
+                WhenNotMatched::Fail => "fail",
+            };
+            merge.insert("whenNotMatched", when_not_matched);
+        }
+        merge
+    }
+}
+
+/// The behavior of a `$merge` stage when a result document matches an existing document.
+#[derive(Clone, Debug, PartialEq)]
+#[non_exhaustive]
+pub enum WhenMatched {
+    /// Replace the existing document with the result document.
+    Replace,
+
+    /// Keep the existing document.
+    KeepExisting,
+
+    /// Merge the result document into the existing document.
+    Merge,
+
+    /// Stop the aggregation with an error.
+    Fail,
+
+    /// Update the existing document with the given pipeline.
+    Pipeline(Pipeline),
+}
+
+/// The behavior of a `$merge` stage when a result document does not match an existing document.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[non_exhaustive]
+pub enum WhenNotMatched {
+    /// Insert the result document.
+    Insert,
+
+    /// Discard the result document.
+    Discard,
+
+    /// Stop the aggregation with an error.
+    Fail,
+}
+
+/// Specifies a `$setWindowFields` stage, which computes fields over windows of documents.
+#[derive(Clone, Debug, PartialEq, TypedBuilder)]
+#[builder(field_defaults(setter(into)))]
+#[non_exhaustive]
+pub struct SetWindowFields {
+    /// The expression to partition documents by.
+    #[builder(default)]
+    pub partition_by: Option<Bson>,
+
+    /// The order of the documents within each partition.
+    #[builder(default)]
+    pub sort_by: Option<Document>,
+
+    /// The fields to compute, mapped to their window operator specifications.
+    pub output: Document,
+}
+
+impl SetWindowFields {
+    fn into_document(self) -> Document {
+        let mut set_window_fields = Document::new();
+        if let Some(partition_by) = self.partition_by {
+            set_window_fields.insert("partitionBy", partition_by);
+        }
+        if let Some(sort_by) = self.sort_by {
+            set_window_fields.insert("sortBy", sort_by);
+        }
+        set_window_fields.insert("output", self.output);
+        set_window_fields
+    }
+}
@@ -0,0 +1,187 @@
+use crate::{
+    bson::{doc, Bson, Document},
+    pipeline::{Lookup, Merge, Pipeline, SetWindowFields, Unwind, WhenMatched, WhenNotMatched},
+    Namespace,
+};
+
+#[test]
+fn build_stages() {
+    let pipeline = Pipeline::new()
+        .search(doc! { "text": { "query": "coffee", "path": "name" } })
+        .match_(doc! { "x": { "$gt": 1 } })
+        .lookup(
+            Lookup::builder()
+                .from("inventory")
+                .local_field("item")
+                .foreign_field("sku")
+                .as_("inventory_docs")
+                .build(),
+        )
+        .unwind("inventory_docs")
+        .group("$item", doc! { "count": { "$sum": 1 } })
+        .project(doc! { "count": 1 })
+        .sort(doc! { "count": -1 })
+        .set_window_fields(
+            SetWindowFields::builder()
+                .partition_by(Bson::from("$state"))
+                .sort_by(doc! { "orderDate": 1 })
+                .output(doc! { "total": { "$sum": "$quantity" } })
+                .build(),
+        );
+
+    let stages: Vec<Document> = pipeline.into();
+    assert_eq!(
+        stages,
+        vec![
+            doc! { "$search": { "text": { "query": "coffee", "path": "name" } } },
+            doc! { "$match": { "x": { "$gt": 1 } } },
+            doc! {
+                "$lookup": {
+                    "from": "inventory",
+                    "localField": "item",
+                    "foreignField": "sku",
+                    "as": "inventory_docs",
+                }
+            },
+            doc! { "$unwind": "$inventory_docs" },
+            doc! { "$group": { "_id": "$item", "count": { "$sum": 1 } } },
+            doc! { "$project": { "count": 1 } },
+            doc! { "$sort": { "count": -1 } },
+            doc! {
+                "$setWindowFields": {
+                    "partitionBy": "$state",
+                    "sortBy": { "orderDate": 1 },
+                    "output": { "total": { "$sum": "$quantity" } },
+                }
+            },
+        ]
+    );
+}
+
+#[test]
+fn build_unwind_options() {
+    let pipeline = Pipeline::new().unwind(
+        Unwind::builder()
+            .path("$sizes")
+            .include_array_index("index".to_string())
+            .preserve_null_and_empty_arrays(true)
+            .build(),
+    );
+    assert_eq!(
+        pipeline.stages(),
+        &[doc! {
+            "$unwind": {
+                "path": "$sizes",
+                "includeArrayIndex": "index",
+                "preserveNullAndEmptyArrays": true,
+            }
+        }]
+    );
+}
+
+#[test]
+fn build_facet() {
+    let pipeline = Pipeline::new().facet([
+        (
+            "byTag",
+            Pipeline::new().unwind("$tags").sort(doc! { "tags": 1 }),
+        ),
+        ("count", Pipeline::new().stage(doc! { "$count": "total" })),
+    ]);
+    assert_eq!(
+        pipeline.stages(),
+        &[doc! {
+            "$facet": {
+                "byTag": [{ "$unwind": "$tags" }, { "$sort": { "tags": 1 } }],
+                "count": [{ "$count": "total" }],
+            }
+        }]
+    );
+}
+
+#[test]
+fn build_lookup_fields_from_str() {
+    let lookup = Lookup::builder()
+        .from("orders")
+        .local_field("x")
+        .foreign_field("y")
+        .as_("joined")
+        .build();
+    assert_eq!(lookup.local_field.as_deref(), Some("x"));
+    assert_eq!(lookup.foreign_field.as_deref(), Some("y"));
+}
+
+#[test]
+fn build_lookup_pipeline() {
+    let pipeline = Pipeline::new().lookup(
+        Lookup::builder()
+            .from("warehouses")
+            .let_vars(doc! { "order_item": "$item" })
+            .pipeline(
+                Pipeline::new()
+                    .match_(doc! { "$expr": { "$eq": ["$stock_item", "$$order_item"] } }),
+            )
+            .as_("stockdata")
+            .build(),
+    );
+    assert_eq!(
+        pipeline.stages(),
+        &[doc! {
+            "$lookup": {
+                "from": "warehouses",
+                "let": { "order_item": "$item" },
+                "pipeline": [{ "$match": { "$expr": { "$eq": ["$stock_item", "$$order_item"] } } }],
+                "as": "stockdata",
+            }
+        }]
+    );
+}
+
+#[test]
+fn build_write_stages() {
+    let pipeline = Pipeline::new().out("archive");
+    assert_eq!(pipeline.stages(), &[doc! { "$out": "archive" }]);
+
+    let ns = Namespace {
+        db: "reporting".to_string(),
+        coll: "archive".to_string(),
+    };
+    let pipeline = Pipeline::new().out(ns.clone());
+    assert_eq!(
+        pipeline.stages(),
+        &[doc! { "$out": { "db": "reporting", "coll": "archive" } }]
+    );
+
+    let pipeline = Pipeline::new().merge(
+        Merge::builder()
+            .target(ns)
+            .on(vec!["region".to_string(), "month".to_string()])
+            .when_matched(WhenMatched::Pipeline(
+                Pipeline::new().stage(doc! { "$set": { "total": "$$new.total" } }),
+            ))
+            .when_not_matched(WhenNotMatched::Discard)
+            .build(),
+    );
+    assert_eq!(
+        pipeline.stages(),
+        &[doc! {
+            "$merge": {
+                "into": { "db": "reporting", "coll": "archive" },
+                "on": ["region", "month"],
+                "whenMatched": [{ "$set": { "total": "$$new.total" } }],
+                "whenNotMatched": "discard",
+            }
+        }]
+    );
+
+    let pipeline = Pipeline::new().merge(
+        Merge::builder()
+            .target("archive")
+            .when_matched(WhenMatched::KeepExisting)
+            .build(),
+    );
+    assert_eq!(
+        pipeline.stages(),
+        &[doc! { "$merge": { "into": "archive", "whenMatched": "keepExisting" } }]
+    );
+}
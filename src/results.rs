@@ -5,17 +5,17 @@ use std::collections::{HashMap, VecDeque};
 use crate::{
     bson::{serde_helpers, Bson, Document},
     change_stream::event::ResumeToken,
-    db::options::CreateCollectionOptions,
+    db::options::{AuthenticationRestriction, CreateCollectionOptions, Privilege, Role},
     serde_util,
     Namespace,
 };
 
-use bson::{Binary, RawDocumentBuf};
+use bson::{Binary, DateTime, RawDocumentBuf};
 use serde::{Deserialize, Serialize};
 
 /// The result of a [`Collection::insert_one`](../struct.Collection.html#method.insert_one)
 /// operation.
-#[derive(Debug, Serialize)]
+#[derive(Clone, Debug, Serialize)]
 #[serde(rename_all = "camelCase")]
 #[non_exhaustive]
 pub struct InsertOneResult {
@@ -51,7 +51,7 @@ impl InsertManyResult {
 
 /// The result of a [`Collection::update_one`](../struct.Collection.html#method.update_one) or
 /// [`Collection::update_many`](../struct.Collection.html#method.update_many) operation.
-#[derive(Debug, Serialize)]
+#[derive(Clone, Debug, Serialize)]
 #[serde(rename_all = "camelCase")]
 #[non_exhaustive]
 pub struct UpdateResult {
@@ -69,7 +69,7 @@ pub struct UpdateResult {
 
 /// The result of a [`Collection::delete_one`](../struct.Collection.html#method.delete_one) or
 /// [`Collection::delete_many`](../struct.Collection.html#method.delete_many) operation.
-#[derive(Debug, Serialize)]
+#[derive(Clone, Debug, Serialize)]
 #[serde(rename_all = "camelCase")]
 #[non_exhaustive]
 pub struct DeleteResult {
@@ -78,6 +78,104 @@ pub struct DeleteResult {
     pub deleted_count: u64,
 }
 
+/// The result of a [`Client::bulk_write`](../struct.Client.html#method.bulk_write) or
+/// [`Collection::bulk_write`](../struct.Collection.html#method.bulk_write) operation.
+///
+/// The counts are always populated. The result of each individual write, including the `_id` of
+/// each inserted or upserted document, is only reported in `insert_results`, `update_results` and
+/// `delete_results`, which are present if verbose results were requested via
+/// [`BulkWriteOptions::verbose_results`](crate::options::BulkWriteOptions::verbose_results) or
+/// [`CollectionBulkWriteOptions::verbose_results`](crate::options::CollectionBulkWriteOptions::verbose_results).
+#[derive(Clone, Debug, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct BulkWriteResult {
+    /// The number of documents inserted.
+    #[serde(serialize_with = "crate::bson::serde_helpers::serialize_u64_as_i64")]
+    pub inserted_count: u64,
+
+    /// The number of documents upserted.
+    #[serde(serialize_with = "crate::bson::serde_helpers::serialize_u64_as_i64")]
+    pub upserted_count: u64,
+
+    /// The number of documents matched by update or replace operations.
+    #[serde(serialize_with = "crate::bson::serde_helpers::serialize_u64_as_i64")]
+    pub matched_count: u64,
+
+    /// The number of documents modified by update or replace operations.
+    #[serde(serialize_with = "crate::bson::serde_helpers::serialize_u64_as_i64")]
+    pub modified_count: u64,
+
+    /// The number of documents deleted.
+    #[serde(serialize_with = "crate::bson::serde_helpers::serialize_u64_as_i64")]
+    pub deleted_count: u64,
+
+    /// The results of each successful insert, keyed by the index of the write in the list of
+    /// models provided. Only present if verbose results were requested.
+    pub insert_results: Option<HashMap<usize, InsertOneResult>>,
+
+    /// The results of each successful update or replace, keyed by the index of the write in the
+    /// list of models provided. Only present if verbose results were requested.
+    pub update_results: Option<HashMap<usize, UpdateResult>>,
+
+    /// The results of each successful delete, keyed by the index of the write in the list of
+    /// models provided. Only present if verbose results were requested.
+    pub delete_results: Option<HashMap<usize, DeleteResult>>,
+}
+
+impl BulkWriteResult {
+    pub(crate) fn new(verbose: bool) -> Self {
+        Self {
+            insert_results: verbose.then(HashMap::new),
+            update_results: verbose.then(HashMap::new),
+            delete_results: verbose.then(HashMap::new),
+            ..Default::default()
+        }
+    }
+
+    /// Creates the result of a batch of inserts from the ids of the inserted documents, keyed by
+    /// their index in the batch.
+    pub(crate) fn from_inserted_ids(inserted_ids: HashMap<usize, Bson>, verbose: bool) -> Self {
+        let mut result = Self::new(verbose);
+        result.inserted_count = inserted_ids.len() as u64;
+        if let Some(ref mut insert_results) = result.insert_results {
+            insert_results.extend(
+                inserted_ids
+                    .into_iter()
+                    .map(|(index, inserted_id)| (index, InsertOneResult { inserted_id })),
+            );
+        }
+        result
+    }
+
+    /// Adds the counts and verbose results from `other`, the result of a batch of writes starting
+    /// at `offset` in the list of models provided, to this result.
+    pub(crate) fn merge(&mut self, other: BulkWriteResult, offset: usize) {
+        self.inserted_count += other.inserted_count;
+        self.upserted_count += other.upserted_count;
+        self.matched_count += other.matched_count;
+        self.modified_count += other.modified_count;
+        self.deleted_count += other.deleted_count;
+        merge_verbose_results(&mut self.insert_results, other.insert_results, offset);
+        merge_verbose_results(&mut self.update_results, other.update_results, offset);
+        merge_verbose_results(&mut self.delete_results, other.delete_results, offset);
+    }
+}
+
+fn merge_verbose_results<R>(
+    results: &mut Option<HashMap<usize, R>>,
+    other: Option<HashMap<usize, R>>,
+    offset: usize,
+) {
+    if let (Some(results), Some(other)) = (results, other) {
+        results.extend(
+            other
+                .into_iter()
+                .map(|(index, result)| (index + offset, result)),
+        );
+    }
+}
+
 /// Information about the index created as a result of a
 /// [`Collection::create_index`](../struct.Collection.html#method.create_index).
 #[derive(Debug, Clone, PartialEq)]
@@ -196,3 +294,335 @@ pub struct DatabaseSpecification {
     /// is `None`.
     pub shards: Option<Document>,
 }
+
+/// Information about a user as reported by
+/// [`Database::users_info`](../struct.Database.html#method.users_info).
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct UserInfo {
+    /// The name of the user.
+    pub user: String,
+
+    /// The database in which the user is defined.
+    pub db: String,
+
+    /// The unique identifier the server assigned to the user.
+    pub user_id: Option<Binary>,
+
+    /// The roles granted directly to the user.
+    pub roles: Vec<Role>,
+
+    /// The custom data stored with the user.
+    pub custom_data: Option<Document>,
+
+    /// The SCRAM mechanisms the user has credentials for.
+    pub mechanisms: Option<Vec<String>>,
+
+    /// The user's credentials. This is only populated if
+    /// [`UsersInfoOptions::show_credentials`](crate::options::UsersInfoOptions::show_credentials)
+    /// was set.
+    pub credentials: Option<Document>,
+
+    /// All of the roles granted to the user, including those inherited from other roles. This is
+    /// only populated if
+    /// [`UsersInfoOptions::show_privileges`](crate::options::UsersInfoOptions::show_privileges)
+    /// was set.
+    pub inherited_roles: Option<Vec<Role>>,
+
+    /// All of the privileges granted to the user, including those inherited from its roles. This
+    /// is only populated if
+    /// [`UsersInfoOptions::show_privileges`](crate::options::UsersInfoOptions::show_privileges)
+    /// was set.
+    pub inherited_privileges: Option<Vec<Privilege>>,
+
+    /// The restrictions on where the user may authenticate from. This is only populated if
+    /// [`UsersInfoOptions::show_authentication_restrictions`](crate::options::UsersInfoOptions::show_authentication_restrictions)
+    /// was set.
+    pub authentication_restrictions: Option<Vec<AuthenticationRestriction>>,
+}
+
+/// Information about a role as reported by
+/// [`Database::roles_info`](../struct.Database.html#method.roles_info).
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct RoleInfo {
+    /// The name of the role.
+    pub role: String,
+
+    /// The database in which the role is defined.
+    pub db: String,
+
+    /// Whether the role is built into the server rather than defined by a user.
+    pub is_builtin: bool,
+
+    /// The roles from which the role directly inherits privileges.
+    pub roles: Vec<Role>,
+
+    /// All of the roles from which the role inherits privileges, directly or indirectly.
+    pub inherited_roles: Option<Vec<Role>>,
+
+    /// The privileges granted directly by the role. This is only populated if
+    /// [`RolesInfoOptions::show_privileges`](crate::options::RolesInfoOptions::show_privileges)
+    /// was set.
+    pub privileges: Option<Vec<Privilege>>,
+
+    /// All of the privileges granted by the role, including inherited ones. This is only
+    /// populated if
+    /// [`RolesInfoOptions::show_privileges`](crate::options::RolesInfoOptions::show_privileges)
+    /// was set.
+    pub inherited_privileges: Option<Vec<Privilege>>,
+
+    /// The sets of restrictions on where users with the role may authenticate from. This is only
+    /// populated if
+    /// [`RolesInfoOptions::show_authentication_restrictions`](crate::options::RolesInfoOptions::show_authentication_restrictions)
+    /// was set.
+    pub authentication_restrictions: Option<Vec<Vec<AuthenticationRestriction>>>,
+}
+
+/// Statistics about a database as reported by
+/// [`Database::stats`](../struct.Database.html#method.stats).
+///
+/// Sizes are reported in bytes unless
+/// [`DbStatsOptions::scale`](crate::options::DbStatsOptions::scale) was set.
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct DatabaseStats {
+    /// The name of the database.
+    pub db: String,
+
+    /// The number of collections in the database.
+    #[serde(deserialize_with = "serde_util::deserialize_u64_from_bson_number")]
+    pub collections: u64,
+
+    /// The number of views in the database.
+    #[serde(
+        default,
+        deserialize_with = "serde_util::deserialize_u64_option_from_bson_number"
+    )]
+    pub views: Option<u64>,
+
+    /// The number of documents in the database.
+    #[serde(deserialize_with = "serde_util::deserialize_u64_from_bson_number")]
+    pub objects: u64,
+
+    /// The average size of each document in the database.
+    pub avg_obj_size: f64,
+
+    /// The total size of the uncompressed data held in the database.
+    pub data_size: f64,
+
+    /// The amount of storage allocated to the database's collections.
+    pub storage_size: f64,
+
+    /// The number of indexes in the database.
+    #[serde(deserialize_with = "serde_util::deserialize_u64_from_bson_number")]
+    pub indexes: u64,
+
+    /// The amount of storage allocated to the database's indexes.
+    pub index_size: f64,
+
+    /// The sum of `storage_size` and `index_size`.
+    pub total_size: Option<f64>,
+
+    /// The scale that the sizes were divided by.
+    pub scale_factor: Option<f64>,
+
+    /// Any other fields included in the server's response, e.g. `fsUsedSize` or, on sharded
+    /// clusters, the per-shard `raw` statistics.
+    #[serde(flatten)]
+    pub extra: Document,
+}
+
+/// Storage statistics about a collection as reported by
+/// [`Collection::storage_stats`](../struct.Collection.html#method.storage_stats). On a sharded
+/// cluster, one of these is reported for each shard the collection is stored on.
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct CollectionStorageStats {
+    /// The namespace of the collection.
+    pub ns: String,
+
+    /// The shard the statistics were gathered from, if the collection is sharded.
+    pub shard: Option<String>,
+
+    /// The host the statistics were gathered from.
+    pub host: Option<String>,
+
+    /// The time at which the statistics were gathered.
+    pub local_time: Option<DateTime>,
+
+    /// The collection's storage statistics.
+    pub storage_stats: StorageStats,
+}
+
+/// The storage statistics of a collection, as contained in
+/// [`CollectionStorageStats::storage_stats`].
+///
+/// Sizes are reported in bytes unless
+/// [`StorageStatsOptions::scale`](crate::options::StorageStatsOptions::scale) was set.
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct StorageStats {
+    /// The total size of the uncompressed documents in the collection.
+    pub size: f64,
+
+    /// The number of documents in the collection.
+    #[serde(deserialize_with = "serde_util::deserialize_u64_from_bson_number")]
+    pub count: u64,
+
+    /// The average size of each document in the collection. This is omitted for empty
+    /// collections.
+    pub avg_obj_size: Option<f64>,
+
+    /// The amount of storage allocated to the collection.
+    pub storage_size: f64,
+
+    /// The amount of storage allocated to the collection that is free to be reused.
+    pub free_storage_size: Option<f64>,
+
+    /// Whether the collection is capped.
+    #[serde(default)]
+    pub capped: bool,
+
+    /// The number of indexes on the collection.
+    #[serde(deserialize_with = "serde_util::deserialize_u64_from_bson_number")]
+    pub nindexes: u64,
+
+    /// The total amount of storage allocated to the collection's indexes.
+    pub total_index_size: f64,
+
+    /// The sum of `storage_size` and `total_index_size`.
+    pub total_size: Option<f64>,
+
+    /// The amount of storage allocated to each index, keyed by index name.
+    #[serde(default)]
+    pub index_sizes: HashMap<String, f64>,
+
+    /// The scale that the sizes were divided by.
+    pub scale_factor: Option<f64>,
+
+    /// Any other fields included in the server's response, e.g. storage engine specific
+    /// statistics such as `wiredTiger`.
+    #[serde(flatten)]
+    pub extra: Document,
+}
+
+/// Latency statistics about a collection as reported by
+/// [`Collection::latency_stats`](../struct.Collection.html#method.latency_stats). On a sharded
+/// cluster, one of these is reported for each shard the collection is stored on.
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct CollectionLatencyStats {
+    /// The namespace of the collection.
+    pub ns: String,
+
+    /// The shard the statistics were gathered from, if the collection is sharded.
+    pub shard: Option<String>,
+
+    /// The host the statistics were gathered from.
+    pub host: Option<String>,
+
+    /// The time at which the statistics were gathered.
+    pub local_time: Option<DateTime>,
+
+    /// The collection's latency statistics.
+    pub latency_stats: LatencyStats,
+}
+
+/// The latency statistics of a collection, as contained in
+/// [`CollectionLatencyStats::latency_stats`].
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct LatencyStats {
+    /// Statistics about read operations.
+    pub reads: OperationLatencyStats,
+
+    /// Statistics about write operations.
+    pub writes: OperationLatencyStats,
+
+    /// Statistics about database commands.
+    pub commands: OperationLatencyStats,
+
+    /// Statistics about transactions. This is only reported by server versions 4.4+.
+    pub transactions: Option<OperationLatencyStats>,
+
+    /// Any other fields included in the server's response.
+    #[serde(flatten)]
+    pub extra: Document,
+}
+
+/// The latency statistics of one type of operation, as contained in [`LatencyStats`].
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct OperationLatencyStats {
+    /// The total combined latency of the operations, in microseconds.
+    #[serde(deserialize_with = "serde_util::deserialize_u64_from_bson_number")]
+    pub latency: u64,
+
+    /// The number of operations performed.
+    #[serde(deserialize_with = "serde_util::deserialize_u64_from_bson_number")]
+    pub ops: u64,
+
+    /// The histogram of the operations' latencies. This is only populated if
+    /// [`LatencyStatsOptions::histograms`](crate::options::LatencyStatsOptions::histograms) was
+    /// set.
+    pub histogram: Option<Vec<LatencyHistogramBucket>>,
+}
+
+/// A bucket of an [`OperationLatencyStats::histogram`].
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+#[non_exhaustive]
+pub struct LatencyHistogramBucket {
+    /// The lower bound of the bucket's latency range, in microseconds.
+    #[serde(deserialize_with = "serde_util::deserialize_u64_from_bson_number")]
+    pub micros: u64,
+
+    /// The number of operations whose latency fell into the bucket.
+    #[serde(deserialize_with = "serde_util::deserialize_u64_from_bson_number")]
+    pub count: u64,
+}
+
+/// The status of a server as reported by
+/// [`Client::server_status`](../struct.Client.html#method.server_status).
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct ServerStatus {
+    /// The hostname and port of the server.
+    pub host: String,
+
+    /// The server's version.
+    pub version: String,
+
+    /// The type of the server process, i.e. `"mongod"` or `"mongos"`.
+    pub process: String,
+
+    /// The server's process id.
+    #[serde(deserialize_with = "serde_util::deserialize_u64_from_bson_number")]
+    pub pid: u64,
+
+    /// The number of seconds the server process has been running.
+    pub uptime: f64,
+
+    /// The number of milliseconds the server process has been running.
+    #[serde(
+        default,
+        deserialize_with = "serde_util::deserialize_u64_option_from_bson_number"
+    )]
+    pub uptime_millis: Option<u64>,
+
+    /// The server's current time.
+    pub local_time: DateTime,
+
+    /// Any other sections included in the server's response, e.g. `opcounters` or `repl`.
+    #[serde(flatten)]
+    pub extra: Document,
+}
@@ -10,10 +10,12 @@ mod join_handle;
 ))]
 pub(crate) mod process;
 mod resolver;
+pub(crate) mod socks5;
 pub(crate) mod stream;
 mod sync_read_ext;
 #[cfg(feature = "openssl-tls")]
 mod tls_openssl;
+mod tls_reload;
 #[cfg_attr(feature = "openssl-tls", allow(unused))]
 mod tls_rustls;
 mod worker_handle;
@@ -1,11 +1,22 @@
+use std::time::Duration;
+
+use futures_core::future::BoxFuture;
 use trust_dns_resolver::{
     config::ResolverConfig,
     error::ResolveErrorKind,
     lookup::{SrvLookup, TxtLookup},
+    proto::rr::RData,
     IntoName,
 };
 
-use crate::error::{Error, Result};
+#[cfg(feature = "gssapi-auth")]
+use crate::error::ErrorKind;
+use crate::{
+    error::{Error, Result},
+    options::{DnsResolver, SrvRecord},
+};
+#[cfg(feature = "gssapi-auth")]
+use trust_dns_resolver::proto::rr::RecordType;
 
 /// An async runtime agnostic DNS resolver.
 pub(crate) struct AsyncResolver {
@@ -62,4 +73,81 @@ impl AsyncResolver {
             },
         }
     }
+
+    /// Resolves `host` to its canonical name by following any CNAME records, returning the
+    /// canonical name along with one of the addresses it resolves to.
+    #[cfg(feature = "gssapi-auth")]
+    pub async fn canonical_name(&self, host: &str) -> Result<(String, std::net::IpAddr)> {
+        let lookup = self
+            .resolver
+            .lookup_ip(host)
+            .await
+            .map_err(Error::from_resolve_error)?;
+        let address = lookup.iter().next().ok_or_else(|| {
+            Error::from(ErrorKind::DnsResolve {
+                message: format!("no addresses found for {}", host),
+            })
+        })?;
+        let canonical = lookup
+            .as_lookup()
+            .record_iter()
+            .find(|record| matches!(record.record_type(), RecordType::A | RecordType::AAAA))
+            .map(|record| record.name().to_utf8())
+            .unwrap_or_else(|| host.to_string());
+        Ok((canonical.trim_end_matches('.').to_string(), address))
+    }
+
+    /// Performs a reverse lookup of `address`, returning the first name found, if any.
+    #[cfg(feature = "gssapi-auth")]
+    pub async fn reverse_lookup(&self, address: std::net::IpAddr) -> Result<Option<String>> {
+        match self.resolver.reverse_lookup(address).await {
+            Ok(lookup) => Ok(lookup
+                .iter()
+                .next()
+                .map(|name| name.to_utf8().trim_end_matches('.').to_string())),
+            Err(e) => match e.kind() {
+                ResolveErrorKind::NoRecordsFound { .. } => Ok(None),
+                _ => Err(Error::from_resolve_error(e)),
+            },
+        }
+    }
+}
+
+impl DnsResolver for AsyncResolver {
+    fn srv_lookup<'a>(&'a self, name: &'a str) -> BoxFuture<'a, Result<Vec<SrvRecord>>> {
+        Box::pin(async move {
+            let lookup = AsyncResolver::srv_lookup(self, name).await?;
+            Ok(lookup
+                .as_lookup()
+                .record_iter()
+                .filter_map(|record| match record.data() {
+                    Some(RData::SRV(srv)) => Some(SrvRecord {
+                        target: srv.target().to_utf8(),
+                        port: srv.port(),
+                        ttl: Duration::from_secs(record.ttl().into()),
+                    }),
+                    _ => None,
+                })
+                .collect())
+        })
+    }
+
+    fn txt_lookup<'a>(&'a self, name: &'a str) -> BoxFuture<'a, Result<Vec<String>>> {
+        Box::pin(async move {
+            let lookup = match AsyncResolver::txt_lookup(self, name).await? {
+                Some(lookup) => lookup,
+                None => return Ok(Vec::new()),
+            };
+            Ok(lookup
+                .iter()
+                .map(|txt| {
+                    txt.txt_data()
+                        .iter()
+                        .map(|bytes| String::from_utf8_lossy(bytes.as_ref()).into_owned())
+                        .collect::<Vec<_>>()
+                        .join("")
+                })
+                .collect())
+        })
+    }
 }
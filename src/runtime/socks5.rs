@@ -0,0 +1,191 @@
+#[cfg(test)]
+mod test;
+
+use std::net::IpAddr;
+
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt};
+
+use crate::{
+    client::options::{ServerAddress, DEFAULT_PORT},
+    error::{Error, Result},
+};
+
+/// The port used for a proxy when none is specified.
+pub(crate) const DEFAULT_PROXY_PORT: u16 = 1080;
+
+const VERSION: u8 = 0x05;
+const AUTH_NONE: u8 = 0x00;
+const AUTH_USERNAME_PASSWORD: u8 = 0x02;
+const AUTH_NO_ACCEPTABLE_METHODS: u8 = 0xFF;
+const USERNAME_PASSWORD_VERSION: u8 = 0x01;
+const CMD_CONNECT: u8 = 0x01;
+const ATYP_IPV4: u8 = 0x01;
+const ATYP_DOMAIN_NAME: u8 = 0x03;
+const ATYP_IPV6: u8 = 0x04;
+
+/// A SOCKS5 proxy that connections should be tunneled through.
+#[derive(Clone, Debug)]
+pub(crate) struct Socks5Proxy {
+    pub(crate) address: ServerAddress,
+    pub(crate) credentials: Option<(String, String)>,
+}
+
+impl Socks5Proxy {
+    /// Performs the SOCKS5 handshake over `stream`, which must already be connected to the proxy,
+    /// asking the proxy to open a connection to `target`. On success, the stream can be used as if
+    /// it were connected to `target` directly.
+    pub(crate) async fn handshake<S>(&self, stream: &mut S, target: &ServerAddress) -> Result<()>
+    where
+        S: AsyncRead + AsyncWrite + Unpin,
+    {
+        let method = if self.credentials.is_some() {
+            AUTH_USERNAME_PASSWORD
+        } else {
+            AUTH_NONE
+        };
+        stream.write_all(&[VERSION, 1, method]).await?;
+        stream.flush().await?;
+
+        let mut reply = [0u8; 2];
+        stream.read_exact(&mut reply).await?;
+        if reply[0] != VERSION {
+            return Err(proxy_error(format!(
+                "unexpected SOCKS version {} in method selection reply",
+                reply[0]
+            )));
+        }
+        match (reply[1], &self.credentials) {
+            (AUTH_NONE, _) => {}
+            (AUTH_USERNAME_PASSWORD, Some((username, password))) => {
+                authenticate(stream, username, password).await?
+            }
+            (AUTH_NO_ACCEPTABLE_METHODS, _) => {
+                return Err(proxy_error(
+                    "proxy did not accept any of the offered authentication methods",
+                ))
+            }
+            (other, _) => {
+                return Err(proxy_error(format!(
+                    "proxy selected unsupported authentication method {}",
+                    other
+                )))
+            }
+        }
+
+        stream.write_all(&connect_request(target)?).await?;
+        stream.flush().await?;
+
+        let mut reply = [0u8; 4];
+        stream.read_exact(&mut reply).await?;
+        if reply[0] != VERSION {
+            return Err(proxy_error(format!(
+                "unexpected SOCKS version {} in connect reply",
+                reply[0]
+            )));
+        }
+        if reply[1] != 0x00 {
+            return Err(proxy_error(format!(
+                "proxy failed to connect to {}: {}",
+                target,
+                reply_message(reply[1])
+            )));
+        }
+
+        // The reply ends with the address the proxy bound for the connection, which is not needed.
+        let bound_address_len = match reply[3] {
+            ATYP_IPV4 => 4,
+            ATYP_IPV6 => 16,
+            ATYP_DOMAIN_NAME => stream.read_u8().await? as usize,
+            other => {
+                return Err(proxy_error(format!(
+                    "unexpected address type {} in connect reply",
+                    other
+                )))
+            }
+        };
+        let mut bound_address = vec![0u8; bound_address_len + 2];
+        stream.read_exact(&mut bound_address).await?;
+
+        Ok(())
+    }
+}
+
+/// Performs username/password authentication as described in RFC 1929.
+async fn authenticate<S>(stream: &mut S, username: &str, password: &str) -> Result<()>
+where
+    S: AsyncRead + AsyncWrite + Unpin,
+{
+    let mut request = vec![USERNAME_PASSWORD_VERSION];
+    push_with_length(&mut request, username.as_bytes(), "proxy username")?;
+    push_with_length(&mut request, password.as_bytes(), "proxy password")?;
+    stream.write_all(&request).await?;
+    stream.flush().await?;
+
+    let mut reply = [0u8; 2];
+    stream.read_exact(&mut reply).await?;
+    if reply[0] != USERNAME_PASSWORD_VERSION {
+        return Err(proxy_error(format!(
+            "unexpected username/password authentication version {} in reply",
+            reply[0]
+        )));
+    }
+    if reply[1] != 0x00 {
+        return Err(proxy_error(
+            "proxy rejected the provided username and password",
+        ));
+    }
+    Ok(())
+}
+
+fn connect_request(target: &ServerAddress) -> Result<Vec<u8>> {
+    let mut request = vec![VERSION, CMD_CONNECT, 0x00];
+    let host = target.host();
+    match host.parse::<IpAddr>() {
+        Ok(IpAddr::V4(ip)) => {
+            request.push(ATYP_IPV4);
+            request.extend_from_slice(&ip.octets());
+        }
+        Ok(IpAddr::V6(ip)) => {
+            request.push(ATYP_IPV6);
+            request.extend_from_slice(&ip.octets());
+        }
+        // Hostnames are resolved by the proxy rather than locally.
+        Err(_) => {
+            request.push(ATYP_DOMAIN_NAME);
+            push_with_length(&mut request, host.as_bytes(), "proxied hostname")?;
+        }
+    }
+    request.extend_from_slice(&target.port().unwrap_or(DEFAULT_PORT).to_be_bytes());
+    Ok(request)
+}
+
+fn push_with_length(buf: &mut Vec<u8>, value: &[u8], name: &str) -> Result<()> {
+    let len = u8::try_from(value.len())
+        .map_err(|_| Error::invalid_argument(format!("{} must be at most 255 bytes", name)))?;
+    buf.push(len);
+    buf.extend_from_slice(value);
+    Ok(())
+}
+
+fn reply_message(code: u8) -> &'static str {
+    match code {
+        0x01 => "general SOCKS server failure",
+        0x02 => "connection not allowed by ruleset",
+        0x03 => "network unreachable",
+        0x04 => "host unreachable",
+        0x05 => "connection refused",
+        0x06 => "TTL expired",
+        0x07 => "command not supported",
+        0x08 => "address type not supported",
+        _ => "unknown error",
+    }
+}
+
+/// Proxy failures are reported as I/O errors so that they are treated as network errors.
+fn proxy_error(message: impl Into<String>) -> Error {
+    std::io::Error::new(
+        std::io::ErrorKind::Other,
+        format!("SOCKS5 proxy error: {}", message.into()),
+    )
+    .into()
+}
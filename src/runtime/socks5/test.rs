@@ -0,0 +1,144 @@
+use tokio::io::{AsyncReadExt, AsyncWriteExt, DuplexStream};
+
+use crate::{options::ServerAddress, runtime::socks5::Socks5Proxy};
+
+fn socks5_proxy(credentials: Option<(&str, &str)>) -> Socks5Proxy {
+    Socks5Proxy {
+        address: ServerAddress::Tcp {
+            host: "proxy.example.com".to_string(),
+            port: Some(1080),
+        },
+        credentials: credentials.map(|(u, p)| (u.to_string(), p.to_string())),
+    }
+}
+
+fn target() -> ServerAddress {
+    ServerAddress::Tcp {
+        host: "db.internal".to_string(),
+        port: Some(27018),
+    }
+}
+
+async fn read_vec(stream: &mut DuplexStream, len: usize) -> Vec<u8> {
+    let mut buf = vec![0u8; len];
+    stream.read_exact(&mut buf).await.unwrap();
+    buf
+}
+
+fn expected_connect_request() -> Vec<u8> {
+    let mut request = vec![0x05, 0x01, 0x00, 0x03, 11];
+    request.extend_from_slice(b"db.internal");
+    request.extend_from_slice(&27018u16.to_be_bytes());
+    request
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+async fn handshake_without_auth() {
+    let (mut client, mut server) = tokio::io::duplex(1024);
+    let proxy = socks5_proxy(None);
+    let target = target();
+
+    let server_side = async {
+        assert_eq!(read_vec(&mut server, 3).await, vec![0x05, 0x01, 0x00]);
+        server.write_all(&[0x05, 0x00]).await.unwrap();
+
+        let request = expected_connect_request();
+        assert_eq!(read_vec(&mut server, request.len()).await, request);
+        server
+            .write_all(&[0x05, 0x00, 0x00, 0x01, 10, 0, 0, 1, 0x69, 0x8A])
+            .await
+            .unwrap();
+        server.write_all(b"after").await.unwrap();
+    };
+    let (result, _) = tokio::join!(proxy.handshake(&mut client, &target), server_side);
+    result.unwrap();
+
+    // The bound address must be consumed so that subsequent reads see the tunneled data.
+    let mut buf = [0u8; 5];
+    client.read_exact(&mut buf).await.unwrap();
+    assert_eq!(&buf, b"after");
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+async fn handshake_with_username_password() {
+    let (mut client, mut server) = tokio::io::duplex(1024);
+    let proxy = socks5_proxy(Some(("user", "pencil")));
+    let target = target();
+
+    let server_side = async {
+        assert_eq!(read_vec(&mut server, 3).await, vec![0x05, 0x01, 0x02]);
+        server.write_all(&[0x05, 0x02]).await.unwrap();
+
+        let mut auth = vec![0x01, 4];
+        auth.extend_from_slice(b"user");
+        auth.push(6);
+        auth.extend_from_slice(b"pencil");
+        assert_eq!(read_vec(&mut server, auth.len()).await, auth);
+        server.write_all(&[0x01, 0x00]).await.unwrap();
+
+        let request = expected_connect_request();
+        assert_eq!(read_vec(&mut server, request.len()).await, request);
+        let mut reply = vec![0x05, 0x00, 0x00, 0x03, 5];
+        reply.extend_from_slice(b"proxy");
+        reply.extend_from_slice(&[0x69, 0x8A]);
+        server.write_all(&reply).await.unwrap();
+    };
+    let (result, _) = tokio::join!(proxy.handshake(&mut client, &target), server_side);
+    result.unwrap();
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+async fn handshake_failures() {
+    let target = target();
+
+    // Rejected credentials.
+    let (mut client, mut server) = tokio::io::duplex(1024);
+    let proxy = socks5_proxy(Some(("user", "wrong")));
+    let server_side = async {
+        read_vec(&mut server, 3).await;
+        server.write_all(&[0x05, 0x02]).await.unwrap();
+        read_vec(&mut server, 2 + 4 + 1 + 5).await;
+        server.write_all(&[0x01, 0x01]).await.unwrap();
+    };
+    let (result, _) = tokio::join!(proxy.handshake(&mut client, &target), server_side);
+    let error = result.unwrap_err();
+    assert!(error.is_network_error(), "{:?}", error);
+    assert!(error.to_string().contains("rejected"), "{}", error);
+
+    // Unexpected username/password authentication version.
+    let (mut client, mut server) = tokio::io::duplex(1024);
+    let proxy = socks5_proxy(Some(("user", "pencil")));
+    let server_side = async {
+        read_vec(&mut server, 3).await;
+        server.write_all(&[0x05, 0x02]).await.unwrap();
+        read_vec(&mut server, 2 + 4 + 1 + 6).await;
+        server.write_all(&[0x05, 0x00]).await.unwrap();
+    };
+    let (result, _) = tokio::join!(proxy.handshake(&mut client, &target), server_side);
+    let error = result.unwrap_err();
+    assert!(error.is_network_error(), "{:?}", error);
+    assert!(error.to_string().contains("version 5"), "{}", error);
+
+    // Connection refused by the proxy.
+    let (mut client, mut server) = tokio::io::duplex(1024);
+    let proxy = socks5_proxy(None);
+    let server_side = async {
+        read_vec(&mut server, 3).await;
+        server.write_all(&[0x05, 0x00]).await.unwrap();
+        read_vec(&mut server, expected_connect_request().len()).await;
+        server
+            .write_all(&[0x05, 0x05, 0x00, 0x01, 0, 0, 0, 0, 0, 0])
+            .await
+            .unwrap();
+    };
+    let (result, _) = tokio::join!(proxy.handshake(&mut client, &target), server_side);
+    let error = result.unwrap_err();
+    assert!(
+        error.to_string().contains("connection refused"),
+        "{}",
+        error
+    );
+}
@@ -14,7 +14,7 @@ use crate::{
     runtime,
 };
 
-use super::{tls::AsyncTlsStream, TlsConfig};
+use super::{socks5::Socks5Proxy, tls::AsyncTlsStream, TlsConfig};
 
 pub(crate) const DEFAULT_CONNECT_TIMEOUT: Duration = Duration::from_secs(10);
 const KEEPALIVE_TIME: Duration = Duration::from_secs(120);
@@ -40,10 +40,11 @@ impl AsyncStream {
     pub(crate) async fn connect(
         address: ServerAddress,
         tls_cfg: Option<&TlsConfig>,
+        proxy: Option<&Socks5Proxy>,
     ) -> Result<Self> {
         match &address {
             ServerAddress::Tcp { host, .. } => {
-                let inner = AsyncTcpStream::connect(&address).await?;
+                let inner = AsyncTcpStream::connect(&address, proxy).await?;
 
                 // If there are TLS options, wrap the inner stream in an AsyncTlsStream.
                 match tls_cfg {
@@ -290,7 +291,22 @@ impl AsyncTcpStream {
         Ok(stream.into())
     }
 
-    pub(crate) async fn connect(address: &ServerAddress) -> Result<Self> {
+    /// Connects to the given address, tunneling through the proxy if one is provided.
+    pub(crate) async fn connect(
+        address: &ServerAddress,
+        proxy: Option<&Socks5Proxy>,
+    ) -> Result<Self> {
+        match proxy {
+            Some(proxy) => {
+                let mut stream = Self::connect_direct(&proxy.address).await?;
+                proxy.handshake(&mut stream, address).await?;
+                Ok(stream)
+            }
+            None => Self::connect_direct(address).await,
+        }
+    }
+
+    async fn connect_direct(address: &ServerAddress) -> Result<Self> {
         let mut socket_addrs: Vec<_> = runtime::resolve_address(address).await?.collect();
 
         if socket_addrs.is_empty() {
@@ -1,12 +1,14 @@
 use std::{
     pin::Pin,
-    sync::Once,
+    sync::{Arc, Once},
     task::{Context, Poll},
 };
 
 use openssl::{
     error::ErrorStack,
-    ssl::{SslConnector, SslFiletype, SslMethod, SslVerifyMode},
+    pkey::PKey,
+    ssl::{SslConnector, SslMethod, SslVerifyMode},
+    x509::X509,
 };
 use tokio::io::{AsyncRead, AsyncWrite};
 use tokio_openssl::SslStream;
@@ -16,7 +18,7 @@ use crate::{
     error::{Error, ErrorKind, Result},
 };
 
-use super::stream::AsyncTcpStream;
+use super::{stream::AsyncTcpStream, tls_reload::CertReloader};
 
 #[derive(Debug)]
 pub(crate) struct AsyncTlsStream {
@@ -28,29 +30,48 @@ pub(crate) struct AsyncTlsStream {
 #[derive(Clone)]
 pub(crate) struct TlsConfig {
     connector: SslConnector,
+    reloader: Option<Arc<CertReloader<SslConnector>>>,
     verify_hostname: bool,
 }
 
 impl TlsConfig {
     /// Create a new `TlsConfig` from the provided options from the user.
     /// This operation is expensive, so the resultant `TlsConfig` should be cached.
-    pub(crate) fn new(options: TlsOptions) -> Result<TlsConfig> {
+    pub(crate) fn new(mut options: TlsOptions) -> Result<TlsConfig> {
         let verify_hostname = match options.allow_invalid_hostnames {
             Some(b) => !b,
             None => true,
         };
 
-        let connector = make_openssl_connector(options).map_err(|e| {
-            Error::from(ErrorKind::InvalidTlsConfig {
-                message: e.to_string(),
-            })
-        })?;
+        if let Some(connector) = options.openssl_connector.take() {
+            return Ok(TlsConfig {
+                connector,
+                reloader: None,
+                verify_hostname,
+            });
+        }
+
+        let reloader = CertReloader::from_options(&options)?.map(Arc::new);
+        let connector = match reloader {
+            Some(ref reloader) => reloader.connector(make_openssl_connector)?,
+            None => make_openssl_connector(options)?,
+        };
 
         Ok(TlsConfig {
             connector,
+            reloader,
             verify_hostname,
         })
     }
+
+    /// Returns the connector to use for a new connection, picking up any change to the client
+    /// certificate.
+    fn connector(&self) -> Result<SslConnector> {
+        match self.reloader {
+            Some(ref reloader) => reloader.connector(make_openssl_connector),
+            None => Ok(self.connector.clone()),
+        }
+    }
 }
 
 impl AsyncTlsStream {
@@ -61,11 +82,13 @@ impl AsyncTlsStream {
     ) -> Result<Self> {
         init_trust();
 
-        let mut stream = make_ssl_stream(host, tcp_stream, cfg).map_err(|err| {
-            Error::from(ErrorKind::InvalidTlsConfig {
-                message: err.to_string(),
-            })
-        })?;
+        let connector = cfg.connector()?;
+        let mut stream = make_ssl_stream(host, tcp_stream, &connector, cfg.verify_hostname)
+            .map_err(|err| {
+                Error::from(ErrorKind::InvalidTlsConfig {
+                    message: err.to_string(),
+                })
+            })?;
         Pin::new(&mut stream).connect().await.map_err(|err| {
             use std::io;
             match err.into_io_error() {
@@ -105,13 +128,19 @@ impl AsyncWrite for AsyncTlsStream {
     }
 }
 
-fn make_openssl_connector(cfg: TlsOptions) -> std::result::Result<SslConnector, ErrorStack> {
-    let mut builder = SslConnector::builder(SslMethod::tls_client())?;
+fn make_openssl_connector(cfg: TlsOptions) -> Result<SslConnector> {
+    let mut builder = SslConnector::builder(SslMethod::tls_client()).map_err(invalid_tls_config)?;
 
     let TlsOptions {
         allow_invalid_certificates,
         ca_file_path,
         cert_key_file_path,
+        ca_pem,
+        cert_key_pem,
+        cert_key_password,
+        openssl_connector: _,
+        cert_key_provider: _,
+        watch_cert_key_file: _,
         allow_invalid_hostnames: _,
     } = cfg;
 
@@ -119,16 +148,54 @@ fn make_openssl_connector(cfg: TlsOptions) -> std::result::Result<SslConnector,
         builder.set_verify(SslVerifyMode::NONE);
     }
     if let Some(path) = ca_file_path {
-        builder.set_ca_file(path)?;
+        builder.set_ca_file(path).map_err(invalid_tls_config)?;
+    }
+    if let Some(pem) = ca_pem {
+        for cert in X509::stack_from_pem(&pem).map_err(invalid_tls_config)? {
+            builder
+                .cert_store_mut()
+                .add_cert(cert)
+                .map_err(invalid_tls_config)?;
+        }
     }
-    if let Some(path) = cert_key_file_path {
-        builder.set_certificate_file(path.clone(), SslFiletype::PEM)?;
-        builder.set_private_key_file(path, SslFiletype::PEM)?;
+
+    let cert_key_pem = match (cert_key_pem, cert_key_file_path) {
+        (Some(pem), _) => Some(pem),
+        (None, Some(path)) => Some(std::fs::read(path)?),
+        (None, None) => None,
+    };
+    if let Some(pem) = cert_key_pem {
+        let mut chain = X509::stack_from_pem(&pem)
+            .map_err(invalid_tls_config)?
+            .into_iter();
+        let cert = chain.next().ok_or_else(|| ErrorKind::InvalidTlsConfig {
+            message: "No PEM-encoded client certificate found".to_string(),
+        })?;
+        builder.set_certificate(&cert).map_err(invalid_tls_config)?;
+        for cert in chain {
+            builder
+                .add_extra_chain_cert(cert)
+                .map_err(invalid_tls_config)?;
+        }
+
+        let key = match cert_key_password {
+            Some(password) => PKey::private_key_from_pem_passphrase(&pem, password.as_bytes()),
+            None => PKey::private_key_from_pem(&pem),
+        }
+        .map_err(invalid_tls_config)?;
+        builder.set_private_key(&key).map_err(invalid_tls_config)?;
     }
 
     Ok(builder.build())
 }
 
+fn invalid_tls_config(error: ErrorStack) -> Error {
+    ErrorKind::InvalidTlsConfig {
+        message: error.to_string(),
+    }
+    .into()
+}
+
 fn init_trust() {
     static ONCE: Once = Once::new();
     ONCE.call_once(openssl_probe::init_ssl_cert_env_vars);
@@ -137,13 +204,13 @@ fn init_trust() {
 fn make_ssl_stream(
     host: &str,
     tcp_stream: AsyncTcpStream,
-    cfg: &TlsConfig,
+    connector: &SslConnector,
+    verify_hostname: bool,
 ) -> std::result::Result<SslStream<AsyncTcpStream>, ErrorStack> {
-    let ssl = cfg
-        .connector
+    let ssl = connector
         .configure()?
         .use_server_name_indication(true)
-        .verify_hostname(cfg.verify_hostname)
+        .verify_hostname(verify_hostname)
         .into_ssl(host)?;
     SslStream::new(ssl, tcp_stream)
 }
@@ -0,0 +1,137 @@
+#[cfg(test)]
+mod test;
+
+use std::{
+    path::PathBuf,
+    sync::{Arc, Mutex},
+    time::{Duration, Instant, SystemTime},
+};
+
+use crate::{
+    client::options::{ClientCertificateProvider, TlsOptions},
+    error::{ErrorKind, Result},
+};
+
+/// How long a watched certificate file is trusted before its metadata is checked again. This
+/// bounds how often establishing a connection performs blocking file I/O.
+const FILE_CHECK_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Rebuilds a TLS connector whenever the client certificate returned by a
+/// [`ClientCertificateProvider`] changes, so that new connections present the current certificate.
+pub(crate) struct CertReloader<C> {
+    options: TlsOptions,
+    provider: Arc<dyn ClientCertificateProvider>,
+    current: Mutex<Option<(Vec<u8>, C)>>,
+}
+
+impl<C: Clone> CertReloader<C> {
+    /// Returns a reloader for the given options, or `None` if the client certificate is static.
+    pub(crate) fn from_options(options: &TlsOptions) -> Result<Option<Self>> {
+        let provider: Arc<dyn ClientCertificateProvider> = match (
+            &options.cert_key_provider,
+            options.watch_cert_key_file,
+            &options.cert_key_file_path,
+        ) {
+            (Some(provider), ..) => provider.clone(),
+            (None, Some(true), Some(path)) => Arc::new(FileCertificateProvider::new(
+                path.clone(),
+                FILE_CHECK_INTERVAL,
+            )),
+            (None, Some(true), None) => {
+                return Err(ErrorKind::InvalidTlsConfig {
+                    message: "watch_cert_key_file requires cert_key_file_path to be set"
+                        .to_string(),
+                }
+                .into())
+            }
+            _ => return Ok(None),
+        };
+
+        Ok(Some(Self {
+            options: options.clone(),
+            provider,
+            current: Mutex::new(None),
+        }))
+    }
+
+    /// Returns the connector for the provider's current certificate, using `build` to create a
+    /// new one if the certificate has changed since the last call.
+    ///
+    /// The provider is called synchronously from the task establishing the connection; see
+    /// [`ClientCertificateProvider::cert_key_pem`] for the requirements this places on it.
+    pub(crate) fn connector(&self, build: impl FnOnce(TlsOptions) -> Result<C>) -> Result<C> {
+        let pem = self.provider.cert_key_pem()?;
+
+        let mut current = self.current.lock().unwrap();
+        if let Some((ref current_pem, ref connector)) = *current {
+            if *current_pem == pem {
+                return Ok(connector.clone());
+            }
+        }
+
+        let mut options = self.options.clone();
+        options.cert_key_pem = Some(pem.clone());
+        options.cert_key_file_path = None;
+        let connector = build(options)?;
+        *current = Some((pem, connector.clone()));
+        Ok(connector)
+    }
+}
+
+/// A [`ClientCertificateProvider`] that reads a file, rereading it only when its modification time
+/// or length changes. The file's metadata is checked at most once per `check_interval`, so the
+/// blocking I/O this performs on the connection path is limited to one `stat` per interval plus a
+/// read whenever the file has actually changed.
+struct FileCertificateProvider {
+    path: PathBuf,
+    check_interval: Duration,
+    cached: Mutex<Option<CachedFile>>,
+}
+
+struct CachedFile {
+    checked_at: Instant,
+    modified: SystemTime,
+    len: u64,
+    pem: Vec<u8>,
+}
+
+impl FileCertificateProvider {
+    fn new(path: PathBuf, check_interval: Duration) -> Self {
+        Self {
+            path,
+            check_interval,
+            cached: Mutex::new(None),
+        }
+    }
+}
+
+impl ClientCertificateProvider for FileCertificateProvider {
+    fn cert_key_pem(&self) -> Result<Vec<u8>> {
+        let mut cached = self.cached.lock().unwrap();
+        if let Some(ref file) = *cached {
+            if file.checked_at.elapsed() < self.check_interval {
+                return Ok(file.pem.clone());
+            }
+        }
+
+        let metadata = std::fs::metadata(&self.path)?;
+        let (modified, len) = (metadata.modified()?, metadata.len());
+        let checked_at = Instant::now();
+
+        if let Some(ref mut file) = *cached {
+            if file.modified == modified && file.len == len {
+                file.checked_at = checked_at;
+                return Ok(file.pem.clone());
+            }
+        }
+
+        let pem = std::fs::read(&self.path)?;
+        *cached = Some(CachedFile {
+            checked_at,
+            modified,
+            len,
+            pem: pem.clone(),
+        });
+        Ok(pem)
+    }
+}
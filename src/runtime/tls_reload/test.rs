@@ -0,0 +1,67 @@
+use std::{
+    path::PathBuf,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use crate::{
+    client::options::{ClientCertificateProvider, TlsOptions},
+    runtime::tls_reload::{CertReloader, FileCertificateProvider},
+};
+
+struct TempFile(PathBuf);
+
+impl TempFile {
+    fn new(name: &str) -> Self {
+        let path = std::env::temp_dir().join(format!("{}-{}", name, std::process::id()));
+        Self(path)
+    }
+
+    fn write(&self, contents: &[u8]) {
+        std::fs::write(&self.0, contents).unwrap();
+    }
+}
+
+impl Drop for TempFile {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_file(&self.0);
+    }
+}
+
+fn reloader(file: &TempFile, check_interval: Duration) -> CertReloader<Vec<u8>> {
+    let provider = FileCertificateProvider::new(file.0.clone(), check_interval);
+    CertReloader {
+        options: TlsOptions::default(),
+        provider: Arc::new(provider),
+        current: Mutex::new(None),
+    }
+}
+
+fn connector_pem(reloader: &CertReloader<Vec<u8>>) -> Vec<u8> {
+    reloader
+        .connector(|options| Ok(options.cert_key_pem.unwrap()))
+        .unwrap()
+}
+
+#[test]
+fn file_provider_picks_up_rewritten_file() {
+    let file = TempFile::new("mongodb-tls-reload-rewrite");
+    file.write(b"first certificate");
+    let reloader = reloader(&file, Duration::ZERO);
+    assert_eq!(connector_pem(&reloader), b"first certificate");
+
+    file.write(b"second, rotated certificate");
+    assert_eq!(connector_pem(&reloader), b"second, rotated certificate");
+}
+
+#[test]
+fn file_provider_checks_at_most_once_per_interval() {
+    let file = TempFile::new("mongodb-tls-reload-interval");
+    file.write(b"first certificate");
+    let provider = FileCertificateProvider::new(file.0.clone(), Duration::from_secs(3600));
+    assert_eq!(provider.cert_key_pem().unwrap(), b"first certificate");
+
+    // The file is not consulted again until the interval has elapsed, even if it is removed.
+    std::fs::remove_file(&file.0).unwrap();
+    assert_eq!(provider.cert_key_pem().unwrap(), b"first certificate");
+}
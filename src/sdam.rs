@@ -7,7 +7,7 @@ mod srv_polling;
 mod test;
 mod topology;
 
-pub use self::public::{ServerInfo, ServerType, TopologyType};
+pub use self::public::{ServerInfo, ServerType, TopologyType, TopologyUpdates};
 
 pub(crate) use self::{
     description::{
@@ -17,7 +17,10 @@ use super::{
     TopologyWatcher,
 };
 use crate::{
-    cmap::{establish::ConnectionEstablisher, Connection},
+    cmap::{
+        establish::{is_faas, ConnectionEstablisher},
+        Connection,
+    },
     error::{Error, Result},
     event::sdam::{
         SdamEvent,
@@ -26,7 +29,7 @@ use crate::{
         ServerHeartbeatSucceededEvent,
     },
     hello::{hello_command, run_hello, AwaitableHelloOptions, HelloReply},
-    options::{ClientOptions, ServerAddress},
+    options::{ClientOptions, ServerAddress, ServerMonitoringMode},
     runtime::{self, stream::DEFAULT_CONNECT_TIMEOUT, WorkerHandle, WorkerHandleListener},
 };
 
@@ -55,6 +58,10 @@ pub(crate) struct Monitor {
     /// use the polling protocol.
     topology_version: Option<TopologyVersion>,
 
+    /// Whether this monitor may use the streaming protocol when the server supports it, as
+    /// determined by the `server_monitoring_mode` option.
+    allow_streaming: bool,
+
     /// Handle to the RTT monitor, used to get the latest known round trip time for a given server
     /// and to reset the RTT when the monitor disconnects from the server.
     rtt_monitor_handle: RttMonitorHandle,
@@ -81,6 +88,11 @@ impl Monitor {
             connection_establisher.clone(),
             client_options.clone(),
         );
+        let allow_streaming = match client_options.server_monitoring_mode {
+            Some(ServerMonitoringMode::Stream) => true,
+            Some(ServerMonitoringMode::Poll) => false,
+            Some(ServerMonitoringMode::Auto) | None => !is_faas(),
+        };
         let monitor = Self {
             address,
             client_options,
@@ -92,10 +104,14 @@ impl Monitor {
             request_receiver: manager_receiver,
             connection: None,
             topology_version: None,
+            allow_streaming,
         };
 
         runtime::execute(monitor.execute());
-        runtime::execute(rtt_monitor.execute());
+        // When polling, the round trip times are measured by the monitor's own checks instead.
+        if allow_streaming {
+            runtime::execute(rtt_monitor.execute());
+        }
     }
 
     async fn execute(mut self) {
@@ -203,6 +219,7 @@ impl Monitor {
             self.connect_timeout()
         };
 
+        let polled = !self.allow_streaming && self.connection.is_some();
         let execute_hello = async {
             match self.connection {
                 Some(ref mut conn) => {
@@ -270,6 +287,9 @@ impl Monitor {
 
         match result {
             HelloResult::Ok(ref r) => {
+                if polled {
+                    self.rtt_monitor_handle.add_sample(duration);
+                }
                 self.emit_event(|| {
                     let mut reply = r
                         .raw_command_response
@@ -289,8 +309,10 @@ impl Monitor {
                 });
 
                 // If the response included a topology version, cache it so that we can return it in
-                // the next hello.
-                self.topology_version = r.command_response.topology_version;
+                // the next hello. Without one, the next check will use the polling protocol.
+                if self.allow_streaming {
+                    self.topology_version = r.command_response.topology_version;
+                }
             }
             HelloResult::Err(ref e) | HelloResult::Cancelled { reason: ref e } => {
                 self.emit_event(|| {
@@ -1,14 +1,23 @@
-use std::{borrow::Cow, fmt, time::Duration};
+use std::{
+    borrow::Cow,
+    fmt,
+    pin::Pin,
+    task::{Context, Poll},
+    time::Duration,
+};
 
+use futures_core::{stream::BoxStream, Stream};
+use futures_util::{stream, StreamExt};
 use serde::Serialize;
 
 pub use crate::sdam::description::{server::ServerType, topology::TopologyType};
 use crate::{
     bson::DateTime,
     error::Error,
+    event::sdam::TopologyDescription,
     hello::HelloCommandResponse,
     options::ServerAddress,
-    sdam::ServerDescription,
+    sdam::{ServerDescription, TopologyWatcher},
     selection_criteria::TagSet,
 };
 
@@ -192,3 +201,38 @@ impl<'a> fmt::Display for ServerInfo<'a> {
         write!(f, " }}")
     }
 }
+
+/// A [`Stream`] of the descriptions of a client's topology, which yields a new
+/// [`TopologyDescription`] each time the topology changes. This can be obtained via
+/// [`Client::topology_updates`](crate::Client::topology_updates).
+///
+/// The stream ends once the client has been shut down or all of its handles have been dropped.
+pub struct TopologyUpdates {
+    inner: BoxStream<'static, TopologyDescription>,
+}
+
+impl TopologyUpdates {
+    pub(crate) fn new(watcher: TopologyWatcher) -> Self {
+        let initial = watcher.peek_latest().description.clone();
+        let inner = stream::unfold((watcher, initial), |(mut watcher, previous)| async move {
+            let latest = watcher.wait_for_description_change(&previous, None).await?;
+            Some((latest.clone().into(), (watcher, latest)))
+        })
+        .boxed();
+        Self { inner }
+    }
+}
+
+impl Stream for TopologyUpdates {
+    type Item = TopologyDescription;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.inner.poll_next_unpin(cx)
+    }
+}
+
+impl fmt::Debug for TopologyUpdates {
+    fn fmt(&self, f: &mut fmt::Formatter) -> std::result::Result<(), fmt::Error> {
+        f.debug_struct("TopologyUpdates").finish()
+    }
+}
@@ -1,7 +1,9 @@
 #[cfg(test)]
 mod test;
 
-use std::time::Duration;
+use std::{collections::HashSet, time::Duration};
+
+use rand::seq::SliceRandom;
 
 use super::{
     description::topology::TopologyType,
@@ -11,7 +13,7 @@ use super::{
 };
 use crate::{
     error::{Error, Result},
-    options::ClientOptions,
+    options::{ClientOptions, ServerAddress},
     runtime,
     srv::{LookupHosts, SrvResolver},
 };
@@ -103,9 +105,40 @@ impl SrvPollingMonitor {
         self.rescan_interval = lookup.min_ttl;
 
         // TODO: RUST-230 Log error with host that was returned.
-        self.topology_updater
-            .sync_hosts(lookup.hosts.into_iter().filter_map(Result::ok).collect())
-            .await;
+        let hosts = self.choose_hosts(lookup.hosts.into_iter().filter_map(Result::ok).collect());
+        self.topology_updater.sync_hosts(hosts).await;
+    }
+
+    /// Limits the hosts to `srv_max_hosts`, if set. Hosts already in the topology that are still
+    /// present in the SRV records are kept, and any remaining slots are filled with randomly chosen
+    /// new hosts.
+    fn choose_hosts(&self, hosts: Vec<ServerAddress>) -> HashSet<ServerAddress> {
+        let max = match self.client_options.srv_max_hosts {
+            Some(max) if max > 0 && hosts.len() > max as usize => max as usize,
+            _ => return hosts.into_iter().collect(),
+        };
+
+        let current: HashSet<ServerAddress> = self
+            .topology_watcher
+            .peek_latest()
+            .description
+            .server_addresses()
+            .cloned()
+            .collect();
+        let mut chosen = HashSet::new();
+        let mut new_hosts = Vec::new();
+        for host in hosts {
+            if current.contains(&host) {
+                chosen.insert(host);
+            } else {
+                new_hosts.push(host);
+            }
+        }
+
+        new_hosts.shuffle(&mut rand::thread_rng());
+        let remaining = max.saturating_sub(chosen.len());
+        chosen.extend(new_hosts.into_iter().take(remaining));
+        chosen
     }
 
     async fn lookup_hosts(&mut self) -> Result<LookupHosts> {
@@ -128,8 +161,12 @@ impl SrvPollingMonitor {
             return Ok(resolver);
         }
 
-        let resolver =
-            SrvResolver::new(self.client_options.resolver_config.clone().map(|c| c.inner)).await?;
+        let resolver = SrvResolver::new(
+            self.client_options.dns_resolver.clone(),
+            self.client_options.resolver_config.clone().map(|c| c.inner),
+            self.client_options.srv_service_name.clone(),
+        )
+        .await?;
 
         // Since the connection was not `Some` above, this will always insert the new connection and
         // return a reference to it.
@@ -26,8 +26,16 @@ lazy_static::lazy_static! {
 }
 
 async fn run_test(new_hosts: Result<Vec<ServerAddress>>, expected_hosts: HashSet<ServerAddress>) {
+    assert_eq!(expected_hosts, run_test_inner(new_hosts, None).await);
+}
+
+async fn run_test_inner(
+    new_hosts: Result<Vec<ServerAddress>>,
+    srv_max_hosts: Option<u32>,
+) -> HashSet<ServerAddress> {
     let mut options = ClientOptions::new_srv();
     options.hosts = DEFAULT_HOSTS.clone();
+    options.srv_max_hosts = srv_max_hosts;
     options.test_options_mut().disable_monitoring_threads = true;
     let mut topology = Topology::new(options.clone()).unwrap();
     topology.watch().wait_until_initialized().await;
@@ -38,7 +46,7 @@ async fn run_test(new_hosts: Result<Vec<ServerAddress>>, expected_hosts: HashSet
         .update_hosts(new_hosts.and_then(make_lookup_hosts))
         .await;
 
-    assert_eq!(expected_hosts, topology.server_addresses());
+    topology.server_addresses()
 }
 
 fn make_lookup_hosts(hosts: Vec<ServerAddress>) -> Result<LookupHosts> {
@@ -109,6 +117,51 @@ async fn no_results() {
     run_test(Ok(Vec::new()), DEFAULT_HOSTS.iter().cloned().collect()).await;
 }
 
+// srvMaxHosts=0 does not limit the number of hosts.
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+async fn srv_max_hosts_zero() {
+    let hosts = vec![
+        localhost_test_build_10gen(27017),
+        localhost_test_build_10gen(27019),
+        localhost_test_build_10gen(27020),
+    ];
+
+    let actual = run_test_inner(Ok(hosts.clone()), Some(0)).await;
+    assert_eq!(hosts.into_iter().collect::<HashSet<_>>(), actual);
+}
+
+// All hosts are used if srvMaxHosts is at least the number of DNS records.
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+async fn srv_max_hosts_gt_actual_dns() {
+    let hosts = vec![
+        localhost_test_build_10gen(27017),
+        localhost_test_build_10gen(27019),
+        localhost_test_build_10gen(27020),
+    ];
+
+    let actual = run_test_inner(Ok(hosts.clone()), Some(6)).await;
+    assert_eq!(hosts.into_iter().collect::<HashSet<_>>(), actual);
+}
+
+// If srvMaxHosts is less than the number of DNS records, existing hosts that are still present are
+// kept and removed ones are replaced with randomly chosen new hosts.
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+async fn srv_max_hosts_lt_actual_dns() {
+    let hosts = vec![
+        localhost_test_build_10gen(27017),
+        localhost_test_build_10gen(27019),
+        localhost_test_build_10gen(27020),
+    ];
+
+    let actual = run_test_inner(Ok(hosts.clone()), Some(2)).await;
+    assert_eq!(actual.len(), 2);
+    assert!(actual.contains(&localhost_test_build_10gen(27017)));
+    assert!(actual.iter().all(|host| hosts.contains(host)));
+}
+
 // SRV polling is not done for load-balanced clusters (as per spec at
 // https://github.com/mongodb/specifications/blob/master/source/polling-srv-records-for-mongos-discovery/tests/README.rst#test-that-srv-polling-is-not-done-for-load-balalanced-clusters).
 #[cfg_attr(feature = "tokio-runtime", tokio::test)]
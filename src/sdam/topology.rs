@@ -2,7 +2,7 @@ use std::{
     collections::{HashMap, HashSet},
     future::Future,
     sync::{Arc, Weak},
-    time::Duration,
+    time::{Duration, Instant},
 };
 
 use bson::oid::ObjectId;
@@ -1003,6 +1003,32 @@ impl TopologyWatcher {
         changed
     }
 
+    /// Wait until a topology description that differs from `previous` is published or the timeout
+    /// is reached, returning the new description if one was seen. If the latest description
+    /// already differs from `previous`, it is returned immediately.
+    ///
+    /// Unlike `wait_for_update`, this ignores published states whose description is unchanged.
+    pub(crate) async fn wait_for_description_change(
+        &mut self,
+        previous: &TopologyDescription,
+        timeout: impl Into<Option<Duration>>,
+    ) -> Option<TopologyDescription> {
+        let deadline = timeout.into().map(|timeout| Instant::now() + timeout);
+        loop {
+            let latest = self.receiver.borrow_and_update().description.clone();
+            if &latest != previous {
+                return Some(latest);
+            }
+            let remaining = match deadline {
+                Some(deadline) => Some(deadline.checked_duration_since(Instant::now())?),
+                None => None,
+            };
+            if !self.wait_for_update(remaining).await {
+                return None;
+            }
+        }
+    }
+
     fn retract_immediate_check_request(&mut self) {
         if self.requested_check {
             self.requested_check = false;
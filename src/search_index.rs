@@ -0,0 +1,38 @@
+pub mod options;
+
+use crate::bson::Document;
+
+use serde::{Deserialize, Serialize};
+use typed_builder::TypedBuilder;
+
+/// Specifies the definition and name of an Atlas Search or Vector Search index. For more
+/// information, see the [documentation](https://www.mongodb.com/docs/atlas/atlas-search/create-index/).
+#[serde_with::skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, TypedBuilder, Serialize)]
+#[builder(field_defaults(default, setter(into)))]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct SearchIndexModel {
+    /// The definition for this index.
+    pub definition: Document,
+
+    /// The name for this index, if present. If no name is given, the server will name the index
+    /// "default".
+    pub name: Option<String>,
+
+    /// The type of this index. If none is given, the server will create an Atlas Search index.
+    #[serde(rename = "type")]
+    pub index_type: Option<SearchIndexType>,
+}
+
+/// The type of a search index.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub enum SearchIndexType {
+    /// An Atlas Search index.
+    Search,
+
+    /// An Atlas Vector Search index.
+    VectorSearch,
+}
@@ -0,0 +1,82 @@
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+use typed_builder::TypedBuilder;
+
+use crate::{bson::Bson, options::AggregateOptions};
+
+/// Specifies the options to a
+/// [`Collection::create_search_index`](crate::Collection::create_search_index) or
+/// [`Collection::create_search_indexes`](crate::Collection::create_search_indexes) operation.
+#[serde_with::skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, TypedBuilder, Serialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+#[builder(field_defaults(default, setter(into)))]
+#[non_exhaustive]
+pub struct CreateSearchIndexOptions {
+    /// Tags the command with an arbitrary [`Bson`] value to help trace the operation through the
+    /// database profiler, currentOp and logs.
+    pub comment: Option<Bson>,
+}
+
+/// Specifies the options to a
+/// [`Collection::update_search_index`](crate::Collection::update_search_index) operation.
+#[serde_with::skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, TypedBuilder, Serialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+#[builder(field_defaults(default, setter(into)))]
+#[non_exhaustive]
+pub struct UpdateSearchIndexOptions {
+    /// Tags the command with an arbitrary [`Bson`] value to help trace the operation through the
+    /// database profiler, currentOp and logs.
+    pub comment: Option<Bson>,
+}
+
+/// Specifies the options to a
+/// [`Collection::drop_search_index`](crate::Collection::drop_search_index) operation.
+#[serde_with::skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, TypedBuilder, Serialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+#[builder(field_defaults(default, setter(into)))]
+#[non_exhaustive]
+pub struct DropSearchIndexOptions {
+    /// Tags the command with an arbitrary [`Bson`] value to help trace the operation through the
+    /// database profiler, currentOp and logs.
+    pub comment: Option<Bson>,
+}
+
+/// Specifies the options to a
+/// [`Collection::list_search_indexes`](crate::Collection::list_search_indexes) operation.
+#[derive(Clone, Debug, Default, Deserialize, TypedBuilder)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+#[builder(field_defaults(default, setter(into)))]
+#[non_exhaustive]
+pub struct ListSearchIndexOptions {
+    /// The number of indexes the server should return per cursor batch.
+    pub batch_size: Option<u32>,
+
+    /// The maximum amount of time to allow the query to run.
+    ///
+    /// This option maps to the `maxTimeMS` MongoDB query option, so the duration will be sent
+    /// across the wire as an integer number of milliseconds.
+    #[serde(
+        rename = "maxTimeMS",
+        default,
+        deserialize_with = "crate::serde_util::deserialize_duration_option_from_u64_millis"
+    )]
+    pub max_time: Option<Duration>,
+
+    /// Tags the query with an arbitrary [`Bson`] value to help trace the operation through the
+    /// database profiler, currentOp and logs.
+    pub comment: Option<Bson>,
+}
+
+impl ListSearchIndexOptions {
+    pub(crate) fn into_aggregate_options(self) -> AggregateOptions {
+        AggregateOptions::builder()
+            .batch_size(self.batch_size)
+            .max_time(self.max_time)
+            .comment_bson(self.comment)
+            .build()
+    }
+}
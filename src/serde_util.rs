@@ -109,6 +109,21 @@ where
     })
 }
 
+pub(crate) fn deserialize_u64_option_from_bson_number<'de, D>(
+    deserializer: D,
+) -> std::result::Result<Option<u64>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    Option::<Bson>::deserialize(deserializer)?
+        .map(|bson| {
+            get_u64(&bson).ok_or_else(|| {
+                serde::de::Error::custom(format!("could not deserialize u64 from {:?}", bson))
+            })
+        })
+        .transpose()
+}
+
 pub(crate) fn serialize_error_as_string<S: Serializer>(
     val: &Error,
     serializer: S,
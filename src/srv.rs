@@ -1,16 +1,20 @@
-use std::time::Duration;
+use std::{sync::Arc, time::Duration};
 
-use trust_dns_proto::rr::RData;
+use rand::seq::SliceRandom;
 use trust_dns_resolver::config::ResolverConfig;
 
 use crate::{
     error::{ErrorKind, Result},
-    options::ServerAddress,
+    options::{DnsResolver, ServerAddress},
     runtime::AsyncResolver,
 };
 
+/// The SRV service name used when none is specified.
+const DEFAULT_SRV_SERVICE_NAME: &str = "mongodb";
+
 pub(crate) struct SrvResolver {
-    resolver: AsyncResolver,
+    resolver: Arc<dyn DnsResolver>,
+    srv_service_name: String,
 }
 
 #[derive(Debug)]
@@ -35,10 +39,23 @@ pub(crate) struct OriginalSrvInfo {
 }
 
 impl SrvResolver {
-    pub(crate) async fn new(config: Option<ResolverConfig>) -> Result<Self> {
-        let resolver = AsyncResolver::new(config).await?;
+    /// Creates a new `SrvResolver`, using the given DNS resolver if provided or otherwise one
+    /// created from `config`.
+    pub(crate) async fn new(
+        dns_resolver: Option<Arc<dyn DnsResolver>>,
+        config: Option<ResolverConfig>,
+        srv_service_name: Option<String>,
+    ) -> Result<Self> {
+        let resolver = match dns_resolver {
+            Some(resolver) => resolver,
+            None => Arc::new(AsyncResolver::new(config).await?),
+        };
 
-        Ok(Self { resolver })
+        Ok(Self {
+            resolver,
+            srv_service_name: srv_service_name
+                .unwrap_or_else(|| DEFAULT_SRV_SERVICE_NAME.to_string()),
+        })
     }
 
     pub(crate) async fn resolve_client_options(
@@ -73,23 +90,16 @@ impl SrvResolver {
             .into());
         }
 
-        let lookup_hostname = format!("_mongodb._tcp.{}", original_hostname);
+        let lookup_hostname = format!("_{}._tcp.{}", self.srv_service_name, original_hostname);
 
-        let srv_lookup = self.resolver.srv_lookup(lookup_hostname.as_str()).await?;
+        let srv_records = self.resolver.srv_lookup(lookup_hostname.as_str()).await?;
         let mut srv_addresses: Vec<Result<ServerAddress>> = Vec::new();
-        let mut min_ttl = u32::MAX;
+        let mut min_ttl = Duration::MAX;
 
-        for record in srv_lookup.as_lookup().record_iter() {
-            let srv = match record.data() {
-                Some(RData::SRV(s)) => s,
-                _ => continue,
-            };
-
-            let hostname = srv.target().to_utf8();
-            let port = Some(srv.port());
+        for record in srv_records {
             let mut address = ServerAddress::Tcp {
-                host: hostname,
-                port,
+                host: record.target,
+                port: Some(record.port),
             };
 
             let domain_name = &hostname_parts[1..];
@@ -121,7 +131,7 @@ impl SrvResolver {
                 port: address.port(),
             };
 
-            min_ttl = std::cmp::min(min_ttl, record.ttl());
+            min_ttl = std::cmp::min(min_ttl, record.ttl);
             srv_addresses.push(Ok(address));
         }
 
@@ -134,7 +144,7 @@ impl SrvResolver {
 
         Ok(LookupHosts {
             hosts: srv_addresses,
-            min_ttl: Duration::from_secs(min_ttl.into()),
+            min_ttl,
         })
     }
 
@@ -143,13 +153,13 @@ impl SrvResolver {
         original_hostname: &str,
         config: &mut ResolvedConfig,
     ) -> Result<()> {
-        let txt_records_response = match self.resolver.txt_lookup(original_hostname).await? {
-            Some(response) => response,
-            None => return Ok(()),
-        };
-        let mut txt_records = txt_records_response.iter();
+        let mut txt_records = self
+            .resolver
+            .txt_lookup(original_hostname)
+            .await?
+            .into_iter();
 
-        let txt_record = match txt_records.next() {
+        let txt_string = match txt_records.next() {
             Some(record) => record,
             None => return Ok(()),
         };
@@ -165,14 +175,6 @@ impl SrvResolver {
             .into());
         }
 
-        let txt_data: Vec<_> = txt_record
-            .txt_data()
-            .iter()
-            .map(|bytes| String::from_utf8_lossy(bytes.as_ref()).into_owned())
-            .collect();
-
-        let txt_string = txt_data.join("");
-
         for option_pair in txt_string.split('&') {
             let parts: Vec<_> = option_pair.split('=').collect();
 
@@ -226,3 +228,19 @@ impl SrvResolver {
         Ok(())
     }
 }
+
+/// Randomly chooses at most `srv_max_hosts` of the given hosts. A limit of zero means that all of
+/// the hosts are used.
+pub(crate) fn choose_hosts(
+    mut hosts: Vec<ServerAddress>,
+    srv_max_hosts: Option<u32>,
+) -> Vec<ServerAddress> {
+    match srv_max_hosts {
+        Some(max) if max > 0 && hosts.len() > max as usize => {
+            hosts.shuffle(&mut rand::thread_rng());
+            hosts.truncate(max as usize);
+            hosts
+        }
+        _ => hosts,
+    }
+}
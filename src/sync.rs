@@ -13,7 +13,7 @@ mod test;
 pub use change_stream::{ChangeStream, SessionChangeStream};
 pub use client::{session::ClientSession, Client};
 pub use coll::Collection;
-pub use cursor::{Cursor, SessionCursor, SessionCursorIter};
+pub use cursor::{Cursor, ResumableCursor, SessionCursor, SessionCursorIter};
 pub use db::Database;
 
 #[cfg(feature = "tokio-sync")]
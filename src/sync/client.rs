@@ -1,19 +1,25 @@
 pub mod session;
 
+use std::time::Duration;
+
 use super::{ChangeStream, ClientSession, Database, SessionChangeStream};
 use crate::{
     bson::Document,
     change_stream::{event::ChangeStreamEvent, options::ChangeStreamOptions},
     concern::{ReadConcern, WriteConcern},
     error::Result,
+    event::sdam::TopologyDescription,
     options::{
+        BulkWriteOptions,
         ClientOptions,
         DatabaseOptions,
         ListDatabasesOptions,
         SelectionCriteria,
+        ServerStatusOptions,
         SessionOptions,
+        WriteModel,
     },
-    results::DatabaseSpecification,
+    results::{BulkWriteResult, DatabaseSpecification, ServerStatus},
     runtime,
     Client as AsyncClient,
 };
@@ -117,6 +123,11 @@ impl Client {
         self.async_client.write_concern()
     }
 
+    /// Gets the default timeout the `Client` uses for operations.
+    pub fn timeout(&self) -> Option<Duration> {
+        self.async_client.timeout()
+    }
+
     /// Gets a handle to a database specified by `name` in the cluster the `Client` is connected to.
     /// The `Database` options (e.g. read preference and write concern) will default to those of the
     /// `Client`.
@@ -169,6 +180,45 @@ impl Client {
         )
     }
 
+    /// Gets the status of a server in the deployment, such as its version, uptime and operation
+    /// counters. The server is chosen according to the selection criteria in `options`, or the
+    /// `Client`'s selection criteria if none is provided.
+    pub fn server_status(
+        &self,
+        options: impl Into<Option<ServerStatusOptions>>,
+    ) -> Result<ServerStatus> {
+        runtime::block_on(self.async_client.server_status(options.into()))
+    }
+
+    /// Executes the writes described by `models` as one or more `bulkWrite` commands. See
+    /// [`crate::Client::bulk_write`] for more details.
+    ///
+    /// This method is only available on MongoDB 8.0+.
+    pub fn bulk_write(
+        &self,
+        models: impl IntoIterator<Item = impl Into<WriteModel>>,
+        options: impl Into<Option<BulkWriteOptions>>,
+    ) -> Result<BulkWriteResult> {
+        runtime::block_on(self.async_client.bulk_write(models, options.into()))
+    }
+
+    /// Executes the writes described by `models` as one or more `bulkWrite` commands using the
+    /// provided `ClientSession`. See [`crate::Client::bulk_write`] for more details.
+    ///
+    /// This method is only available on MongoDB 8.0+.
+    pub fn bulk_write_with_session(
+        &self,
+        models: impl IntoIterator<Item = impl Into<WriteModel>>,
+        options: impl Into<Option<BulkWriteOptions>>,
+        session: &mut ClientSession,
+    ) -> Result<BulkWriteResult> {
+        runtime::block_on(self.async_client.bulk_write_with_session(
+            models,
+            options.into(),
+            &mut session.async_client_session,
+        ))
+    }
+
     /// Starts a new `ClientSession`.
     pub fn start_session(&self, options: Option<SessionOptions>) -> Result<ClientSession> {
         runtime::block_on(self.async_client.start_session(options)).map(Into::into)
@@ -216,6 +266,23 @@ impl Client {
         .map(SessionChangeStream::new)
     }
 
+    /// Returns a snapshot of the client's current view of the topology, including the type of the
+    /// topology and the state of each of its servers (e.g. their round trip times or the errors
+    /// that caused them to be marked as unknown).
+    pub fn topology_description(&self) -> TopologyDescription {
+        self.async_client.topology_description()
+    }
+
+    /// Waits for the client's view of the topology to change, returning the new description. If
+    /// `timeout` is specified and elapses before a change occurs, `None` is returned. `None` is
+    /// also returned if the client is shut down while waiting.
+    pub fn wait_for_topology_change(
+        &self,
+        timeout: impl Into<Option<Duration>>,
+    ) -> Option<TopologyDescription> {
+        runtime::block_on(self.async_client.wait_for_topology_change(timeout.into()))
+    }
+
     /// Shut down this `Client`, terminating background thread workers and closing connections.
     /// This will wait for any live handles to server-side resources (see below) to be
     /// dropped and any associated server-side operations to finish.
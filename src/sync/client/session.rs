@@ -1,6 +1,6 @@
 use super::Client;
 use crate::{
-    bson::Document,
+    bson::{Document, Timestamp},
     client::session::ClusterTime,
     error::Result,
     options::{SessionOptions, TransactionOptions},
@@ -48,6 +48,13 @@ impl ClientSession {
         self.async_client_session.options()
     }
 
+    /// The point in time that reads in this session observe if it is a snapshot session. This is
+    /// either the time specified via [`SessionOptions::snapshot_time`] or, if none was given, the
+    /// time returned by the server in response to the first read in the session.
+    pub fn snapshot_time(&self) -> Option<Timestamp> {
+        self.async_client_session.snapshot_time()
+    }
+
     /// Set the cluster time to the provided one if it is greater than this session's highest seen
     /// cluster time or if this session's cluster time is `None`.
     pub fn advance_cluster_time(&mut self, to: &ClusterTime) {
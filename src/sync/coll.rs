@@ -1,8 +1,15 @@
-use std::{borrow::Borrow, fmt::Debug};
+use std::{borrow::Borrow, fmt::Debug, time::Duration};
 
 use serde::{de::DeserializeOwned, Serialize};
 
-use super::{ChangeStream, ClientSession, Cursor, SessionChangeStream, SessionCursor};
+use super::{
+    ChangeStream,
+    ClientSession,
+    Cursor,
+    ResumableCursor,
+    SessionChangeStream,
+    SessionCursor,
+};
 use crate::{
     bson::{Bson, Document},
     change_stream::{event::ChangeStreamEvent, options::ChangeStreamOptions},
@@ -10,13 +17,19 @@ use crate::{
     index::IndexModel,
     options::{
         AggregateOptions,
+        CollModOptions,
+        CollectionBulkWriteOptions,
+        CollectionWriteModel,
         CountOptions,
         CreateIndexOptions,
+        CreateSearchIndexOptions,
         DeleteOptions,
         DistinctOptions,
         DropCollectionOptions,
         DropIndexOptions,
+        DropSearchIndexOptions,
         EstimatedDocumentCountOptions,
+        ExplainVerbosity,
         FindOneAndDeleteOptions,
         FindOneAndReplaceOptions,
         FindOneAndUpdateOptions,
@@ -24,15 +37,22 @@ use crate::{
         FindOptions,
         InsertManyOptions,
         InsertOneOptions,
+        LatencyStatsOptions,
         ListIndexesOptions,
+        ListSearchIndexOptions,
         ReadConcern,
         ReplaceOptions,
         SelectionCriteria,
+        StorageStatsOptions,
         UpdateModifications,
         UpdateOptions,
+        UpdateSearchIndexOptions,
         WriteConcern,
     },
     results::{
+        BulkWriteResult,
+        CollectionLatencyStats,
+        CollectionStorageStats,
         CreateIndexResult,
         CreateIndexesResult,
         DeleteResult,
@@ -41,6 +61,7 @@ use crate::{
         UpdateResult,
     },
     runtime,
+    search_index::SearchIndexModel,
     Collection as AsyncCollection,
     Namespace,
 };
@@ -126,6 +147,11 @@ impl<T> Collection<T> {
         self.async_collection.write_concern()
     }
 
+    /// Gets the default operation timeout of the `Collection`.
+    pub fn timeout(&self) -> Option<Duration> {
+        self.async_collection.timeout()
+    }
+
     /// Drops the collection, deleting all data, users, and indexes stored in it.
     pub fn drop(&self, options: impl Into<Option<DropCollectionOptions>>) -> Result<()> {
         runtime::block_on(self.async_collection.drop(options.into()))
@@ -144,6 +170,52 @@ impl<T> Collection<T> {
         )
     }
 
+    /// Modifies the collection with the
+    /// [`collMod`](https://www.mongodb.com/docs/manual/reference/command/collMod/) command, e.g. to
+    /// change its validator, hide one of its indexes, or change how long documents are kept by a
+    /// TTL index.
+    pub fn modify(&self, options: CollModOptions) -> Result<()> {
+        runtime::block_on(self.async_collection.modify(options))
+    }
+
+    /// Modifies the collection with the
+    /// [`collMod`](https://www.mongodb.com/docs/manual/reference/command/collMod/) command using the
+    /// provided `ClientSession`.
+    pub fn modify_with_session(
+        &self,
+        options: CollModOptions,
+        session: &mut ClientSession,
+    ) -> Result<()> {
+        runtime::block_on(
+            self.async_collection
+                .modify_with_session(options, &mut session.async_client_session),
+        )
+    }
+
+    /// Renames the collection to `new_namespace`, which may be in a different database. If
+    /// `drop_target` is true, any existing collection at `new_namespace` will be dropped first;
+    /// otherwise, the rename will fail if such a collection exists.
+    ///
+    /// This handle will continue to refer to the old namespace after the rename.
+    pub fn rename(&self, new_namespace: Namespace, drop_target: bool) -> Result<()> {
+        runtime::block_on(self.async_collection.rename(new_namespace, drop_target))
+    }
+
+    /// Renames the collection to `new_namespace` using the provided `ClientSession`. See
+    /// [`Collection::rename`] for more details.
+    pub fn rename_with_session(
+        &self,
+        new_namespace: Namespace,
+        drop_target: bool,
+        session: &mut ClientSession,
+    ) -> Result<()> {
+        runtime::block_on(self.async_collection.rename_with_session(
+            new_namespace,
+            drop_target,
+            &mut session.async_client_session,
+        ))
+    }
+
     /// Runs an aggregation operation.
     ///
     /// See the documentation [here](https://www.mongodb.com/docs/manual/aggregation/) for more
@@ -459,6 +531,79 @@ impl<T> Collection<T> {
         )
     }
 
+    /// Creates the given Atlas Search index on this collection. Returns the name of the created
+    /// index.
+    pub fn create_search_index(
+        &self,
+        model: SearchIndexModel,
+        options: impl Into<Option<CreateSearchIndexOptions>>,
+    ) -> Result<String> {
+        runtime::block_on(self.async_collection.create_search_index(model, options))
+    }
+
+    /// Creates the given Atlas Search indexes on this collection. Returns the names of the created
+    /// indexes.
+    pub fn create_search_indexes(
+        &self,
+        models: impl IntoIterator<Item = SearchIndexModel>,
+        options: impl Into<Option<CreateSearchIndexOptions>>,
+    ) -> Result<Vec<String>> {
+        runtime::block_on(self.async_collection.create_search_indexes(models, options))
+    }
+
+    /// Updates the definition of the Atlas Search index with the given name on this collection.
+    pub fn update_search_index(
+        &self,
+        name: impl AsRef<str>,
+        definition: Document,
+        options: impl Into<Option<UpdateSearchIndexOptions>>,
+    ) -> Result<()> {
+        runtime::block_on(
+            self.async_collection
+                .update_search_index(name, definition, options),
+        )
+    }
+
+    /// Drops the Atlas Search index with the given name from this collection. Succeeds if the
+    /// collection does not exist.
+    pub fn drop_search_index(
+        &self,
+        name: impl AsRef<str>,
+        options: impl Into<Option<DropSearchIndexOptions>>,
+    ) -> Result<()> {
+        runtime::block_on(self.async_collection.drop_search_index(name, options))
+    }
+
+    /// Lists the Atlas Search indexes on this collection, or only the index with the given name if
+    /// one is provided.
+    pub fn list_search_indexes<'a>(
+        &self,
+        name: impl Into<Option<&'a str>>,
+        options: impl Into<Option<ListSearchIndexOptions>>,
+    ) -> Result<Cursor<Document>> {
+        runtime::block_on(self.async_collection.list_search_indexes(name, options)).map(Cursor::new)
+    }
+
+    /// Gets storage statistics about the collection, such as its size and the sizes of its
+    /// indexes. This is executed as an aggregation with a `$collStats` stage, so on a sharded
+    /// cluster one set of statistics is returned for each shard the collection is stored on.
+    pub fn storage_stats(
+        &self,
+        options: impl Into<Option<StorageStatsOptions>>,
+    ) -> Result<Vec<CollectionStorageStats>> {
+        runtime::block_on(self.async_collection.storage_stats(options.into()))
+    }
+
+    /// Gets statistics about the latency of the reads, writes and commands performed on the
+    /// collection. This is executed as an aggregation with a `$collStats` stage, so on a sharded
+    /// cluster one set of statistics is returned for each shard the collection is stored on.
+    pub fn latency_stats(
+        &self,
+        options: impl Into<Option<LatencyStatsOptions>>,
+    ) -> Result<Vec<CollectionLatencyStats>> {
+        runtime::block_on(self.async_collection.latency_stats(options.into()))
+    }
+
     /// Updates all documents matching `query` in the collection using the provided `ClientSession`.
     ///
     /// Both `Document` and `Vec<Document>` implement `Into<UpdateModifications>`, so either can be
@@ -603,6 +748,165 @@ impl<T> Collection<T> {
         ))
         .map(SessionCursor::new)
     }
+
+    /// Finds the documents in the collection matching `filter`, returning a [`ResumableCursor`]
+    /// that re-issues the `find` after the last returned document if the cursor is lost to a
+    /// resumable error. See [`Collection::find_resumable`](crate::Collection::find_resumable) for
+    /// more information.
+    pub fn find_resumable(
+        &self,
+        filter: impl Into<Option<Document>>,
+        options: impl Into<Option<FindOptions>>,
+    ) -> Result<ResumableCursor<T>>
+    where
+        T: DeserializeOwned + Unpin + Send + Sync,
+    {
+        runtime::block_on(
+            self.async_collection
+                .find_resumable(filter.into(), options.into()),
+        )
+        .map(ResumableCursor::new)
+    }
+
+    /// Explains a [`Collection::find`] operation with the given verbosity, returning the raw
+    /// output of the server's `explain` command.
+    pub fn explain_find(
+        &self,
+        filter: impl Into<Option<Document>>,
+        options: impl Into<Option<FindOptions>>,
+        verbosity: ExplainVerbosity,
+    ) -> Result<Document> {
+        runtime::block_on(self.async_collection.explain_find(
+            filter.into(),
+            options.into(),
+            verbosity,
+        ))
+    }
+
+    /// Explains a [`Collection::aggregate`] operation with the given verbosity, returning the raw
+    /// output of the server's `explain` command.
+    pub fn explain_aggregate(
+        &self,
+        pipeline: impl IntoIterator<Item = Document>,
+        options: impl Into<Option<AggregateOptions>>,
+        verbosity: ExplainVerbosity,
+    ) -> Result<Document> {
+        let pipeline: Vec<Document> = pipeline.into_iter().collect();
+        runtime::block_on(self.async_collection.explain_aggregate(
+            pipeline,
+            options.into(),
+            verbosity,
+        ))
+    }
+
+    /// Explains a [`Collection::count_documents`] operation with the given verbosity, returning
+    /// the raw output of the server's `explain` command.
+    pub fn explain_count_documents(
+        &self,
+        filter: impl Into<Option<Document>>,
+        options: impl Into<Option<CountOptions>>,
+        verbosity: ExplainVerbosity,
+    ) -> Result<Document> {
+        runtime::block_on(self.async_collection.explain_count_documents(
+            filter.into(),
+            options.into(),
+            verbosity,
+        ))
+    }
+
+    /// Explains a [`Collection::estimated_document_count`] operation with the given verbosity,
+    /// returning the raw output of the server's `explain` command.
+    pub fn explain_estimated_document_count(
+        &self,
+        options: impl Into<Option<EstimatedDocumentCountOptions>>,
+        verbosity: ExplainVerbosity,
+    ) -> Result<Document> {
+        runtime::block_on(
+            self.async_collection
+                .explain_estimated_document_count(options.into(), verbosity),
+        )
+    }
+
+    /// Explains a [`Collection::distinct`] operation with the given verbosity, returning the raw
+    /// output of the server's `explain` command.
+    pub fn explain_distinct(
+        &self,
+        field_name: impl AsRef<str>,
+        filter: impl Into<Option<Document>>,
+        options: impl Into<Option<DistinctOptions>>,
+        verbosity: ExplainVerbosity,
+    ) -> Result<Document> {
+        runtime::block_on(self.async_collection.explain_distinct(
+            field_name.as_ref(),
+            filter.into(),
+            options.into(),
+            verbosity,
+        ))
+    }
+
+    /// Explains a [`Collection::update_one`] operation with the given verbosity, returning the
+    /// raw output of the server's `explain` command. The update is not performed.
+    pub fn explain_update_one(
+        &self,
+        query: Document,
+        update: impl Into<UpdateModifications>,
+        options: impl Into<Option<UpdateOptions>>,
+        verbosity: ExplainVerbosity,
+    ) -> Result<Document> {
+        runtime::block_on(self.async_collection.explain_update_one(
+            query,
+            update.into(),
+            options.into(),
+            verbosity,
+        ))
+    }
+
+    /// Explains a [`Collection::update_many`] operation with the given verbosity, returning the
+    /// raw output of the server's `explain` command. The update is not performed.
+    pub fn explain_update_many(
+        &self,
+        query: Document,
+        update: impl Into<UpdateModifications>,
+        options: impl Into<Option<UpdateOptions>>,
+        verbosity: ExplainVerbosity,
+    ) -> Result<Document> {
+        runtime::block_on(self.async_collection.explain_update_many(
+            query,
+            update.into(),
+            options.into(),
+            verbosity,
+        ))
+    }
+
+    /// Explains a [`Collection::delete_one`] operation with the given verbosity, returning the
+    /// raw output of the server's `explain` command. The delete is not performed.
+    pub fn explain_delete_one(
+        &self,
+        query: Document,
+        options: impl Into<Option<DeleteOptions>>,
+        verbosity: ExplainVerbosity,
+    ) -> Result<Document> {
+        runtime::block_on(self.async_collection.explain_delete_one(
+            query,
+            options.into(),
+            verbosity,
+        ))
+    }
+
+    /// Explains a [`Collection::delete_many`] operation with the given verbosity, returning the
+    /// raw output of the server's `explain` command. The delete is not performed.
+    pub fn explain_delete_many(
+        &self,
+        query: Document,
+        options: impl Into<Option<DeleteOptions>>,
+        verbosity: ExplainVerbosity,
+    ) -> Result<Document> {
+        runtime::block_on(self.async_collection.explain_delete_many(
+            query,
+            options.into(),
+            verbosity,
+        ))
+    }
 }
 
 impl<T> Collection<T>
@@ -809,6 +1113,60 @@ where
         ))
     }
 
+    /// Performs the writes described by `models` against the collection. Consecutive writes of
+    /// the same kind are sent to the server together in a single command, and the results of all
+    /// of the commands are combined into a single [`BulkWriteResult`].
+    ///
+    /// If any of the writes fail, a [`ErrorKind::BulkWrite`](crate::error::ErrorKind::BulkWrite)
+    /// error is returned whose write errors are indexed by the position of the failed write in
+    /// `models`. If the writes are ordered, no further writes are attempted after the first
+    /// failure. The results of the writes that did succeed are reported in
+    /// [`BulkWriteFailure::partial_result`](crate::error::BulkWriteFailure::partial_result). Any
+    /// other error that occurs after some of the writes were sent is returned the same way, with
+    /// the original error as its source.
+    ///
+    /// This operation will retry once upon failure if the connection and encountered error support
+    /// retryability and none of the writes in the failed command can affect multiple documents.
+    /// See the documentation [here](https://www.mongodb.com/docs/manual/core/retryable-writes/)
+    /// for more information on retryable writes.
+    pub fn bulk_write(
+        &self,
+        models: impl IntoIterator<Item = impl Into<CollectionWriteModel<T>>>,
+        options: impl Into<Option<CollectionBulkWriteOptions>>,
+    ) -> Result<BulkWriteResult> {
+        runtime::block_on(self.async_collection.bulk_write(models, options.into()))
+    }
+
+    /// Performs the writes described by `models` against the collection using the provided
+    /// `ClientSession`. Consecutive writes of the same kind are sent to the server together in a
+    /// single command, and the results of all of the commands are combined into a single
+    /// [`BulkWriteResult`].
+    ///
+    /// If any of the writes fail, a [`ErrorKind::BulkWrite`](crate::error::ErrorKind::BulkWrite)
+    /// error is returned whose write errors are indexed by the position of the failed write in
+    /// `models`. If the writes are ordered, no further writes are attempted after the first
+    /// failure. The results of the writes that did succeed are reported in
+    /// [`BulkWriteFailure::partial_result`](crate::error::BulkWriteFailure::partial_result). Any
+    /// other error that occurs after some of the writes were sent is returned the same way, with
+    /// the original error as its source.
+    ///
+    /// This operation will retry once upon failure if the connection and encountered error support
+    /// retryability and none of the writes in the failed command can affect multiple documents.
+    /// See the documentation [here](https://www.mongodb.com/docs/manual/core/retryable-writes/)
+    /// for more information on retryable writes.
+    pub fn bulk_write_with_session(
+        &self,
+        models: impl IntoIterator<Item = impl Into<CollectionWriteModel<T>>>,
+        options: impl Into<Option<CollectionBulkWriteOptions>>,
+        session: &mut ClientSession,
+    ) -> Result<BulkWriteResult> {
+        runtime::block_on(self.async_collection.bulk_write_with_session(
+            models,
+            options.into(),
+            &mut session.async_client_session,
+        ))
+    }
+
     /// Inserts `doc` into the collection.
     ///
     /// This operation will retry once upon failure if the connection and encountered error support
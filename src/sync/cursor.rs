@@ -7,6 +7,7 @@ use crate::{
     error::Result,
     runtime,
     Cursor as AsyncCursor,
+    ResumableCursor as AsyncResumableCursor,
     SessionCursor as AsyncSessionCursor,
     SessionCursorStream,
 };
@@ -178,6 +179,75 @@ where
     }
 }
 
+/// A `ResumableCursor` streams the results of a `find` like a [`Cursor`], but transparently
+/// re-issues the `find` after the last returned document if the cursor is lost to a resumable
+/// error, such as a network error during a primary failover. `ResumableCursor`s should be created
+/// with [`Collection::find_resumable`](crate::sync::Collection::find_resumable); see the
+/// [async documentation](crate::ResumableCursor) for details on how results are resumed.
+///
+/// ```rust
+/// # use mongodb::{bson::{doc, Document}, sync::Client, error::Result};
+/// #
+/// # fn do_stuff() -> Result<()> {
+/// # let client = Client::with_uri_str("mongodb://example.com")?;
+/// # let coll = client.database("foo").collection::<Document>("bar");
+/// #
+/// let cursor = coll.find_resumable(doc! { "archived": false }, None)?;
+/// for doc in cursor {
+///     println!("{}", doc?);
+/// }
+/// #
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct ResumableCursor<T>
+where
+    T: DeserializeOwned + Unpin + Send + Sync,
+{
+    async_cursor: AsyncResumableCursor<T>,
+}
+
+impl<T> ResumableCursor<T>
+where
+    T: DeserializeOwned + Unpin + Send + Sync,
+{
+    pub(crate) fn new(async_cursor: AsyncResumableCursor<T>) -> Self {
+        Self { async_cursor }
+    }
+
+    /// Returns the sort key of the most recently returned document, keyed by sort field path.
+    pub fn resume_key(&self) -> Option<Document> {
+        self.async_cursor.resume_key()
+    }
+
+    /// Update the type streamed values will be parsed as.
+    pub fn with_type<D: DeserializeOwned + Unpin + Send + Sync>(self) -> ResumableCursor<D> {
+        ResumableCursor {
+            async_cursor: self.async_cursor.with_type(),
+        }
+    }
+
+    /// Retrieves the next result from the cursor, if any.
+    ///
+    /// Where calling `Iterator::next` will internally loop until a document is received, this will
+    /// make at most one request and return `None` if the returned document batch is empty.
+    pub fn next_if_any(&mut self) -> Result<Option<T>> {
+        runtime::block_on(self.async_cursor.next_if_any())
+    }
+}
+
+impl<T> Iterator for ResumableCursor<T>
+where
+    T: DeserializeOwned + Unpin + Send + Sync,
+{
+    type Item = Result<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        runtime::block_on(self.async_cursor.next())
+    }
+}
+
 /// A `SessionCursor` is a cursor that was created with a `ClientSession` must be iterated using
 /// one. To iterate, retrieve a [`SessionCursorIter]` using [`SessionCursor::iter`]:
 ///
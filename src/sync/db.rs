@@ -1,4 +1,4 @@
-use std::fmt::Debug;
+use std::{fmt::Debug, time::Duration};
 
 use super::{
     gridfs::GridFsBucket,
@@ -17,14 +17,24 @@ use crate::{
         AggregateOptions,
         CollectionOptions,
         CreateCollectionOptions,
+        CreateRoleOptions,
+        CreateUserOptions,
+        DbStatsOptions,
         DropDatabaseOptions,
+        DropRoleOptions,
+        DropUserOptions,
+        GrantRolesToUserOptions,
         GridFsBucketOptions,
         ListCollectionsOptions,
         ReadConcern,
+        Role,
+        RolesInfoOptions,
         SelectionCriteria,
+        UpdateUserOptions,
+        UsersInfoOptions,
         WriteConcern,
     },
-    results::CollectionSpecification,
+    results::{CollectionSpecification, DatabaseStats, RoleInfo, UserInfo},
     runtime,
     Database as AsyncDatabase,
 };
@@ -91,6 +101,11 @@ impl Database {
         self.async_database.write_concern()
     }
 
+    /// Gets the default operation timeout of the `Database`.
+    pub fn timeout(&self) -> Option<Duration> {
+        self.async_database.timeout()
+    }
+
     /// Gets a handle to a collection with type `T` specified by `name` of the database. The
     /// `Collection` options (e.g. read preference and write concern) will default to those of the
     /// `Database`.
@@ -219,6 +234,138 @@ impl Database {
         ))
     }
 
+    /// Creates a new view named `name` in the database, whose contents are the result of running
+    /// the aggregation `pipeline` against the collection or view named `source` in this database.
+    pub fn create_view(
+        &self,
+        name: impl AsRef<str>,
+        source: impl Into<String>,
+        pipeline: impl IntoIterator<Item = Document>,
+    ) -> Result<()> {
+        let pipeline: Vec<Document> = pipeline.into_iter().collect();
+        runtime::block_on(
+            self.async_database
+                .create_view(name.as_ref(), source.into(), pipeline),
+        )
+    }
+
+    /// Creates a new view named `name` in the database using the provided `ClientSession`. See
+    /// [`Database::create_view`] for more details.
+    pub fn create_view_with_session(
+        &self,
+        name: impl AsRef<str>,
+        source: impl Into<String>,
+        pipeline: impl IntoIterator<Item = Document>,
+        session: &mut ClientSession,
+    ) -> Result<()> {
+        let pipeline: Vec<Document> = pipeline.into_iter().collect();
+        runtime::block_on(self.async_database.create_view_with_session(
+            name.as_ref(),
+            source.into(),
+            pipeline,
+            &mut session.async_client_session,
+        ))
+    }
+
+    /// Gets statistics about the database, such as the number of documents it holds and the
+    /// amount of storage allocated to it.
+    pub fn stats(&self, options: impl Into<Option<DbStatsOptions>>) -> Result<DatabaseStats> {
+        runtime::block_on(self.async_database.stats(options.into()))
+    }
+
+    /// Creates a new user named `name` in the database. The password in `options`, if any, is
+    /// redacted from command monitoring events and tracing output.
+    pub fn create_user(
+        &self,
+        name: impl AsRef<str>,
+        options: impl Into<Option<CreateUserOptions>>,
+    ) -> Result<()> {
+        runtime::block_on(
+            self.async_database
+                .create_user(name.as_ref(), options.into()),
+        )
+    }
+
+    /// Updates the user named `name` in the database. Only the fields set in `options` are
+    /// changed; fields such as `roles` replace the user's existing values rather than being
+    /// merged with them.
+    pub fn update_user(
+        &self,
+        name: impl AsRef<str>,
+        options: impl Into<Option<UpdateUserOptions>>,
+    ) -> Result<()> {
+        runtime::block_on(
+            self.async_database
+                .update_user(name.as_ref(), options.into()),
+        )
+    }
+
+    /// Removes the user named `name` from the database.
+    pub fn drop_user(
+        &self,
+        name: impl AsRef<str>,
+        options: impl Into<Option<DropUserOptions>>,
+    ) -> Result<()> {
+        runtime::block_on(self.async_database.drop_user(name.as_ref(), options.into()))
+    }
+
+    /// Gets information about the users with the given `names` in the database, or about all of
+    /// the database's users if `names` is `None`.
+    pub fn users_info(
+        &self,
+        names: impl Into<Option<Vec<String>>>,
+        options: impl Into<Option<UsersInfoOptions>>,
+    ) -> Result<Vec<UserInfo>> {
+        runtime::block_on(self.async_database.users_info(names.into(), options.into()))
+    }
+
+    /// Grants the given `roles` to the user named `name` in the database, in addition to the
+    /// roles it already has.
+    pub fn grant_roles_to_user(
+        &self,
+        name: impl AsRef<str>,
+        roles: impl IntoIterator<Item = Role>,
+        options: impl Into<Option<GrantRolesToUserOptions>>,
+    ) -> Result<()> {
+        let roles: Vec<Role> = roles.into_iter().collect();
+        runtime::block_on(self.async_database.grant_roles_to_user(
+            name.as_ref(),
+            roles,
+            options.into(),
+        ))
+    }
+
+    /// Creates a new user-defined role named `name` in the database.
+    pub fn create_role(
+        &self,
+        name: impl AsRef<str>,
+        options: impl Into<Option<CreateRoleOptions>>,
+    ) -> Result<()> {
+        runtime::block_on(
+            self.async_database
+                .create_role(name.as_ref(), options.into()),
+        )
+    }
+
+    /// Removes the user-defined role named `name` from the database.
+    pub fn drop_role(
+        &self,
+        name: impl AsRef<str>,
+        options: impl Into<Option<DropRoleOptions>>,
+    ) -> Result<()> {
+        runtime::block_on(self.async_database.drop_role(name.as_ref(), options.into()))
+    }
+
+    /// Gets information about the roles with the given `names` in the database, or about all of
+    /// the database's user-defined roles if `names` is `None`.
+    pub fn roles_info(
+        &self,
+        names: impl Into<Option<Vec<String>>>,
+        options: impl Into<Option<RolesInfoOptions>>,
+    ) -> Result<Vec<RoleInfo>> {
+        runtime::block_on(self.async_database.roles_info(names.into(), options.into()))
+    }
+
     /// Runs a database-level command.
     ///
     /// Note that no inspection is done on `doc`, so the command will not use the database's default
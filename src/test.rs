@@ -15,6 +15,8 @@ mod documentation_examples;
 mod index_management;
 #[cfg(all(not(feature = "sync"), not(feature = "tokio-sync")))]
 mod lambda_examples;
+#[cfg(feature = "mock-server")]
+mod mock_server;
 pub(crate) mod spec;
 mod timeseries;
 pub(crate) mod util;
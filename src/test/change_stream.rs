@@ -733,16 +733,111 @@ async fn split_large_event() -> Result<()> {
     )
     .await?;
 
-    let events: Vec<_> = stream.take(2).try_collect().await?;
-    assert_eq!(2, events.len());
+    // The fragments of the split event are reassembled into a single event.
+    let events: Vec<_> = stream.take(1).try_collect().await?;
+    assert_eq!(1, events.len());
+    let event = &events[0];
+    assert!(!event.contains_key("splitEvent"));
+    assert_eq!(event.get_str("operationType")?, "update");
     assert_eq!(
-        events[0].get_document("splitEvent")?,
-        &doc! { "fragment": 1, "of": 2 },
+        event
+            .get_document("fullDocumentBeforeChange")?
+            .get_str("value")?
+            .len(),
+        10 * 1024 * 1024
     );
+    assert!(event
+        .get_document("updateDescription")?
+        .contains_key("updatedFields"));
+
+    Ok(())
+}
+
+#[test]
+fn split_event_reassembly() {
+    use crate::change_stream::SplitEventBuffer;
+
+    let mut buffer = SplitEventBuffer::default();
+    let whole = bson::rawdoc! { "_id": { "token": 0 }, "operationType": "insert" };
+    assert_eq!(buffer.push(whole.clone()).unwrap(), Some(whole));
+
+    let fragments = [
+        bson::rawdoc! {
+            "_id": { "token": 1 },
+            "splitEvent": { "fragment": 1, "of": 3 },
+            "operationType": "update",
+            "fullDocumentBeforeChange": { "a": 1 },
+        },
+        bson::rawdoc! {
+            "_id": { "token": 2 },
+            "splitEvent": { "fragment": 2, "of": 3 },
+            "updateDescription": { "updatedFields": { "a": 2 }, "removedFields": [] },
+        },
+        bson::rawdoc! {
+            "_id": { "token": 3 },
+            "splitEvent": { "fragment": 3, "of": 3 },
+            "fullDocument": { "a": 2 },
+        },
+    ];
+    assert_eq!(buffer.push(fragments[0].clone()).unwrap(), None);
+    assert_eq!(buffer.push(fragments[1].clone()).unwrap(), None);
+    let event = buffer.push(fragments[2].clone()).unwrap().unwrap();
     assert_eq!(
-        events[1].get_document("splitEvent")?,
-        &doc! { "fragment": 2, "of": 2 },
+        event.to_document().unwrap(),
+        doc! {
+            "_id": { "token": 3 },
+            "operationType": "update",
+            "fullDocumentBeforeChange": { "a": 1 },
+            "updateDescription": { "updatedFields": { "a": 2 }, "removedFields": [] },
+            "fullDocument": { "a": 2 },
+        }
     );
 
-    Ok(())
+    // Fragments must be received in order.
+    let mut buffer = SplitEventBuffer::default();
+    assert!(buffer.push(fragments[1].clone()).is_err());
+    let mut buffer = SplitEventBuffer::default();
+    buffer.push(fragments[0].clone()).unwrap();
+    assert!(buffer
+        .push(bson::rawdoc! { "_id": { "token": 4 }, "operationType": "insert" })
+        .is_err());
+}
+
+#[test]
+fn expanded_event_deserialization() {
+    let event: ChangeStreamEvent<Document> = bson::from_document(doc! {
+        "_id": { "token": 1 },
+        "operationType": "createIndexes",
+        "ns": { "db": "db", "coll": "coll" },
+        "collectionUUID": bson::Binary {
+            subtype: bson::spec::BinarySubtype::Uuid,
+            bytes: vec![0; 16],
+        },
+        "operationDescription": { "indexes": [{ "v": 2, "key": { "x": 1 }, "name": "x_1" }] },
+        "wallTime": bson::DateTime::from_millis(1000),
+    })
+    .unwrap();
+    assert_eq!(event.operation_type, OperationType::CreateIndexes);
+    assert_eq!(event.collection_uuid.unwrap().bytes, vec![0; 16]);
+    assert!(event.operation_description.unwrap().contains_key("indexes"));
+    assert_eq!(event.wall_time, Some(bson::DateTime::from_millis(1000)));
+
+    for (name, operation_type) in [
+        ("create", OperationType::Create),
+        ("dropIndexes", OperationType::DropIndexes),
+        ("modify", OperationType::Modify),
+        ("shardCollection", OperationType::ShardCollection),
+        ("reshardCollection", OperationType::ReshardCollection),
+        (
+            "refineCollectionShardKey",
+            OperationType::RefineCollectionShardKey,
+        ),
+    ] {
+        let parsed: OperationType = bson::from_bson(Bson::String(name.to_string())).unwrap();
+        assert_eq!(parsed, operation_type);
+        assert_eq!(
+            bson::to_bson(&parsed).unwrap(),
+            Bson::String(name.to_string())
+        );
+    }
 }
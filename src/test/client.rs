@@ -1,15 +1,39 @@
-use std::{borrow::Cow, collections::HashMap, sync::Arc, time::Duration};
+use std::{
+    borrow::Cow,
+    collections::HashMap,
+    path::PathBuf,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
 
 use bson::Document;
 use serde::{Deserialize, Serialize};
 
 use crate::{
     bson::{doc, Bson},
-    coll::options::FindOptions,
+    coll::options::{FindOneOptions, FindOptions},
     error::{CommandError, Error, ErrorKind},
     event::cmap::CmapEvent,
     hello::LEGACY_HELLO_COMMAND_NAME,
-    options::{AuthMechanism, ClientOptions, Credential, ListDatabasesOptions, ServerAddress},
+    options::{
+        AuthMechanism,
+        BulkWriteOptions,
+        ClientCertificateProvider,
+        ClientOptions,
+        Credential,
+        DatabaseOptions,
+        DeleteOneModel,
+        InsertOneModel,
+        ListDatabasesOptions,
+        ServerAddress,
+        Tls,
+        TlsOptions,
+        UpdateOneModel,
+        WriteModel,
+    },
     runtime,
     selection_criteria::{ReadPreference, ReadPreferenceOptions, SelectionCriteria},
     test::{
@@ -822,6 +846,222 @@ async fn retry_commit_txn_check_out() {
         .expect("should see checked out event");
 }
 
+/// Verifies that an operation blocked on the server fails with a timeout error once its timeout
+/// elapses, and that the remaining budget is sent to the server as `maxTimeMS`.
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+async fn operation_timeout() {
+    let handler = Arc::new(EventHandler::new());
+    let client = Client::test_builder()
+        .event_handler(handler.clone())
+        .build()
+        .await;
+    if !client.supports_block_connection() {
+        log_uncaptured("skipping operation_timeout due to blockConnection not being supported");
+        return;
+    }
+
+    let coll = client
+        .database("operation_timeout")
+        .collection::<Document>("operation_timeout");
+    coll.insert_one(doc! { "x": 1 }, None).await.unwrap();
+
+    let options = FailCommandOptions::builder()
+        .block_connection(Duration::from_millis(500))
+        .build();
+    let failpoint = FailPoint::fail_command(&["find"], FailPointMode::Times(1), Some(options));
+    let _guard = client.enable_failpoint(failpoint, None).await.unwrap();
+
+    let options = FindOneOptions::builder()
+        .timeout(Duration::from_millis(100))
+        .build();
+    let error = coll.find_one(doc! {}, options).await.unwrap_err();
+    assert!(
+        error.is_timeout_error(),
+        "expected timeout error, got {:?}",
+        error
+    );
+
+    let started = handler.get_command_started_events(&["find"]);
+    let max_time_ms = started[0].command.get_i64("maxTimeMS").unwrap();
+    assert!(max_time_ms > 0 && max_time_ms <= 100);
+}
+
+/// Verifies that a cursor's `getMore`s share the timeout of the operation that created it rather
+/// than each getting the full timeout.
+#[cfg_attr(feature = "tokio-runtime", tokio::test(flavor = "multi_thread"))]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+async fn cursor_timeout_covers_get_more() {
+    let client = Client::test_builder().build().await;
+    if !client.supports_block_connection() {
+        log_uncaptured(
+            "skipping cursor_timeout_covers_get_more due to blockConnection not being supported",
+        );
+        return;
+    }
+
+    let coll = client
+        .init_db_and_coll("cursor_timeout_covers_get_more", "coll")
+        .await;
+    coll.insert_many((0..3).map(|i| doc! { "x": i }), None)
+        .await
+        .unwrap();
+
+    let options = FailCommandOptions::builder()
+        .block_connection(Duration::from_millis(250))
+        .build();
+    let failpoint = FailPoint::fail_command(&["getMore"], FailPointMode::Times(2), Some(options));
+    let _guard = client.enable_failpoint(failpoint, None).await.unwrap();
+
+    let options = FindOptions::builder()
+        .batch_size(1)
+        .timeout(Duration::from_millis(400))
+        .build();
+    let mut cursor = coll.find(doc! {}, options).await.unwrap();
+    // The first batch and the first getMore fit within the timeout, but the second getMore only
+    // gets what is left of it.
+    assert!(cursor.advance().await.unwrap());
+    assert!(cursor.advance().await.unwrap());
+    let error = cursor.advance().await.unwrap_err();
+    assert!(
+        error.is_timeout_error(),
+        "expected timeout error, got {:?}",
+        error
+    );
+}
+
+/// Verifies that `Client::bulk_write` performs writes across namespaces and reports verbose results
+/// and write errors keyed by the index of the corresponding model.
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+async fn bulk_write_multiple_namespaces() {
+    let client = TestClient::new().await;
+    if client.server_version_lt(8, 0) {
+        log_uncaptured("skipping bulk_write_multiple_namespaces: requires server 8.0+");
+        return;
+    }
+
+    let coll_a = client
+        .init_db_and_coll("bulk_write_multiple_namespaces", "a")
+        .await;
+    let coll_b = client
+        .init_db_and_coll("bulk_write_multiple_namespaces", "b")
+        .await;
+
+    let models: Vec<WriteModel> = vec![
+        InsertOneModel::builder()
+            .namespace(coll_a.namespace())
+            .document(doc! { "_id": 1, "x": 1 })
+            .build()
+            .into(),
+        InsertOneModel::builder()
+            .namespace(coll_b.namespace())
+            .document(doc! { "x": 2 })
+            .build()
+            .into(),
+        UpdateOneModel::builder()
+            .namespace(coll_a.namespace())
+            .filter(doc! { "_id": 1 })
+            .update(doc! { "$inc": { "x": 1 } })
+            .build()
+            .into(),
+        DeleteOneModel::builder()
+            .namespace(coll_b.namespace())
+            .filter(doc! { "x": 2 })
+            .build()
+            .into(),
+    ];
+    let options = BulkWriteOptions::builder().verbose_results(true).build();
+    let result = client.bulk_write(models, options).await.unwrap();
+
+    assert_eq!(result.inserted_count, 2);
+    assert_eq!(result.matched_count, 1);
+    assert_eq!(result.modified_count, 1);
+    assert_eq!(result.deleted_count, 1);
+    let insert_results = result.insert_results.unwrap();
+    assert_eq!(insert_results[&0].inserted_id, Bson::Int32(1));
+    assert!(matches!(insert_results[&1].inserted_id, Bson::ObjectId(_)));
+    assert_eq!(result.update_results.unwrap()[&2].modified_count, 1);
+    assert_eq!(result.delete_results.unwrap()[&3].deleted_count, 1);
+
+    let models = vec![
+        InsertOneModel::builder()
+            .namespace(coll_a.namespace())
+            .document(doc! { "_id": 1 })
+            .build(),
+        InsertOneModel::builder()
+            .namespace(coll_a.namespace())
+            .document(doc! { "_id": 2 })
+            .build(),
+    ];
+    let error = client.bulk_write(models, None).await.unwrap_err();
+    match *error.kind {
+        ErrorKind::ClientBulkWrite(failure) => {
+            assert_eq!(failure.write_errors.len(), 1);
+            assert_eq!(failure.write_errors[&0].code, 11000);
+            assert!(failure.partial_result.is_none());
+        }
+        other => panic!("expected bulk write error, got {:?}", other),
+    }
+}
+
+/// Verifies that a database's default timeout applies to database-level operations and to the
+/// cursors they create.
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+async fn database_timeout() {
+    let handler = Arc::new(EventHandler::new());
+    let client = Client::test_builder()
+        .event_handler(handler.clone())
+        .build()
+        .await;
+    if !client.supports_block_connection() {
+        log_uncaptured("skipping database_timeout due to blockConnection not being supported");
+        return;
+    }
+
+    let db = client.database_with_options(
+        "database_timeout",
+        DatabaseOptions::builder()
+            .timeout(Duration::from_millis(100))
+            .build(),
+    );
+    assert_eq!(db.timeout(), Some(Duration::from_millis(100)));
+    db.collection::<Document>("database_timeout")
+        .insert_one(doc! { "x": 1 }, None)
+        .await
+        .unwrap();
+
+    let options = FailCommandOptions::builder()
+        .block_connection(Duration::from_millis(500))
+        .build();
+    let failpoint = FailPoint::fail_command(
+        &["listCollections", "ping"],
+        FailPointMode::Times(2),
+        Some(options),
+    );
+    let _guard = client.enable_failpoint(failpoint, None).await.unwrap();
+
+    let error = db.list_collection_names(None).await.unwrap_err();
+    assert!(
+        error.is_timeout_error(),
+        "expected timeout error, got {:?}",
+        error
+    );
+    let error = db.run_command(doc! { "ping": 1 }, None).await.unwrap_err();
+    assert!(
+        error.is_timeout_error(),
+        "expected timeout error, got {:?}",
+        error
+    );
+
+    let started = handler.get_command_started_events(&["listCollections", "ping"]);
+    for event in started {
+        let max_time_ms = event.command.get_i64("maxTimeMS").unwrap();
+        assert!(max_time_ms > 0 && max_time_ms <= 100);
+    }
+}
+
 /// Verifies that `Client::shutdown` succeeds.
 #[cfg_attr(feature = "tokio-runtime", tokio::test)]
 #[cfg_attr(feature = "async-std-runtime", async_std::test)]
@@ -990,3 +1230,96 @@ async fn warm_connection_pool() {
     // Validate that a command executes.
     client.list_database_names(None, None).await.unwrap();
 }
+
+// Verifies that TLS certificates can be supplied in memory rather than as file paths.
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+async fn tls_in_memory_material() {
+    let mut options = CLIENT_OPTIONS.get().await.clone();
+    let tls_options = match options.tls_options() {
+        Some(tls_options) if tls_options.ca_file_path.is_some() => tls_options,
+        _ => {
+            log_uncaptured("skipping tls_in_memory_material: no CA file configured");
+            return;
+        }
+    };
+
+    let mut in_memory = TlsOptions::builder()
+        .allow_invalid_certificates(tls_options.allow_invalid_certificates)
+        .ca_pem(std::fs::read(tls_options.ca_file_path.unwrap()).unwrap())
+        .build();
+    if let Some(path) = tls_options.cert_key_file_path {
+        in_memory.cert_key_pem = Some(std::fs::read(path).unwrap());
+    }
+    options.tls = Some(Tls::Enabled(in_memory));
+
+    let client = Client::with_options(options.clone()).unwrap();
+    client
+        .database("admin")
+        .run_command(doc! { "ping": 1 }, None)
+        .await
+        .unwrap();
+
+    options.tls = Some(Tls::Enabled(
+        TlsOptions::builder()
+            .cert_key_pem(b"not a certificate".to_vec())
+            .build(),
+    ));
+    let error = Client::with_options(options).unwrap_err();
+    assert!(matches!(*error.kind, ErrorKind::InvalidTlsConfig { .. }));
+}
+
+// Verifies that a certificate provider is consulted for new connections.
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+async fn tls_cert_key_provider() {
+    struct CountingProvider {
+        path: PathBuf,
+        calls: AtomicUsize,
+    }
+
+    impl ClientCertificateProvider for CountingProvider {
+        fn cert_key_pem(&self) -> crate::error::Result<Vec<u8>> {
+            self.calls.fetch_add(1, Ordering::SeqCst);
+            Ok(std::fs::read(&self.path)?)
+        }
+    }
+
+    let mut options = CLIENT_OPTIONS.get().await.clone();
+    let mut tls_options = match options.tls_options() {
+        Some(tls_options) if tls_options.cert_key_file_path.is_some() => tls_options,
+        _ => {
+            log_uncaptured("skipping tls_cert_key_provider: no client certificate configured");
+            return;
+        }
+    };
+
+    let provider = Arc::new(CountingProvider {
+        path: tls_options.cert_key_file_path.take().unwrap(),
+        calls: AtomicUsize::new(0),
+    });
+    tls_options.cert_key_provider = Some(provider.clone());
+    options.tls = Some(Tls::Enabled(tls_options));
+
+    let client = Client::with_options(options).unwrap();
+    let calls_at_creation = provider.calls.load(Ordering::SeqCst);
+    assert!(calls_at_creation >= 1);
+
+    client
+        .database("admin")
+        .run_command(doc! { "ping": 1 }, None)
+        .await
+        .unwrap();
+    assert!(provider.calls.load(Ordering::SeqCst) > calls_at_creation);
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+async fn tls_watch_cert_key_file_requires_path() {
+    let options = ClientOptions::builder()
+        .hosts(vec![ServerAddress::default()])
+        .tls(TlsOptions::builder().watch_cert_key_file(true).build())
+        .build();
+    let error = Client::with_options(options).unwrap_err();
+    assert!(matches!(*error.kind, ErrorKind::InvalidTlsConfig { .. }));
+}
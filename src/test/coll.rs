@@ -12,26 +12,39 @@ use crate::{
     options::{
         Acknowledgment,
         AggregateOptions,
+        CollectionBulkWriteOptions,
         CollectionOptions,
+        CollectionWriteModel,
+        DeleteMany,
+        DeleteOne,
         DeleteOptions,
         DropCollectionOptions,
+        ExplainVerbosity,
         FindOneAndDeleteOptions,
         FindOneOptions,
         FindOptions,
         Hint,
         IndexOptions,
         InsertManyOptions,
+        InsertOne,
         ReadConcern,
         ReadPreference,
+        ReplaceOne,
         SelectionCriteria,
+        UpdateMany,
+        UpdateOne,
         UpdateOptions,
         WriteConcern,
     },
+    pipeline::Pipeline,
     results::DeleteResult,
     runtime,
     test::{
         log_uncaptured,
         util::{drop_collection, EventClient, TestClient},
+        FailCommandOptions,
+        FailPoint,
+        FailPointMode,
         CLIENT_OPTIONS,
     },
     Collection,
@@ -207,6 +220,357 @@ async fn delete() {
     assert_eq!(coll.count_documents(doc! {"x": 3 }, None).await.unwrap(), 0);
 }
 
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[function_name::named]
+async fn bulk_write() {
+    let client = EventClient::new().await;
+    let coll = client
+        .init_db_and_coll(function_name!(), function_name!())
+        .await;
+
+    let models: Vec<CollectionWriteModel<Document>> = vec![
+        InsertOne::builder()
+            .document(doc! { "_id": 1, "x": 1 })
+            .build()
+            .into(),
+        InsertOne::builder()
+            .document(doc! { "_id": 2, "x": 1 })
+            .build()
+            .into(),
+        UpdateMany::builder()
+            .filter(doc! { "x": 1 })
+            .update(doc! { "$inc": { "x": 1 } })
+            .build()
+            .into(),
+        UpdateOne::builder()
+            .filter(doc! { "_id": 3 })
+            .update(doc! { "$set": { "x": 3 } })
+            .upsert(true)
+            .build()
+            .into(),
+        ReplaceOne::builder()
+            .filter(doc! { "_id": 1 })
+            .replacement(doc! { "x": 10 })
+            .build()
+            .into(),
+        DeleteOne::builder()
+            .filter(doc! { "_id": 2 })
+            .build()
+            .into(),
+        InsertOne::builder()
+            .document(doc! { "x": 4 })
+            .build()
+            .into(),
+    ];
+    let result = coll.bulk_write(models, None).await.unwrap();
+    assert_eq!(result.inserted_count, 3);
+    assert_eq!(result.matched_count, 3);
+    assert_eq!(result.modified_count, 3);
+    assert_eq!(result.upserted_count, 1);
+    assert_eq!(result.deleted_count, 1);
+    assert!(result.insert_results.is_none());
+
+    // consecutive updates and replacements are sent together.
+    let command_names: Vec<String> = client
+        .get_command_started_events(&["insert", "update", "delete"])
+        .into_iter()
+        .map(|event| event.command_name)
+        .collect();
+    assert_eq!(command_names, ["insert", "update", "delete", "insert"]);
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[function_name::named]
+async fn bulk_write_verbose_results() {
+    let client = EventClient::new().await;
+    let coll = client
+        .init_db_and_coll(function_name!(), function_name!())
+        .await;
+
+    let models: Vec<CollectionWriteModel<Document>> = vec![
+        InsertOne::builder()
+            .document(doc! { "_id": 1, "x": 1 })
+            .build()
+            .into(),
+        InsertOne::builder()
+            .document(doc! { "x": 2 })
+            .build()
+            .into(),
+        UpdateOne::builder()
+            .filter(doc! { "_id": 3 })
+            .update(doc! { "$set": { "x": 3 } })
+            .upsert(true)
+            .build()
+            .into(),
+        UpdateOne::builder()
+            .filter(doc! { "_id": 1 })
+            .update(doc! { "$inc": { "x": 1 } })
+            .build()
+            .into(),
+        DeleteOne::builder()
+            .filter(doc! { "_id": 1 })
+            .build()
+            .into(),
+    ];
+    let options = CollectionBulkWriteOptions::builder()
+        .verbose_results(true)
+        .build();
+    let result = coll.bulk_write(models, options).await.unwrap();
+    assert_eq!(result.inserted_count, 2);
+    assert_eq!(result.upserted_count, 1);
+    assert_eq!(result.matched_count, 1);
+    assert_eq!(result.deleted_count, 1);
+
+    let insert_results = result.insert_results.unwrap();
+    assert_eq!(insert_results[&0].inserted_id, Bson::Int32(1));
+    assert!(matches!(insert_results[&1].inserted_id, Bson::ObjectId(_)));
+    let update_results = result.update_results.unwrap();
+    assert_eq!(update_results[&2].upserted_id, Some(Bson::Int32(3)));
+    assert_eq!(update_results[&3].matched_count, 1);
+    assert_eq!(update_results[&3].modified_count, 1);
+    assert_eq!(result.delete_results.unwrap()[&4].deleted_count, 1);
+
+    // inserts are still sent together, but each update is sent on its own.
+    let command_names: Vec<String> = client
+        .get_command_started_events(&["insert", "update", "delete"])
+        .into_iter()
+        .map(|event| event.command_name)
+        .collect();
+    assert_eq!(command_names, ["insert", "update", "update", "delete"]);
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[function_name::named]
+async fn explain() {
+    let client = EventClient::new().await;
+    let coll = client
+        .init_db_and_coll(function_name!(), function_name!())
+        .await;
+    coll.insert_many((0..5).map(|i| doc! { "x": i }), None)
+        .await
+        .unwrap();
+
+    let find_options = FindOptions::builder()
+        .hint(Hint::Name("_id_".to_string()))
+        .build();
+    let explained = coll
+        .explain_find(
+            doc! { "x": 1 },
+            find_options,
+            ExplainVerbosity::ExecutionStats,
+        )
+        .await
+        .unwrap();
+    assert!(explained.contains_key("queryPlanner"));
+    assert!(explained.contains_key("executionStats"));
+
+    let explained = coll
+        .explain_delete_many(doc! {}, None, ExplainVerbosity::QueryPlanner)
+        .await
+        .unwrap();
+    assert!(explained.contains_key("queryPlanner"));
+    assert_eq!(coll.count_documents(None, None).await.unwrap(), 5);
+
+    let events = client.get_command_started_events(&["explain"]);
+    assert_eq!(events.len(), 2);
+    let explained_find = events[0].command.get_document("explain").unwrap();
+    assert_eq!(explained_find.get_str("hint"), Ok("_id_"));
+    assert_eq!(events[0].command.get_str("verbosity"), Ok("executionStats"));
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[function_name::named]
+async fn bulk_write_errors() {
+    let client = TestClient::new().await;
+    let coll = client
+        .init_db_and_coll(function_name!(), function_name!())
+        .await;
+
+    let models = || -> Vec<CollectionWriteModel<Document>> {
+        vec![
+            DeleteMany::builder().filter(doc! {}).build().into(),
+            InsertOne::builder()
+                .document(doc! { "_id": 1 })
+                .build()
+                .into(),
+            InsertOne::builder()
+                .document(doc! { "_id": 1 })
+                .build()
+                .into(),
+            InsertOne::builder()
+                .document(doc! { "_id": 2 })
+                .build()
+                .into(),
+        ]
+    };
+
+    let options = CollectionBulkWriteOptions::builder()
+        .verbose_results(true)
+        .build();
+    let error = coll.bulk_write(models(), options).await.unwrap_err();
+    match *error.kind {
+        ErrorKind::BulkWrite(ref failure) => {
+            let write_errors = failure.write_errors.as_ref().unwrap();
+            assert_eq!(write_errors.len(), 1);
+            assert_eq!(write_errors[0].index, 2);
+
+            let partial_result = failure.partial_result.as_ref().unwrap();
+            assert_eq!(partial_result.inserted_count, 1);
+            let insert_results = partial_result.insert_results.as_ref().unwrap();
+            assert_eq!(insert_results[&1].inserted_id, Bson::Int32(1));
+            assert_eq!(partial_result.deleted_count, 0);
+        }
+        ref e => panic!("expected bulk write error, got {:?}", e),
+    }
+    assert_eq!(coll.count_documents(None, None).await.unwrap(), 1);
+
+    let options = CollectionBulkWriteOptions::builder()
+        .ordered(false)
+        .verbose_results(true)
+        .build();
+    let error = coll.bulk_write(models(), options).await.unwrap_err();
+    match *error.kind {
+        ErrorKind::BulkWrite(ref failure) => {
+            let write_errors = failure.write_errors.as_ref().unwrap();
+            assert_eq!(write_errors.len(), 1);
+            assert_eq!(write_errors[0].index, 2);
+
+            let partial_result = failure.partial_result.as_ref().unwrap();
+            assert_eq!(partial_result.inserted_count, 2);
+            let insert_results = partial_result.insert_results.as_ref().unwrap();
+            assert_eq!(insert_results[&1].inserted_id, Bson::Int32(1));
+            assert_eq!(insert_results[&3].inserted_id, Bson::Int32(2));
+            assert_eq!(partial_result.deleted_count, 1);
+            assert_eq!(
+                partial_result.delete_results.as_ref().unwrap()[&0].deleted_count,
+                1
+            );
+        }
+        ref e => panic!("expected bulk write error, got {:?}", e),
+    }
+    assert_eq!(coll.count_documents(None, None).await.unwrap(), 2);
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[function_name::named]
+async fn bulk_write_update_error_partial_result() {
+    let client = TestClient::new().await;
+    let coll = client
+        .init_db_and_coll(function_name!(), function_name!())
+        .await;
+
+    let index_model = IndexModel::builder()
+        .keys(doc! { "x": 1 })
+        .options(IndexOptions::builder().unique(true).build())
+        .build();
+    coll.create_index(index_model, None).await.unwrap();
+
+    let models: Vec<CollectionWriteModel<Document>> = vec![
+        InsertOne::builder()
+            .document(doc! { "_id": 1, "x": 1 })
+            .build()
+            .into(),
+        UpdateOne::builder()
+            .filter(doc! { "_id": 2 })
+            .update(doc! { "$set": { "x": 2 } })
+            .upsert(true)
+            .build()
+            .into(),
+        UpdateOne::builder()
+            .filter(doc! { "_id": 1 })
+            .update(doc! { "$set": { "y": 1 } })
+            .build()
+            .into(),
+        // the upserted document conflicts with the one upserted above.
+        UpdateOne::builder()
+            .filter(doc! { "_id": 3 })
+            .update(doc! { "$set": { "x": 2 } })
+            .upsert(true)
+            .build()
+            .into(),
+        DeleteOne::builder()
+            .filter(doc! { "_id": 1 })
+            .build()
+            .into(),
+    ];
+
+    let error = coll.bulk_write(models, None).await.unwrap_err();
+    match *error.kind {
+        ErrorKind::BulkWrite(ref failure) => {
+            let write_errors = failure.write_errors.as_ref().unwrap();
+            assert_eq!(write_errors.len(), 1);
+            assert_eq!(write_errors[0].index, 3);
+            assert_eq!(write_errors[0].code, 11000);
+
+            let partial_result = failure.partial_result.as_ref().unwrap();
+            assert_eq!(partial_result.inserted_count, 1);
+            assert_eq!(partial_result.upserted_count, 1);
+            assert_eq!(partial_result.matched_count, 1);
+            assert_eq!(partial_result.modified_count, 1);
+            assert_eq!(partial_result.deleted_count, 0);
+        }
+        ref e => panic!("expected bulk write error, got {:?}", e),
+    }
+    assert_eq!(coll.count_documents(None, None).await.unwrap(), 2);
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test(flavor = "multi_thread"))] // multi_thread required for FailPoint
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[function_name::named]
+async fn bulk_write_command_error_partial_result() {
+    let client = TestClient::new().await;
+    if !client.supports_fail_command() {
+        log_uncaptured(format!(
+            "skipping {} due to failCommand not being supported",
+            function_name!()
+        ));
+        return;
+    }
+    let coll = client
+        .init_db_and_coll(function_name!(), function_name!())
+        .await;
+
+    let failpoint = FailPoint::fail_command(
+        &["delete"],
+        FailPointMode::Times(1),
+        FailCommandOptions::builder().error_code(8).build(),
+    );
+    let _guard = client.enable_failpoint(failpoint, None).await.unwrap();
+
+    let models: Vec<CollectionWriteModel<Document>> = vec![
+        InsertOne::builder()
+            .document(doc! { "_id": 1 })
+            .build()
+            .into(),
+        DeleteOne::builder()
+            .filter(doc! { "_id": 1 })
+            .build()
+            .into(),
+    ];
+
+    let error = coll.bulk_write(models, None).await.unwrap_err();
+    match *error.kind {
+        ErrorKind::BulkWrite(ref failure) => {
+            assert!(failure.write_errors.is_none());
+            let partial_result = failure.partial_result.as_ref().unwrap();
+            assert_eq!(partial_result.inserted_count, 1);
+            assert_eq!(partial_result.deleted_count, 0);
+        }
+        ref e => panic!("expected bulk write error, got {:?}", e),
+    }
+    let source = error.source.as_ref().unwrap();
+    assert!(
+        matches!(*source.kind, ErrorKind::Command(ref e) if e.code == 8),
+        "expected command error, got {:?}",
+        source
+    );
+}
+
 #[cfg_attr(feature = "tokio-runtime", tokio::test)]
 #[cfg_attr(feature = "async-std-runtime", async_std::test)]
 #[function_name::named]
@@ -255,6 +619,40 @@ async fn aggregate_out() {
         .any(|name| name.as_str() == out_coll.name()));
 }
 
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[function_name::named]
+async fn aggregate_write_concern_only_for_write_stages() {
+    let client = EventClient::new().await;
+    let db = client.database(function_name!());
+    let options = CollectionOptions::builder()
+        .write_concern(WriteConcern::builder().w(Acknowledgment::Majority).build())
+        .build();
+    let coll = db.collection_with_options::<Document>(function_name!(), options);
+    let out_coll = db.collection::<Document>(&format!("{}_1", function_name!()));
+
+    drop_collection(&coll).await;
+    drop_collection(&out_coll).await;
+    coll.insert_many((0i32..5).map(|n| doc! { "x": n }), None)
+        .await
+        .unwrap();
+
+    let pipeline = Pipeline::new().match_(doc! { "x": { "$gt": 1 } });
+    coll.aggregate(pipeline.clone(), None).await.unwrap();
+    let events = client.get_command_started_events(&["aggregate"]);
+    assert!(!events.last().unwrap().command.contains_key("writeConcern"));
+
+    coll.aggregate(pipeline.out(out_coll.name()), None)
+        .await
+        .unwrap();
+    let events = client.get_command_started_events(&["aggregate"]);
+    assert_eq!(
+        events.last().unwrap().command.get_document("writeConcern"),
+        Ok(&doc! { "w": "majority" })
+    );
+    assert_eq!(out_coll.count_documents(None, None).await.unwrap(), 3);
+}
+
 fn kill_cursors_sent(client: &EventClient) -> bool {
     !client
         .get_command_started_events(&["killCursors"])
@@ -4,10 +4,19 @@ use futures::{future::Either, StreamExt, TryStreamExt};
 use serde::{Deserialize, Serialize};
 
 use crate::{
-    bson::doc,
+    bson::{doc, Document},
+    error::ErrorKind,
     options::{CreateCollectionOptions, CursorType, FindOptions},
     runtime,
-    test::{log_uncaptured, util::EventClient, TestClient, SERVERLESS},
+    test::{
+        log_uncaptured,
+        util::EventClient,
+        FailCommandOptions,
+        FailPoint,
+        FailPointMode,
+        TestClient,
+        SERVERLESS,
+    },
 };
 
 #[cfg_attr(feature = "tokio-runtime", tokio::test)]
@@ -288,3 +297,170 @@ async fn cursor_final_batch() {
     }
     assert_eq!(found, 5);
 }
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test(flavor = "multi_thread"))] // multi_thread required for FailPoint
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+async fn resumable_cursor_resumes_on_error() {
+    let client = EventClient::new().await;
+    if !client.is_replica_set() || !client.supports_fail_command() {
+        log_uncaptured(
+            "skipping resumable_cursor_resumes_on_error: requires a replica set with failCommand",
+        );
+        return;
+    }
+
+    let coll = client
+        .create_fresh_collection("resumable_cursor_db", "resumes_on_error", None)
+        .await;
+    coll.insert_many((0..10).map(|i| doc! { "_id": i, "x": i % 3 }), None)
+        .await
+        .unwrap();
+
+    let mut cursor = coll
+        .find_resumable(
+            doc! { "x": { "$lt": 2 } },
+            FindOptions::builder()
+                .sort(doc! { "x": -1 })
+                .batch_size(2)
+                .build(),
+        )
+        .await
+        .unwrap();
+
+    // Consume the initial batch and one getMore.
+    let mut ids = Vec::new();
+    for _ in 0..4 {
+        let doc = cursor.try_next().await.unwrap().unwrap();
+        ids.push(doc.get_i32("_id").unwrap());
+    }
+    assert_eq!(ids, vec![1, 4, 7, 0]);
+    assert_eq!(cursor.resume_key(), Some(doc! { "x": 0, "_id": 0 }));
+
+    let _guard = FailPoint::fail_command(
+        &["getMore"],
+        FailPointMode::Times(1),
+        FailCommandOptions::builder().error_code(43).build(),
+    )
+    .enable(&client, None)
+    .await
+    .unwrap();
+
+    while let Some(doc) = cursor.try_next().await.unwrap() {
+        ids.push(doc.get_i32("_id").unwrap());
+    }
+    assert_eq!(ids, vec![1, 4, 7, 0, 3, 6, 9]);
+
+    let finds = client.get_command_started_events(&["find"]);
+    assert_eq!(finds.len(), 2);
+    assert_eq!(
+        finds[0].command.get_document("sort").unwrap(),
+        &doc! { "x": -1, "_id": 1 }
+    );
+    assert_eq!(
+        finds[1].command.get_document("filter").unwrap(),
+        &doc! {
+            "$and": [
+                { "x": { "$lt": 2 } },
+                { "$expr": { "$or": [
+                    { "$lt": [{ "$ifNull": ["$x", null] }, { "$literal": 0 }] },
+                    { "$and": [
+                        { "$eq": [{ "$ifNull": ["$x", null] }, { "$literal": 0 }] },
+                        { "$gt": [{ "$ifNull": ["$_id", null] }, { "$literal": 0 }] },
+                    ] },
+                ] } },
+            ]
+        }
+    );
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test(flavor = "multi_thread"))] // multi_thread required for FailPoint
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+async fn resumable_cursor_resumes_across_types() {
+    let client = EventClient::new().await;
+    if !client.is_replica_set() || !client.supports_fail_command() {
+        log_uncaptured(
+            "skipping resumable_cursor_resumes_across_types: requires a replica set with \
+             failCommand",
+        );
+        return;
+    }
+
+    let coll = client
+        .create_fresh_collection("resumable_cursor_db", "resumes_across_types", None)
+        .await;
+    coll.insert_many(
+        vec![
+            doc! { "_id": 0, "x": null },
+            doc! { "_id": 1, "x": 1 },
+            doc! { "_id": 2, "x": 2.5 },
+            doc! { "_id": 3, "x": "a" },
+            doc! { "_id": 4, "x": "$b" },
+            doc! { "_id": 5, "x": { "y": 1 } },
+            doc! { "_id": 6 },
+        ],
+        None,
+    )
+    .await
+    .unwrap();
+
+    let mut cursor = coll
+        .find_resumable(
+            None,
+            FindOptions::builder()
+                .sort(doc! { "x": 1 })
+                .batch_size(2)
+                .build(),
+        )
+        .await
+        .unwrap();
+
+    // Both getMores fail: the first after a null key, the second after a numeric key that is
+    // followed by strings.
+    let _guard = FailPoint::fail_command(
+        &["getMore"],
+        FailPointMode::Times(2),
+        FailCommandOptions::builder().error_code(43).build(),
+    )
+    .enable(&client, None)
+    .await
+    .unwrap();
+
+    let mut ids = Vec::new();
+    while let Some(doc) = cursor.try_next().await.unwrap() {
+        ids.push(doc.get_i32("_id").unwrap());
+    }
+    // The missing value sorts with null, and strings sort after numbers.
+    assert_eq!(ids, vec![0, 6, 1, 2, 4, 3, 5]);
+    assert_eq!(client.get_command_started_events(&["find"]).len(), 3);
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+async fn resumable_cursor_invalid_options() {
+    let client = TestClient::new().await;
+    let coll = client
+        .database("resumable_cursor_db")
+        .collection::<Document>("invalid_options");
+
+    let err = coll
+        .find_resumable(
+            None,
+            FindOptions::builder()
+                .sort(doc! { "score": { "$meta": "textScore" } })
+                .build(),
+        )
+        .await
+        .unwrap_err();
+    assert!(matches!(*err.kind, ErrorKind::InvalidArgument { .. }));
+
+    let err = coll
+        .find_resumable(
+            None,
+            FindOptions::builder()
+                .cursor_type(CursorType::Tailable)
+                .build(),
+        )
+        .await
+        .unwrap_err();
+    assert!(matches!(*err.kind, ErrorKind::InvalidArgument { .. }));
+}
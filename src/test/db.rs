@@ -1,4 +1,4 @@
-use std::cmp::Ord;
+use std::{cmp::Ord, time::Duration};
 
 use futures::stream::TryStreamExt;
 
@@ -7,15 +7,29 @@ use crate::{
     error::Result,
     options::{
         AggregateOptions,
+        CollModIndex,
+        CollModOptions,
         Collation,
         CreateCollectionOptions,
+        CreateRoleOptions,
+        CreateUserOptions,
+        DbStatsOptions,
         IndexOptionDefaults,
+        IndexOptions,
+        LatencyStatsOptions,
+        Privilege,
+        Role,
+        RolesInfoOptions,
+        UpdateUserOptions,
+        UsersInfoOptions,
         ValidationAction,
         ValidationLevel,
     },
     results::{CollectionSpecification, CollectionType},
     test::util::{EventClient, TestClient},
     Database,
+    IndexModel,
+    Namespace,
 };
 
 use super::log_uncaptured;
@@ -226,6 +240,219 @@ async fn collection_management() {
     assert!(coll3.id_index.is_none());
 }
 
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[function_name::named]
+async fn collection_administration() {
+    let client = TestClient::new().await;
+    if client.server_version_lt(4, 4) {
+        log_uncaptured("skipping collection_administration: requires server 4.4+");
+        return;
+    }
+    let db = client.database(function_name!());
+    db.drop(None).await.unwrap();
+
+    let coll = db.collection::<Document>("source");
+    coll.insert_one(doc! { "x": 1 }, None).await.unwrap();
+    coll.create_index(
+        IndexModel::builder()
+            .keys(doc! { "x": 1 })
+            .options(
+                IndexOptions::builder()
+                    .expire_after(Duration::from_secs(60))
+                    .build(),
+            )
+            .build(),
+        None,
+    )
+    .await
+    .unwrap();
+
+    coll.modify(
+        CollModOptions::builder()
+            .validator(doc! { "x": { "$gt": 0 } })
+            .validation_level(ValidationLevel::Moderate)
+            .index(
+                CollModIndex::builder()
+                    .name("x_1".to_string())
+                    .hidden(true)
+                    .expire_after_seconds(Duration::from_secs(120))
+                    .build(),
+            )
+            .build(),
+    )
+    .await
+    .unwrap();
+
+    let info = get_coll_info(&db, Some(doc! { "name": "source" })).await;
+    assert_eq!(info[0].options.validator, Some(doc! { "x": { "$gt": 0 } }));
+    assert_eq!(
+        info[0].options.validation_level,
+        Some(ValidationLevel::Moderate)
+    );
+    let index = coll
+        .list_indexes(None)
+        .await
+        .unwrap()
+        .try_collect::<Vec<_>>()
+        .await
+        .unwrap()
+        .into_iter()
+        .find(|index| index.keys == doc! { "x": 1 })
+        .unwrap();
+    let options = index.options.unwrap();
+    assert_eq!(options.hidden, Some(true));
+    assert_eq!(options.expire_after, Some(Duration::from_secs(120)));
+
+    db.create_view("view", "source", vec![doc! { "$project": { "_id": 0 } }])
+        .await
+        .unwrap();
+    let view = db.collection::<Document>("view");
+    assert_eq!(
+        view.find_one(None, None).await.unwrap(),
+        Some(doc! { "x": 1 })
+    );
+
+    coll.rename(Namespace::new(function_name!(), "renamed"), false)
+        .await
+        .unwrap();
+    let names = db
+        .list_collection_names(doc! { "type": "collection" })
+        .await
+        .unwrap();
+    assert_eq!(names, vec!["renamed".to_string()]);
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[function_name::named]
+async fn user_and_role_management() {
+    let client = EventClient::new().await;
+    let db = client.database(function_name!());
+    let _ = db.drop_user("tenant", None).await;
+    let _ = db.drop_role("auditor", None).await;
+
+    db.create_role(
+        "auditor",
+        CreateRoleOptions::builder()
+            .privileges(vec![Privilege::builder()
+                .resource(doc! { "db": function_name!(), "collection": "" })
+                .actions(vec!["find".to_string()])
+                .build()])
+            .build(),
+    )
+    .await
+    .unwrap();
+    db.create_user(
+        "tenant",
+        CreateUserOptions::builder()
+            .password("hunter2".to_string())
+            .custom_data(doc! { "tier": "free" })
+            .build(),
+    )
+    .await
+    .unwrap();
+    db.grant_roles_to_user("tenant", vec![Role::new("auditor", function_name!())], None)
+        .await
+        .unwrap();
+    db.update_user(
+        "tenant",
+        UpdateUserOptions::builder()
+            .password("correct horse".to_string())
+            .build(),
+    )
+    .await
+    .unwrap();
+
+    let users = db
+        .users_info(
+            vec!["tenant".to_string()],
+            UsersInfoOptions::builder().show_custom_data(true).build(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(users.len(), 1);
+    assert_eq!(users[0].user, "tenant");
+    assert_eq!(users[0].custom_data, Some(doc! { "tier": "free" }));
+    assert_eq!(users[0].roles, vec![Role::new("auditor", function_name!())]);
+
+    let roles = db
+        .roles_info(
+            None,
+            RolesInfoOptions::builder().show_privileges(true).build(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(roles.len(), 1);
+    assert_eq!(roles[0].role, "auditor");
+    assert!(!roles[0].is_builtin);
+    assert_eq!(
+        roles[0].privileges.as_ref().unwrap()[0].actions,
+        vec!["find".to_string()]
+    );
+
+    let events = client.get_command_started_events(&["createUser", "updateUser"]);
+    assert_eq!(events.len(), 2);
+    for event in events {
+        assert!(event.command.is_empty(), "{:?}", event);
+    }
+
+    db.drop_user("tenant", None).await.unwrap();
+    db.drop_role("auditor", None).await.unwrap();
+    assert!(db.users_info(None, None).await.unwrap().is_empty());
+    assert!(db.roles_info(None, None).await.unwrap().is_empty());
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[function_name::named]
+async fn stats() {
+    let client = TestClient::new().await;
+    let db = client.database(function_name!());
+    db.drop(None).await.unwrap();
+
+    let coll = db.collection::<Document>("coll");
+    coll.insert_many(vec![doc! { "x": 1 }, doc! { "x": 2 }], None)
+        .await
+        .unwrap();
+    coll.find_one(None, None).await.unwrap();
+
+    let db_stats = db
+        .stats(DbStatsOptions::builder().scale(1024).build())
+        .await
+        .unwrap();
+    assert_eq!(db_stats.db, function_name!());
+    assert_eq!(db_stats.collections, 1);
+    assert_eq!(db_stats.objects, 2);
+
+    let storage_stats = coll.storage_stats(None).await.unwrap();
+    assert!(!storage_stats.is_empty());
+    assert_eq!(
+        storage_stats
+            .iter()
+            .map(|stats| stats.storage_stats.count)
+            .sum::<u64>(),
+        2
+    );
+    assert!(storage_stats[0]
+        .storage_stats
+        .index_sizes
+        .contains_key("_id_"));
+
+    let latency_stats = coll
+        .latency_stats(LatencyStatsOptions::builder().histograms(true).build())
+        .await
+        .unwrap();
+    assert!(!latency_stats.is_empty());
+    assert!(latency_stats
+        .iter()
+        .any(|stats| stats.latency_stats.writes.ops > 0));
+
+    let status = client.server_status(None).await.unwrap();
+    assert!(!status.version.is_empty());
+    assert!(status.extra.contains_key("opcounters"));
+}
+
 #[cfg_attr(feature = "tokio-runtime", tokio::test)]
 #[cfg_attr(feature = "async-std-runtime", async_std::test)]
 async fn db_aggregate() {
@@ -0,0 +1,254 @@
+use std::{sync::Arc, time::Duration};
+
+use futures::StreamExt;
+
+use crate::{
+    bson::{doc, oid::ObjectId, Document},
+    error::ErrorKind,
+    event::sdam::ServerHeartbeatStartedEvent,
+    mock_server::{MockReply, MockResponse, MockServer, MockServerOptions, MockTopology},
+    options::ClientOptions,
+    runtime,
+    test::util::{Event, EventHandler, SdamEvent},
+    Client,
+    ServerType,
+    TopologyType,
+};
+
+fn find_reply(documents: Vec<Document>) -> MockReply {
+    MockReply::Document(doc! {
+        "cursor": { "id": 0_i64, "ns": "db.coll", "firstBatch": documents },
+    })
+}
+
+#[tokio::test]
+async fn scripted_responses() {
+    let server = MockServer::start(None).await.unwrap();
+    server.add_response(
+        MockResponse::builder()
+            .command_name("find")
+            .reply(find_reply(vec![doc! { "_id": 1 }, doc! { "_id": 2 }]))
+            .build(),
+    );
+    server.add_response(
+        MockResponse::builder()
+            .command_name("insert")
+            .reply(MockReply::Document(doc! { "n": 2 }))
+            .build(),
+    );
+
+    let client = Client::with_uri_str(server.uri()).await.unwrap();
+    let coll = client.database("db").collection::<Document>("coll");
+    coll.insert_many(vec![doc! { "_id": 1 }, doc! { "_id": 2 }], None)
+        .await
+        .unwrap();
+    let mut cursor = coll.find(doc! { "x": 1 }, None).await.unwrap();
+    let mut found = Vec::new();
+    while cursor.advance().await.unwrap() {
+        found.push(cursor.deserialize_current().unwrap());
+    }
+    assert_eq!(found, vec![doc! { "_id": 1 }, doc! { "_id": 2 }]);
+
+    let commands = server.received_commands();
+    let names: Vec<&str> = commands
+        .iter()
+        .map(|command| command.keys().next().unwrap().as_str())
+        .collect();
+    assert_eq!(names, vec!["insert", "find"]);
+    // Document sequences are folded into the command.
+    assert_eq!(
+        commands[0].get_array("documents").unwrap().len(),
+        2,
+        "{:?}",
+        commands[0]
+    );
+    assert_eq!(commands[1].get_document("filter"), Ok(&doc! { "x": 1 }));
+}
+
+#[tokio::test]
+async fn unscripted_command_fails() {
+    let server = MockServer::start(None).await.unwrap();
+    let client = Client::with_uri_str(server.uri()).await.unwrap();
+
+    client
+        .database("admin")
+        .run_command(doc! { "ping": 1 }, None)
+        .await
+        .unwrap();
+    let error = client
+        .database("db")
+        .run_command(doc! { "unknownCommand": 1 }, None)
+        .await
+        .unwrap_err();
+    assert_eq!(error.sdam_code(), Some(59));
+}
+
+#[tokio::test]
+async fn failures_are_retried() {
+    let server = MockServer::start(None).await.unwrap();
+    let client = Client::with_uri_str(server.uri()).await.unwrap();
+    let coll = client.database("db").collection::<Document>("coll");
+
+    // A retryable command error is retried.
+    for reply in [
+        MockReply::error(11600, "interrupted at shutdown"),
+        MockReply::CloseConnection,
+    ] {
+        server.clear_responses();
+        server.add_response(
+            MockResponse::builder()
+                .command_name("find")
+                .reply(reply)
+                .times(1)
+                .build(),
+        );
+        server.add_response(
+            MockResponse::builder()
+                .command_name("find")
+                .reply(find_reply(vec![doc! { "_id": 1 }]))
+                .build(),
+        );
+        assert_eq!(
+            coll.find_one(None, None).await.unwrap(),
+            Some(doc! { "_id": 1 })
+        );
+    }
+    assert_eq!(server.received_commands().len(), 4);
+
+    server.clear_responses();
+    server.add_response(
+        MockResponse::builder()
+            .command_name("insert")
+            .reply(MockReply::error(11000, "duplicate key"))
+            .build(),
+    );
+    let error = coll.insert_one(doc! { "_id": 1 }, None).await.unwrap_err();
+    assert!(
+        matches!(*error.kind, ErrorKind::Command(ref e) if e.code == 11000),
+        "{:?}",
+        error
+    );
+}
+
+#[tokio::test]
+async fn replica_set_topology() {
+    let options = MockServerOptions::builder()
+        .topology(MockTopology::ReplicaSetPrimary {
+            set_name: "rs".to_string(),
+        })
+        .build();
+    let server = MockServer::start(options).await.unwrap();
+    let client = Client::with_uri_str(format!("{}?replicaSet=rs", server.uri()))
+        .await
+        .unwrap();
+
+    client
+        .database("admin")
+        .run_command(doc! { "ping": 1 }, None)
+        .await
+        .unwrap();
+    let commands = server.received_commands();
+    assert_eq!(commands.len(), 1);
+    assert_eq!(commands[0].get_str("$db"), Ok("admin"));
+}
+
+#[tokio::test]
+async fn topology_inspection() {
+    let server = MockServer::start(
+        MockServerOptions::builder()
+            .topology(MockTopology::ReplicaSetPrimary {
+                set_name: "rs".to_string(),
+            })
+            .build(),
+    )
+    .await
+    .unwrap();
+    let client = Client::with_uri_str(format!(
+        "{}?replicaSet=rs&heartbeatFrequencyMS=500",
+        server.uri()
+    ))
+    .await
+    .unwrap();
+
+    let mut description = client.topology_description();
+    while !description.has_writable_server() {
+        description = client
+            .wait_for_topology_change(Duration::from_secs(10))
+            .await
+            .expect("topology should be discovered");
+    }
+    assert_eq!(
+        description.topology_type(),
+        TopologyType::ReplicaSetWithPrimary
+    );
+    assert_eq!(description.set_name(), Some(&"rs".to_string()));
+    let servers = description.servers();
+    let info = servers.get(server.address()).unwrap();
+    assert_eq!(info.server_type(), ServerType::RsPrimary);
+    assert!(info.average_round_trip_time().is_some());
+    assert!(info.error().is_none());
+
+    // Once the server goes away, the next heartbeat fails and the server is marked unknown.
+    let address = server.address().clone();
+    let mut updates = client.topology_updates();
+    drop(server);
+    let description = runtime::timeout(Duration::from_secs(10), updates.next())
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(
+        description.topology_type(),
+        TopologyType::ReplicaSetNoPrimary
+    );
+    let servers = description.servers();
+    let info = servers.get(&address).unwrap();
+    assert_eq!(info.server_type(), ServerType::Unknown);
+    assert!(info.error().is_some());
+}
+
+#[tokio::test]
+async fn server_monitoring_mode() {
+    async fn heartbeats(mode: &str) -> Vec<ServerHeartbeatStartedEvent> {
+        let server = MockServer::start(
+            MockServerOptions::builder()
+                .hello_fields(doc! {
+                    "topologyVersion": { "processId": ObjectId::new(), "counter": 0_i64 },
+                })
+                .build(),
+        )
+        .await
+        .unwrap();
+        let handler = Arc::new(EventHandler::new());
+        let mut subscriber = handler.subscribe();
+        let mut options = ClientOptions::parse(format!(
+            "{}?heartbeatFrequencyMS=500&serverMonitoringMode={}",
+            server.uri(),
+            mode
+        ))
+        .await
+        .unwrap();
+        options.sdam_event_handler = Some(handler.clone());
+        let _client = Client::with_options(options).unwrap();
+
+        subscriber
+            .collect_events(Duration::from_millis(1500), |event| {
+                matches!(event, Event::Sdam(SdamEvent::ServerHeartbeatStarted(_)))
+            })
+            .await
+            .into_iter()
+            .filter_map(|event| match event {
+                Event::Sdam(SdamEvent::ServerHeartbeatStarted(event)) => Some(event),
+                _ => None,
+            })
+            .collect()
+    }
+
+    // The server reports a topology version, so the streaming protocol is used once the initial
+    // check completes unless polling was requested.
+    let events = heartbeats("stream").await;
+    assert!(events.iter().any(|event| event.awaited));
+
+    let events = heartbeats("poll").await;
+    assert!(events.len() >= 2, "{:?}", events);
+    assert!(events.iter().all(|event| !event.awaited));
+}
@@ -33,6 +33,7 @@ impl From<TestCredential> for Credential {
                 .and_then(|s| AuthMechanism::from_str(s.as_str()).ok()),
             mechanism_properties: test_credential.mechanism_properties,
             oidc_callbacks: None,
+            oidc_cache: Default::default(),
         }
     }
 }
@@ -52,13 +53,11 @@ async fn run_auth_test(test_file: TestFile) {
         test_case.description = test_case.description.replace('$', "%");
 
         let skipped_mechanisms = [
-            "GSSAPI",
             "MONGODB-CR",
             #[cfg(not(feature = "aws-auth"))]
             "MONGODB-AWS",
         ];
 
-        // TODO: GSSAPI (RUST-196)
         if skipped_mechanisms
             .iter()
             .any(|mech| test_case.description.contains(mech))
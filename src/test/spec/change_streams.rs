@@ -5,8 +5,6 @@ use crate::test::spec::unified_runner::run_unified_tests;
 async fn run_unified() {
     run_unified_tests(&["change-streams", "unified"])
         .skip_files(&[
-            // TODO RUST-1281: unskip this file
-            "change-streams-showExpandedEvents.json",
             // TODO RUST-1423: unskip this file
             "change-streams-disambiguatedPaths.json",
         ])
@@ -25,13 +25,6 @@ async fn run_unified() {
             // Unacknowledged write; see above.
             "Unacknowledged write using dollar-prefixed or dotted keys may be silently rejected \
              on pre-5.0 server",
-            // TODO RUST-663: Unskip these tests.
-            "Aggregate with $out includes read preference for 5.0+ server",
-            "Aggregate with $out omits read preference for pre-5.0 server",
-            "Aggregate with $merge includes read preference for 5.0+ server",
-            "Aggregate with $merge omits read preference for pre-5.0 server",
-            "Database-level aggregate with $out omits read preference for pre-5.0 server",
-            "Database-level aggregate with $merge omits read preference for pre-5.0 server",
         ])
         .await;
 }
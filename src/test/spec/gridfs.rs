@@ -74,6 +74,135 @@ async fn download_stream_across_buffers() {
     assert_eq!(buf, data);
 }
 
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+async fn download_stream_seek() {
+    use std::io::SeekFrom;
+
+    use futures_util::io::AsyncSeekExt;
+
+    let client = TestClient::new().await;
+
+    let options = GridFsBucketOptions::builder().chunk_size_bytes(3).build();
+    let bucket = client
+        .database("download_stream_seek")
+        .gridfs_bucket(options);
+    bucket.drop().await.unwrap();
+
+    let data: Vec<u8> = (0..20).collect();
+    let id = bucket
+        .upload_from_futures_0_3_reader("test", &data[..], None)
+        .await
+        .unwrap();
+
+    let mut download_stream = bucket.open_download_stream(id.into()).await.unwrap();
+    let mut buf = vec![0u8; 4];
+
+    // seek into the middle of a chunk
+    assert_eq!(download_stream.seek(SeekFrom::Start(7)).await.unwrap(), 7);
+    download_stream.read_exact(&mut buf).await.unwrap();
+    assert_eq!(buf, &data[7..11]);
+
+    // seek backwards relative to the current position
+    assert_eq!(
+        download_stream.seek(SeekFrom::Current(-10)).await.unwrap(),
+        1
+    );
+    download_stream.read_exact(&mut buf).await.unwrap();
+    assert_eq!(buf, &data[1..5]);
+
+    // seek relative to the end of the file and read to the end
+    assert_eq!(download_stream.seek(SeekFrom::End(-5)).await.unwrap(), 15);
+    let mut rest = Vec::new();
+    download_stream.read_to_end(&mut rest).await.unwrap();
+    assert_eq!(rest, &data[15..]);
+
+    // seeking past the end of the file is allowed but yields no bytes
+    assert_eq!(download_stream.seek(SeekFrom::Start(25)).await.unwrap(), 25);
+    assert_eq!(download_stream.read(&mut buf).await.unwrap(), 0);
+
+    // seeking before the start of the file is an error
+    assert!(download_stream.seek(SeekFrom::Current(-26)).await.is_err());
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+async fn download_range() {
+    let client = TestClient::new().await;
+
+    let options = GridFsBucketOptions::builder().chunk_size_bytes(3).build();
+    let bucket = client.database("download_range").gridfs_bucket(options);
+    bucket.drop().await.unwrap();
+
+    let data: Vec<u8> = (0..20).collect();
+    let id: Bson = bucket
+        .upload_from_futures_0_3_reader("test", &data[..], None)
+        .await
+        .unwrap()
+        .into();
+
+    for range in [0..20, 0..1, 2..3, 4..11, 6..9, 18..20, 5..5] {
+        let bytes = bucket
+            .download_range(id.clone(), range.clone())
+            .await
+            .unwrap();
+        assert_eq!(bytes, &data[range.start as usize..range.end as usize]);
+    }
+
+    // the end of the range is capped at the end of the file
+    let bytes = bucket.download_range(id.clone(), 17..100).await.unwrap();
+    assert_eq!(bytes, &data[17..]);
+    let bytes = bucket.download_range(id.clone(), 50..100).await.unwrap();
+    assert!(bytes.is_empty());
+
+    #[allow(clippy::reversed_empty_ranges)]
+    let result = bucket.download_range(id, 10..5).await;
+    assert!(result.is_err());
+}
+
+#[cfg(feature = "tokio-runtime")]
+#[tokio::test]
+async fn tokio_streams() {
+    use std::io::SeekFrom;
+
+    // The futures extension traits imported in this module are also applicable to the streams, so
+    // the tokio methods are called explicitly.
+    use tokio::io::{AsyncReadExt as TokioReadExt, AsyncSeekExt, AsyncWriteExt as TokioWriteExt};
+
+    let client = TestClient::new().await;
+
+    let options = GridFsBucketOptions::builder().chunk_size_bytes(3).build();
+    let bucket = client.database("tokio_streams").gridfs_bucket(options);
+    bucket.drop().await.unwrap();
+
+    let data: Vec<u8> = (0..20).collect();
+    let mut upload_stream = bucket.open_upload_stream("test", None);
+    TokioWriteExt::write_all(&mut upload_stream, &data[..10])
+        .await
+        .unwrap();
+    TokioWriteExt::write_all(&mut upload_stream, &data[10..])
+        .await
+        .unwrap();
+    upload_stream.shutdown().await.unwrap();
+
+    let mut download_stream = bucket
+        .open_download_stream(upload_stream.id().clone())
+        .await
+        .unwrap();
+    let mut buf = Vec::new();
+    TokioReadExt::read_to_end(&mut download_stream, &mut buf)
+        .await
+        .unwrap();
+    assert_eq!(buf, data);
+
+    assert_eq!(download_stream.seek(SeekFrom::Start(4)).await.unwrap(), 4);
+    let mut buf = vec![0u8; 5];
+    TokioReadExt::read_exact(&mut download_stream, &mut buf)
+        .await
+        .unwrap();
+    assert_eq!(buf, &data[4..9]);
+}
+
 #[cfg_attr(feature = "tokio-runtime", tokio::test)]
 #[cfg_attr(feature = "async-std-runtime", async_std::test)]
 async fn upload_stream() {
@@ -6,7 +6,10 @@ use crate::{
         auth::{oidc, AuthMechanism, Credential},
         options::ClientOptions,
     },
-    test::log_uncaptured,
+    test::{
+        log_uncaptured,
+        util::{FailCommandOptions, FailPoint, FailPointMode},
+    },
     Client,
 };
 
@@ -44,3 +47,74 @@ async fn single_principal_implicit_username() -> Result<()> {
         .await?;
     Ok(())
 }
+
+// Uses the built-in "file" provider rather than a user-supplied callback.
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+async fn file_provider() -> Result<()> {
+    if std::env::var("OIDC_TOKEN_DIR").is_err() {
+        log_uncaptured("Skipping OIDC test");
+        return Ok(());
+    }
+    let client = Client::with_uri_str(
+        "mongodb://localhost/?authMechanism=MONGODB-OIDC&authMechanismProperties=PROVIDER_NAME:\
+         file,OIDC_TOKEN_FILE:/tmp/tokens/test_user1",
+    )
+    .await?;
+    client
+        .database("test")
+        .collection::<Document>("test")
+        .find_one(None, None)
+        .await?;
+    Ok(())
+}
+
+// Prose test 4.1 Reauthentication Succeeds
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+async fn reauthentication_succeeds() -> Result<()> {
+    use std::sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    };
+
+    if std::env::var("OIDC_TOKEN_DIR").is_err() {
+        log_uncaptured("Skipping OIDC test");
+        return Ok(());
+    }
+    let calls = Arc::new(AtomicUsize::new(0));
+    let callback_calls = calls.clone();
+    let mut opts =
+        ClientOptions::parse_async("mongodb://localhost/?authMechanism=MONGODB-OIDC").await?;
+    opts.credential = Some(Credential {
+        mechanism: Some(AuthMechanism::MongoDbOidc),
+        oidc_callbacks: Some(oidc::Callbacks::new(move |_info, _params| {
+            callback_calls.fetch_add(1, Ordering::SeqCst);
+            async move {
+                Ok(oidc::IdpServerResponse {
+                    access_token: tokio::fs::read_to_string("/tmp/tokens/test_user1").await?,
+                    expires: None,
+                    refresh_token: None,
+                })
+            }
+            .boxed()
+        })),
+        ..Credential::default()
+    });
+    let client = Client::with_options(opts)?;
+    let coll = client.database("test").collection::<Document>("test");
+    coll.find_one(None, None).await?;
+    assert_eq!(calls.load(Ordering::SeqCst), 1);
+
+    let _guard = FailPoint::fail_command(
+        &["find"],
+        FailPointMode::Times(1),
+        FailCommandOptions::builder().error_code(391).build(),
+    )
+    .enable(&client, None)
+    .await?;
+
+    coll.find_one(None, None).await?;
+    assert_eq!(calls.load(Ordering::SeqCst), 2);
+    Ok(())
+}
@@ -7,11 +7,11 @@ use futures::TryStreamExt;
 use futures_util::{future::try_join_all, FutureExt};
 
 use crate::{
-    bson::{doc, Document},
+    bson::{doc, Document, Timestamp},
     client::options::ClientOptions,
     error::{ErrorKind, Result},
     event::command::{CommandEvent, CommandEventHandler, CommandStartedEvent},
-    options::SessionOptions,
+    options::{FindOneOptions, ReadConcern, SessionOptions},
     runtime::process::Process,
     test::{
         log_uncaptured,
@@ -51,6 +51,81 @@ async fn snapshot_and_causal_consistency_are_mutually_exclusive() {
     assert!(client.start_session(options).await.is_err());
 }
 
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+async fn snapshot_time_requires_snapshot() {
+    let options = SessionOptions::builder()
+        .snapshot_time(Timestamp {
+            time: 1,
+            increment: 1,
+        })
+        .build();
+    let client = TestClient::new().await;
+    let error = client.start_session(options).await.unwrap_err();
+    assert!(matches!(*error.kind, ErrorKind::InvalidArgument { .. }));
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[function_name::named]
+async fn snapshot_time_shared_across_sessions() {
+    let client = EventClient::new().await;
+    if client.is_standalone() || client.server_version_lt(5, 0) {
+        log_uncaptured(
+            "skipping snapshot_time_shared_across_sessions: requires a 5.0+ replica set or \
+             sharded cluster",
+        );
+        return;
+    }
+    if client.is_sharded() && client.server_version_gte(7, 0) {
+        // TODO RUST-1666: unskip this test
+        log_uncaptured("skipping snapshot_time_shared_across_sessions on 7.0+ sharded clusters");
+        return;
+    }
+
+    let coll = client
+        .init_db_and_coll(function_name!(), function_name!())
+        .await;
+    coll.insert_one(doc! { "x": 1 }, None).await.unwrap();
+
+    let mut first = client
+        .start_session(SessionOptions::builder().snapshot(true).build())
+        .await
+        .unwrap();
+    assert_eq!(first.snapshot_time(), None);
+    coll.find_one_with_session(None, None, &mut first)
+        .await
+        .unwrap();
+    let snapshot_time = first.snapshot_time().expect("snapshot time should be set");
+
+    let options = SessionOptions::builder()
+        .snapshot(true)
+        .snapshot_time(snapshot_time)
+        .build();
+    let mut second = client.start_session(options).await.unwrap();
+    assert_eq!(second.snapshot_time(), Some(snapshot_time));
+    coll.find_one_with_session(None, None, &mut second)
+        .await
+        .unwrap();
+    assert_eq!(second.snapshot_time(), Some(snapshot_time));
+
+    let options = FindOneOptions::builder()
+        .read_concern(ReadConcern::snapshot_at(snapshot_time))
+        .build();
+    coll.find_one(None, options).await.unwrap();
+
+    let events = client.get_command_started_events(&["find"]);
+    assert_eq!(events.len(), 3);
+    for event in &events[1..] {
+        let read_concern = event.command.get_document("readConcern").unwrap();
+        assert_eq!(read_concern.get_str("level"), Ok("snapshot"));
+        assert_eq!(
+            read_concern.get_timestamp("atClusterTime"),
+            Ok(snapshot_time)
+        );
+    }
+}
+
 #[cfg_attr(feature = "tokio-runtime", tokio::test(flavor = "multi_thread"))]
 #[cfg_attr(feature = "async-std-runtime", async_std::test)]
 #[function_name::named]
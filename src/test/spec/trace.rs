@@ -350,6 +350,7 @@ fn error_redaction() {
             }]),
             write_concern_error: Some(wce_copy),
             inserted_ids: HashMap::default(),
+            partial_result: None,
         }),
         labels,
     );
@@ -381,6 +381,8 @@ impl<'de> Deserialize<'de> for Operation {
             #[cfg(feature = "in-use-encryption-unstable")]
             "getKeys" => deserialize_op::<GetKeys>(definition.arguments),
             #[cfg(feature = "in-use-encryption-unstable")]
+            "rewrapManyDataKey" => deserialize_op::<RewrapManyDataKey>(definition.arguments),
+            #[cfg(feature = "in-use-encryption-unstable")]
             "removeKeyAltName" => deserialize_op::<RemoveKeyAltName>(definition.arguments),
             "iterateOnce" => deserialize_op::<IterateOnce>(definition.arguments),
             s => Ok(Box::new(UnimplementedOperation {
@@ -532,6 +534,12 @@ pub(super) struct Find {
     collation: Option<Collation>,
     #[serde(rename = "let")]
     let_vars: Option<Document>,
+    #[serde(
+        default,
+        rename = "timeoutMS",
+        deserialize_with = "serde_util::deserialize_duration_option_from_u64_millis"
+    )]
+    timeout: Option<Duration>,
 }
 
 impl Find {
@@ -574,6 +582,7 @@ impl Find {
             max_await_time: None,
             selection_criteria: None,
             let_vars: self.let_vars.clone(),
+            timeout: self.timeout,
         };
         match &self.session {
             Some(session_id) => {
@@ -658,6 +667,12 @@ pub(super) struct CreateFindCursor {
     collation: Option<Collation>,
     #[serde(rename = "let")]
     let_vars: Option<Document>,
+    #[serde(
+        default,
+        rename = "timeoutMS",
+        deserialize_with = "serde_util::deserialize_duration_option_from_u64_millis"
+    )]
+    timeout: Option<Duration>,
 }
 
 impl TestOperation for CreateFindCursor {
@@ -689,6 +704,7 @@ impl TestOperation for CreateFindCursor {
                 sort: self.sort.clone(),
                 collation: self.collation.clone(),
                 let_vars: self.let_vars.clone(),
+                timeout: self.timeout,
             };
             let cursor = find.get_cursor(id, test_runner).await?;
             Ok(Some(Entity::Cursor(cursor)))
@@ -2707,7 +2723,7 @@ impl TestOperation for RecordTopologyDescription {
     ) -> BoxFuture<'a, ()> {
         async {
             let client = test_runner.get_client(&self.client).await;
-            let description = client.topology_description();
+            let description = client.topology_description().description;
             test_runner.insert_entity(&self.id, description).await;
         }
         .boxed()
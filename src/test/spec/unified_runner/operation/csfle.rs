@@ -6,8 +6,8 @@ use serde::Deserialize;
 use super::{Entity, TestOperation, TestRunner};
 
 use crate::{
-    bson::{doc, Bson},
-    client_encryption::{DataKeyOptions, MasterKey},
+    bson::{doc, Bson, Document},
+    client_encryption::{DataKeyOptions, MasterKey, RewrapManyDataKeyOptions},
     error::Result,
 };
 
@@ -200,3 +200,49 @@ impl TestOperation for RemoveKeyAltName {
         .boxed()
     }
 }
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub(super) struct RewrapManyDataKey {
+    filter: Document,
+    opts: Option<RewrapManyDataKeyOptions>,
+}
+
+impl TestOperation for RewrapManyDataKey {
+    fn execute_entity_operation<'a>(
+        &'a self,
+        id: &'a str,
+        test_runner: &'a TestRunner,
+    ) -> BoxFuture<'a, Result<Option<Entity>>> {
+        async move {
+            let ce = test_runner.get_client_encryption(id).await;
+            let result = ce
+                .rewrap_many_data_key(self.filter.clone(), self.opts.clone())
+                .await?;
+            let mut result_doc = doc! {};
+            if let Some(bulk_write_result) = result.bulk_write_result {
+                let upserted_ids: Document = bulk_write_result
+                    .update_results
+                    .into_iter()
+                    .flatten()
+                    .filter_map(|(index, result)| {
+                        result.upserted_id.map(|id| (index.to_string(), id))
+                    })
+                    .collect();
+                result_doc.insert(
+                    "bulkWriteResult",
+                    doc! {
+                        "insertedCount": bulk_write_result.inserted_count as i64,
+                        "matchedCount": bulk_write_result.matched_count as i64,
+                        "modifiedCount": bulk_write_result.modified_count as i64,
+                        "deletedCount": bulk_write_result.deleted_count as i64,
+                        "upsertedCount": bulk_write_result.upserted_count as i64,
+                        "upsertedIds": upserted_ids,
+                    },
+                );
+            }
+            Ok(Some(Entity::Bson(Bson::Document(result_doc))))
+        }
+        .boxed()
+    }
+}
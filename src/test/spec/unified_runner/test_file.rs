@@ -362,6 +362,12 @@ pub(crate) struct CollectionOrDatabaseOptions {
     #[serde(rename = "readPreference")]
     pub(crate) selection_criteria: Option<SelectionCriteria>,
     pub(crate) write_concern: Option<WriteConcern>,
+    #[serde(
+        default,
+        rename = "timeoutMS",
+        deserialize_with = "serde_util::deserialize_duration_option_from_u64_millis"
+    )]
+    pub(crate) timeout: Option<Duration>,
 }
 
 impl CollectionOrDatabaseOptions {
@@ -370,6 +376,7 @@ impl CollectionOrDatabaseOptions {
             read_concern: self.read_concern.clone(),
             selection_criteria: self.selection_criteria.clone(),
             write_concern: self.write_concern.clone(),
+            timeout: self.timeout,
         }
     }
 
@@ -379,6 +386,7 @@ impl CollectionOrDatabaseOptions {
             read_concern: self.read_concern.clone(),
             selection_criteria: self.selection_criteria.clone(),
             write_concern: self.write_concern.clone(),
+            timeout: self.timeout,
             human_readable_serialization: None,
         }
     }
@@ -71,7 +71,6 @@ const SKIPPED_OPERATIONS: &[&str] = &[
     "listDatabaseObjects",
     "mapReduce",
     "watch",
-    "rewrapManyDataKey",
 ];
 
 static MIN_SPEC_VERSION: Version = Version::new(1, 0, 0);